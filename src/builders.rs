@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use ::validy::core::{NestedValidationError, SimpleValidationError, ValidationErrors};
+use ::validy::core::{NestedValidationError, SimpleValidationError, ValidationErrors, ValidationParams};
 
 pub struct ValidationErrorBuilder {}
 
@@ -20,6 +20,7 @@ impl ValidationErrorBuilderWithField {
 			code: code.into(),
 			field: self.field,
 			message: None,
+			params: ValidationParams::new(),
 		}
 	}
 
@@ -44,6 +45,7 @@ pub struct SimpleValidationErrorBuilder {
 	pub(super) code: Cow<'static, str>,
 	pub(super) field: Cow<'static, str>,
 	pub(super) message: Option<Cow<'static, str>>,
+	pub(super) params: ValidationParams,
 }
 
 impl SimpleValidationErrorBuilder {
@@ -52,11 +54,21 @@ impl SimpleValidationErrorBuilder {
 		self
 	}
 
+	pub fn with_param(
+		mut self,
+		key: impl Into<Cow<'static, str>>,
+		value: impl Into<Cow<'static, str>>,
+	) -> SimpleValidationErrorBuilder {
+		self.params.insert(key.into(), value.into());
+		self
+	}
+
 	pub fn build(self) -> SimpleValidationError {
 		SimpleValidationError {
 			code: self.code,
 			field: self.field,
 			message: self.message,
+			params: self.params,
 		}
 	}
 }
@@ -1,13 +1,19 @@
 use ::validy::builders::ValidationErrorBuilder;
 use async_trait::async_trait;
 use serde::Serialize;
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+	borrow::Cow,
+	collections::{BTreeMap, HashMap},
+};
 #[cfg(feature = "derive")]
 pub use validation_derive::*;
 
+pub use crate::validated::Checked;
+
 #[cfg(feature = "modification")]
 pub type ParseResult<T> = (T, Option<ValidationError>);
 pub type ValidationErrors = HashMap<Cow<'static, str>, Vec<ValidationError>>;
+pub type ValidationParams = BTreeMap<Cow<'static, str>, Cow<'static, str>>;
 
 #[derive(Debug, Serialize, Clone, PartialEq, Eq)]
 #[serde(untagged)]
@@ -36,48 +42,126 @@ pub struct SimpleValidationError {
 	pub field: Cow<'static, str>,
 	pub code: Cow<'static, str>,
 	pub message: Option<Cow<'static, str>>,
+	#[serde(skip_serializing_if = "BTreeMap::is_empty")]
+	pub params: ValidationParams,
 }
 
 pub trait Validate {
 	fn validate(&self) -> Result<(), ValidationErrors>;
+
+	fn validate_checked(self) -> Result<Checked<Self>, ValidationErrors>
+	where
+		Self: Sized,
+	{
+		self.validate()?;
+		Ok(Checked(self))
+	}
 }
 
 #[async_trait]
 pub trait AsyncValidate: Send + Sync {
 	async fn async_validate(&self) -> Result<(), ValidationErrors>;
+
+	async fn async_validate_checked(self) -> Result<Checked<Self>, ValidationErrors>
+	where
+		Self: Sized,
+	{
+		self.async_validate().await?;
+		Ok(Checked(self))
+	}
 }
 
 pub trait ValidateWithContext<C> {
 	fn validate_with_context(&self, context: &C) -> Result<(), ValidationErrors>;
+
+	fn validate_with_context_checked(self, context: &C) -> Result<Checked<Self>, ValidationErrors>
+	where
+		Self: Sized,
+	{
+		self.validate_with_context(context)?;
+		Ok(Checked(self))
+	}
 }
 
 pub trait SpecificValidateWithContext {
 	type Context: Send + Sync;
 	fn specific_validate_with_context(&self, context: &Self::Context) -> Result<(), ValidationErrors>;
+
+	fn specific_validate_with_context_checked(self, context: &Self::Context) -> Result<Checked<Self>, ValidationErrors>
+	where
+		Self: Sized,
+	{
+		self.specific_validate_with_context(context)?;
+		Ok(Checked(self))
+	}
 }
 
 #[async_trait]
 pub trait AsyncValidateWithContext<C>: Send + Sync {
 	async fn async_validate_with_context(&self, context: &C) -> Result<(), ValidationErrors>;
+
+	async fn async_validate_with_context_checked(self, context: &C) -> Result<Checked<Self>, ValidationErrors>
+	where
+		Self: Sized,
+		C: Sync,
+	{
+		self.async_validate_with_context(context).await?;
+		Ok(Checked(self))
+	}
 }
 
 #[async_trait]
 pub trait SpecificAsyncValidateWithContext: Send + Sync {
 	type Context: Send + Sync;
 	async fn specific_async_validate_with_context(&self, context: &Self::Context) -> Result<(), ValidationErrors>;
+
+	async fn specific_async_validate_with_context_checked(
+		self,
+		context: &Self::Context,
+	) -> Result<Checked<Self>, ValidationErrors>
+	where
+		Self: Sized,
+	{
+		self.specific_async_validate_with_context(context).await?;
+		Ok(Checked(self))
+	}
 }
 
 pub trait ValidateAndModificate {
 	fn validate_and_modificate(&mut self) -> Result<(), ValidationErrors>;
+
+	fn validate_and_modificate_checked(mut self) -> Result<Checked<Self>, ValidationErrors>
+	where
+		Self: Sized,
+	{
+		self.validate_and_modificate()?;
+		Ok(Checked(self))
+	}
 }
 
 #[async_trait]
 pub trait AsyncValidateAndModificate: Send + Sync {
 	async fn async_validate_and_modificate(&mut self) -> Result<(), ValidationErrors>;
+
+	async fn async_validate_and_modificate_checked(mut self) -> Result<Checked<Self>, ValidationErrors>
+	where
+		Self: Sized,
+	{
+		self.async_validate_and_modificate().await?;
+		Ok(Checked(self))
+	}
 }
 
 pub trait ValidateAndModificateWithContext<C> {
 	fn validate_and_modificate_with_context(&mut self, context: &C) -> Result<(), ValidationErrors>;
+
+	fn validate_and_modificate_with_context_checked(mut self, context: &C) -> Result<Checked<Self>, ValidationErrors>
+	where
+		Self: Sized,
+	{
+		self.validate_and_modificate_with_context(context)?;
+		Ok(Checked(self))
+	}
 }
 
 pub trait SpecificValidateAndModificateWithContext {
@@ -86,11 +170,34 @@ pub trait SpecificValidateAndModificateWithContext {
 		&mut self,
 		context: &Self::Context,
 	) -> Result<(), ValidationErrors>;
+
+	fn specific_validate_and_modificate_with_context_checked(
+		mut self,
+		context: &Self::Context,
+	) -> Result<Checked<Self>, ValidationErrors>
+	where
+		Self: Sized,
+	{
+		self.specific_validate_and_modificate_with_context(context)?;
+		Ok(Checked(self))
+	}
 }
 
 #[async_trait]
 pub trait AsyncValidateAndModificateWithContext<C>: Send + Sync {
 	async fn async_validate_and_modificate_with_context(&mut self, context: &C) -> Result<(), ValidationErrors>;
+
+	async fn async_validate_and_modificate_with_context_checked(
+		mut self,
+		context: &C,
+	) -> Result<Checked<Self>, ValidationErrors>
+	where
+		Self: Sized,
+		C: Sync,
+	{
+		self.async_validate_and_modificate_with_context(context).await?;
+		Ok(Checked(self))
+	}
 }
 
 #[async_trait]
@@ -100,30 +207,67 @@ pub trait SpecificAsyncValidateAndModificateWithContext: Send + Sync {
 		&mut self,
 		context: &Self::Context,
 	) -> Result<(), ValidationErrors>;
+
+	async fn specific_async_validate_and_modificate_with_context_checked(
+		mut self,
+		context: &Self::Context,
+	) -> Result<Checked<Self>, ValidationErrors>
+	where
+		Self: Sized,
+	{
+		self.specific_async_validate_and_modificate_with_context(context)
+			.await?;
+		Ok(Checked(self))
+	}
 }
 
 pub trait ValidateAndParse<W>: Sized {
 	fn validate_and_parse(wrapper: W) -> Result<Self, ValidationErrors>;
+
+	fn validate_and_parse_checked(wrapper: W) -> Result<Checked<Self>, ValidationErrors> {
+		Self::validate_and_parse(wrapper).map(Checked)
+	}
 }
 
 pub trait SpecificValidateAndParse: Sized {
 	type Wrapper: Send + Sync;
 	fn specific_validate_and_parse(wrapper: Self::Wrapper) -> Result<Self, ValidationErrors>;
+
+	fn specific_validate_and_parse_checked(wrapper: Self::Wrapper) -> Result<Checked<Self>, ValidationErrors> {
+		Self::specific_validate_and_parse(wrapper).map(Checked)
+	}
 }
 
 #[async_trait]
 pub trait AsyncValidateAndParse<W>: Sized + Send + Sync {
 	async fn async_validate_and_parse(wrapper: W) -> Result<Self, ValidationErrors>;
+
+	async fn async_validate_and_parse_checked(wrapper: W) -> Result<Checked<Self>, ValidationErrors>
+	where
+		W: Send + 'async_trait,
+	{
+		Self::async_validate_and_parse(wrapper).await.map(Checked)
+	}
 }
 
 #[async_trait]
 pub trait SpecificAsyncValidateAndParse: Sized + Send + Sync {
 	type Wrapper: Send + Sync;
 	async fn specific_async_validate_and_parse(wrapper: Self::Wrapper) -> Result<Self, ValidationErrors>;
+
+	async fn specific_async_validate_and_parse_checked(
+		wrapper: Self::Wrapper,
+	) -> Result<Checked<Self>, ValidationErrors> {
+		Self::specific_async_validate_and_parse(wrapper).await.map(Checked)
+	}
 }
 
 pub trait ValidateAndParseWithContext<W, C>: Sized {
 	fn validate_and_parse_with_context(wrapper: W, context: &C) -> Result<Self, ValidationErrors>;
+
+	fn validate_and_parse_with_context_checked(wrapper: W, context: &C) -> Result<Checked<Self>, ValidationErrors> {
+		Self::validate_and_parse_with_context(wrapper, context).map(Checked)
+	}
 }
 
 pub trait SpecificValidateAndParseWithContext: Sized {
@@ -133,11 +277,31 @@ pub trait SpecificValidateAndParseWithContext: Sized {
 		wrapper: Self::Wrapper,
 		context: &Self::Context,
 	) -> Result<Self, ValidationErrors>;
+
+	fn specific_validate_and_parse_with_context_checked(
+		wrapper: Self::Wrapper,
+		context: &Self::Context,
+	) -> Result<Checked<Self>, ValidationErrors> {
+		Self::specific_validate_and_parse_with_context(wrapper, context).map(Checked)
+	}
 }
 
 #[async_trait]
 pub trait AsyncValidateAndParseWithContext<W, C>: Sized + Send + Sync {
 	async fn async_validate_and_parse_with_context(wrapper: W, context: &C) -> Result<Self, ValidationErrors>;
+
+	async fn async_validate_and_parse_with_context_checked(
+		wrapper: W,
+		context: &C,
+	) -> Result<Checked<Self>, ValidationErrors>
+	where
+		W: Send + 'async_trait,
+		C: Sync,
+	{
+		Self::async_validate_and_parse_with_context(wrapper, context)
+			.await
+			.map(Checked)
+	}
 }
 
 #[async_trait]
@@ -148,6 +312,21 @@ pub trait SpecificAsyncValidateAndParseWithContext: Sized + Send + Sync {
 		wrapper: Self::Wrapper,
 		context: &Self::Context,
 	) -> Result<Self, ValidationErrors>;
+
+	async fn specific_async_validate_and_parse_with_context_checked(
+		wrapper: Self::Wrapper,
+		context: &Self::Context,
+	) -> Result<Checked<Self>, ValidationErrors> {
+		Self::specific_async_validate_and_parse_with_context(wrapper, context)
+			.await
+			.map(Checked)
+	}
+}
+
+pub trait ValidNewtype: Sized {
+	type Inner;
+	fn try_new(value: Self::Inner) -> Result<Self, ValidationErrors>;
+	fn into_inner(self) -> Self::Inner;
 }
 
 #[derive(Debug)]
@@ -0,0 +1,852 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[cfg(feature = "ip")]
+use crate::utils::ip::IpClass;
+use crate::{dynamic::schema::Schema, settings::LengthUnit};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum Rule {
+	Required {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Length {
+		min: Option<usize>,
+		max: Option<usize>,
+		#[serde(default)]
+		unit: Option<LengthUnit>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Range {
+		min: Option<f64>,
+		max: Option<f64>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Contains {
+		slice: String,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Prefix {
+		prefix: String,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Suffix {
+		suffix: String,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Allowlist {
+		items: Vec<Value>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Blocklist {
+		items: Vec<Value>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Ascii {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Alphanumeric {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Alpha {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Numeric {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Printable {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	NoControlChars {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	NoZeroWidth {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	SingleLine {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Charset {
+		allowed: String,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "email")]
+	Email {
+		#[serde(default)]
+		allow_display_name: bool,
+		#[serde(default)]
+		allowed_domains: Vec<String>,
+		#[serde(default)]
+		blocked_domains: Vec<String>,
+		require_tld: Option<bool>,
+		#[serde(default)]
+		allow_ip_domain: bool,
+		#[serde(default)]
+		allow_disposable: bool,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "pattern")]
+	Pattern {
+		pattern: String,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "url")]
+	Url {
+		#[serde(default)]
+		schemes: Vec<String>,
+		#[serde(default)]
+		require_host: bool,
+		#[serde(default)]
+		allowed_hosts: Vec<String>,
+		#[serde(default)]
+		blocked_hosts: Vec<String>,
+		#[serde(default)]
+		deny_private_ips: bool,
+		max_length: Option<usize>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "ip")]
+	Ip {
+		#[serde(rename = "in", default)]
+		in_networks: Vec<String>,
+		#[serde(rename = "not_in", default)]
+		not_in_networks: Vec<String>,
+		class: Option<IpClass>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "ip")]
+	Ipv4 {
+		#[serde(rename = "in", default)]
+		in_networks: Vec<String>,
+		#[serde(rename = "not_in", default)]
+		not_in_networks: Vec<String>,
+		class: Option<IpClass>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "ip")]
+	Ipv6 {
+		#[serde(rename = "in", default)]
+		in_networks: Vec<String>,
+		#[serde(rename = "not_in", default)]
+		not_in_networks: Vec<String>,
+		class: Option<IpClass>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "ip")]
+	Cidr {
+		min_prefix: Option<u8>,
+		max_prefix: Option<u8>,
+		#[serde(default)]
+		strict: bool,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "ip")]
+	SocketAddr {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "ip")]
+	Port {
+		min: Option<u16>,
+		max: Option<u16>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "domain")]
+	Hostname {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "domain")]
+	Domain {
+		require_tld: Option<bool>,
+		#[serde(default)]
+		allow_wildcard: bool,
+		suffix_of: Option<String>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "payments")]
+	CreditCard {
+		#[serde(default)]
+		brands: Vec<String>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "payments")]
+	Luhn {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "payments")]
+	Iban {
+		#[serde(default)]
+		countries: Vec<String>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "payments")]
+	Bic {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "phone")]
+	Phone {
+		region: Option<String>,
+		#[serde(default)]
+		regions: Vec<String>,
+		#[serde(default)]
+		types: Vec<String>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "password")]
+	Password {
+		min_entropy: Option<f64>,
+		min_score: Option<u8>,
+		#[serde(default)]
+		require: Vec<String>,
+		#[serde(default)]
+		forbid_fields: Vec<String>,
+		max_repeats: Option<usize>,
+		reference_year: Option<i32>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "iso")]
+	Country {
+		format: Option<String>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "iso")]
+	Currency {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "iso")]
+	LanguageTag {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "iso")]
+	Timezone {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "unicode")]
+	UnicodeScripts {
+		allowed: Vec<String>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "unicode")]
+	NoMixedScript {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "unicode")]
+	ConfusableSafe {
+		level: Option<String>,
+		message: Option<String>,
+		code: Option<String>,
+	},
+	#[cfg(feature = "uuid")]
+	Uuid {
+		message: Option<String>,
+		code: Option<String>,
+	},
+	Nested {
+		schema: Schema,
+		code: Option<String>,
+	},
+	ForEach {
+		rules: Vec<Rule>,
+	},
+	Trim,
+	TrimStart,
+	TrimEnd,
+	Truncate {
+		max: usize,
+		#[serde(default)]
+		unit: Option<LengthUnit>,
+	},
+	#[cfg(feature = "email")]
+	NormalizeEmail {
+		#[serde(default)]
+		strip_tag: bool,
+	},
+	#[cfg(feature = "domain")]
+	Punycode,
+	#[cfg(feature = "phone")]
+	E164 {
+		region: Option<String>,
+	},
+	Uppercase,
+	Lowercase,
+	DigitsOnly,
+	Capitalize,
+	CamelCase,
+	LowerCamelCase,
+	SnakeCase,
+	ShoutySnakeCase,
+	KebabCase,
+	ShoutyKebabCase,
+	TrainCase,
+}
+
+impl Rule {
+	pub fn required() -> Self {
+		Rule::Required {
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn length(min: Option<usize>, max: Option<usize>) -> Self {
+		Rule::Length {
+			min,
+			max,
+			unit: None,
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn range(min: Option<f64>, max: Option<f64>) -> Self {
+		Rule::Range {
+			min,
+			max,
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn contains(slice: impl Into<String>) -> Self {
+		Rule::Contains {
+			slice: slice.into(),
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn prefix(prefix: impl Into<String>) -> Self {
+		Rule::Prefix {
+			prefix: prefix.into(),
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn suffix(suffix: impl Into<String>) -> Self {
+		Rule::Suffix {
+			suffix: suffix.into(),
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn allowlist(items: impl IntoIterator<Item = Value>) -> Self {
+		Rule::Allowlist {
+			items: items.into_iter().collect(),
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn blocklist(items: impl IntoIterator<Item = Value>) -> Self {
+		Rule::Blocklist {
+			items: items.into_iter().collect(),
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn ascii() -> Self {
+		Rule::Ascii {
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn alphanumeric() -> Self {
+		Rule::Alphanumeric {
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn alpha() -> Self {
+		Rule::Alpha {
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn numeric() -> Self {
+		Rule::Numeric {
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn printable() -> Self {
+		Rule::Printable {
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn no_control_chars() -> Self {
+		Rule::NoControlChars {
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn no_zero_width() -> Self {
+		Rule::NoZeroWidth {
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn single_line() -> Self {
+		Rule::SingleLine {
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn charset(allowed: impl Into<String>) -> Self {
+		Rule::Charset {
+			allowed: allowed.into(),
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "email")]
+	pub fn email() -> Self {
+		Rule::Email {
+			allow_display_name: false,
+			allowed_domains: Vec::new(),
+			blocked_domains: Vec::new(),
+			require_tld: None,
+			allow_ip_domain: false,
+			allow_disposable: false,
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "pattern")]
+	pub fn pattern(pattern: impl Into<String>) -> Self {
+		Rule::Pattern {
+			pattern: pattern.into(),
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "url")]
+	pub fn url() -> Self {
+		Rule::Url {
+			schemes: Vec::new(),
+			require_host: false,
+			allowed_hosts: Vec::new(),
+			blocked_hosts: Vec::new(),
+			deny_private_ips: false,
+			max_length: None,
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "ip")]
+	pub fn ip() -> Self {
+		Rule::Ip {
+			in_networks: Vec::new(),
+			not_in_networks: Vec::new(),
+			class: None,
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "ip")]
+	pub fn ipv4() -> Self {
+		Rule::Ipv4 {
+			in_networks: Vec::new(),
+			not_in_networks: Vec::new(),
+			class: None,
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "ip")]
+	pub fn ipv6() -> Self {
+		Rule::Ipv6 {
+			in_networks: Vec::new(),
+			not_in_networks: Vec::new(),
+			class: None,
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "ip")]
+	pub fn cidr(min_prefix: Option<u8>, max_prefix: Option<u8>) -> Self {
+		Rule::Cidr {
+			min_prefix,
+			max_prefix,
+			strict: false,
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "ip")]
+	pub fn socket_addr() -> Self {
+		Rule::SocketAddr {
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "ip")]
+	pub fn port(min: Option<u16>, max: Option<u16>) -> Self {
+		Rule::Port {
+			min,
+			max,
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "domain")]
+	pub fn hostname() -> Self {
+		Rule::Hostname {
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "domain")]
+	pub fn domain(suffix_of: Option<&str>) -> Self {
+		Rule::Domain {
+			require_tld: None,
+			allow_wildcard: false,
+			suffix_of: suffix_of.map(str::to_string),
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "payments")]
+	pub fn credit_card(brands: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		Rule::CreditCard {
+			brands: brands.into_iter().map(Into::into).collect(),
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "payments")]
+	pub fn luhn() -> Self {
+		Rule::Luhn {
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "payments")]
+	pub fn iban(countries: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		Rule::Iban {
+			countries: countries.into_iter().map(Into::into).collect(),
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "payments")]
+	pub fn bic() -> Self {
+		Rule::Bic {
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "phone")]
+	pub fn phone(region: Option<&str>) -> Self {
+		Rule::Phone {
+			region: region.map(str::to_string),
+			regions: Vec::new(),
+			types: Vec::new(),
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "phone")]
+	pub fn e164(region: Option<&str>) -> Self {
+		Rule::E164 {
+			region: region.map(str::to_string),
+		}
+	}
+
+	#[cfg(feature = "password")]
+	pub fn password() -> Self {
+		Rule::Password {
+			min_entropy: None,
+			min_score: None,
+			require: Vec::new(),
+			forbid_fields: Vec::new(),
+			max_repeats: None,
+			reference_year: None,
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "iso")]
+	pub fn country(format: Option<&str>) -> Self {
+		Rule::Country {
+			format: format.map(str::to_string),
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "iso")]
+	pub fn currency() -> Self {
+		Rule::Currency {
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "iso")]
+	pub fn language_tag() -> Self {
+		Rule::LanguageTag {
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "iso")]
+	pub fn timezone() -> Self {
+		Rule::Timezone {
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "unicode")]
+	pub fn unicode_scripts(allowed: impl IntoIterator<Item = impl Into<String>>) -> Self {
+		Rule::UnicodeScripts {
+			allowed: allowed.into_iter().map(Into::into).collect(),
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "unicode")]
+	pub fn no_mixed_script() -> Self {
+		Rule::NoMixedScript {
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "unicode")]
+	pub fn confusable_safe(level: Option<&str>) -> Self {
+		Rule::ConfusableSafe {
+			level: level.map(str::to_string),
+			message: None,
+			code: None,
+		}
+	}
+
+	#[cfg(feature = "uuid")]
+	pub fn uuid() -> Self {
+		Rule::Uuid {
+			message: None,
+			code: None,
+		}
+	}
+
+	pub fn nested(schema: Schema) -> Self {
+		Rule::Nested { schema, code: None }
+	}
+
+	pub fn for_each(rules: impl IntoIterator<Item = Rule>) -> Self {
+		Rule::ForEach {
+			rules: rules.into_iter().collect(),
+		}
+	}
+
+	pub fn truncate(max: usize) -> Self {
+		Rule::Truncate { max, unit: None }
+	}
+
+	#[cfg(feature = "email")]
+	pub fn normalize_email(strip_tag: bool) -> Self {
+		Rule::NormalizeEmail { strip_tag }
+	}
+
+	pub fn with_unit(mut self, new_unit: LengthUnit) -> Self {
+		if let Rule::Length { unit, .. } | Rule::Truncate { unit, .. } = &mut self {
+			*unit = Some(new_unit);
+		}
+
+		self
+	}
+
+	pub fn with_message(mut self, new_message: impl Into<String>) -> Self {
+		match &mut self {
+			Rule::Required { message, .. }
+			| Rule::Length { message, .. }
+			| Rule::Range { message, .. }
+			| Rule::Contains { message, .. }
+			| Rule::Prefix { message, .. }
+			| Rule::Suffix { message, .. }
+			| Rule::Allowlist { message, .. }
+			| Rule::Blocklist { message, .. }
+			| Rule::Ascii { message, .. }
+			| Rule::Alphanumeric { message, .. }
+			| Rule::Alpha { message, .. }
+			| Rule::Numeric { message, .. }
+			| Rule::Printable { message, .. }
+			| Rule::NoControlChars { message, .. }
+			| Rule::NoZeroWidth { message, .. }
+			| Rule::SingleLine { message, .. }
+			| Rule::Charset { message, .. } => *message = Some(new_message.into()),
+			#[cfg(feature = "email")]
+			Rule::Email { message, .. } => *message = Some(new_message.into()),
+			#[cfg(feature = "pattern")]
+			Rule::Pattern { message, .. } => *message = Some(new_message.into()),
+			#[cfg(feature = "url")]
+			Rule::Url { message, .. } => *message = Some(new_message.into()),
+			#[cfg(feature = "ip")]
+			Rule::Ip { message, .. }
+			| Rule::Ipv4 { message, .. }
+			| Rule::Ipv6 { message, .. }
+			| Rule::Cidr { message, .. }
+			| Rule::SocketAddr { message, .. }
+			| Rule::Port { message, .. } => *message = Some(new_message.into()),
+			#[cfg(feature = "domain")]
+			Rule::Hostname { message, .. } | Rule::Domain { message, .. } => *message = Some(new_message.into()),
+			#[cfg(feature = "payments")]
+			Rule::CreditCard { message, .. }
+			| Rule::Luhn { message, .. }
+			| Rule::Iban { message, .. }
+			| Rule::Bic { message, .. } => *message = Some(new_message.into()),
+			#[cfg(feature = "phone")]
+			Rule::Phone { message, .. } => *message = Some(new_message.into()),
+			#[cfg(feature = "password")]
+			Rule::Password { message, .. } => *message = Some(new_message.into()),
+			#[cfg(feature = "iso")]
+			Rule::Country { message, .. }
+			| Rule::Currency { message, .. }
+			| Rule::LanguageTag { message, .. }
+			| Rule::Timezone { message, .. } => *message = Some(new_message.into()),
+			#[cfg(feature = "unicode")]
+			Rule::UnicodeScripts { message, .. }
+			| Rule::NoMixedScript { message, .. }
+			| Rule::ConfusableSafe { message, .. } => *message = Some(new_message.into()),
+			#[cfg(feature = "uuid")]
+			Rule::Uuid { message, .. } => *message = Some(new_message.into()),
+			_ => {}
+		}
+
+		self
+	}
+
+	pub fn with_code(mut self, new_code: impl Into<String>) -> Self {
+		match &mut self {
+			Rule::Required { code, .. }
+			| Rule::Length { code, .. }
+			| Rule::Range { code, .. }
+			| Rule::Contains { code, .. }
+			| Rule::Prefix { code, .. }
+			| Rule::Suffix { code, .. }
+			| Rule::Allowlist { code, .. }
+			| Rule::Blocklist { code, .. }
+			| Rule::Ascii { code, .. }
+			| Rule::Alphanumeric { code, .. }
+			| Rule::Alpha { code, .. }
+			| Rule::Numeric { code, .. }
+			| Rule::Printable { code, .. }
+			| Rule::NoControlChars { code, .. }
+			| Rule::NoZeroWidth { code, .. }
+			| Rule::SingleLine { code, .. }
+			| Rule::Charset { code, .. }
+			| Rule::Nested { code, .. } => *code = Some(new_code.into()),
+			#[cfg(feature = "email")]
+			Rule::Email { code, .. } => *code = Some(new_code.into()),
+			#[cfg(feature = "pattern")]
+			Rule::Pattern { code, .. } => *code = Some(new_code.into()),
+			#[cfg(feature = "url")]
+			Rule::Url { code, .. } => *code = Some(new_code.into()),
+			#[cfg(feature = "ip")]
+			Rule::Ip { code, .. }
+			| Rule::Ipv4 { code, .. }
+			| Rule::Ipv6 { code, .. }
+			| Rule::Cidr { code, .. }
+			| Rule::SocketAddr { code, .. }
+			| Rule::Port { code, .. } => *code = Some(new_code.into()),
+			#[cfg(feature = "domain")]
+			Rule::Hostname { code, .. } | Rule::Domain { code, .. } => *code = Some(new_code.into()),
+			#[cfg(feature = "payments")]
+			Rule::CreditCard { code, .. }
+			| Rule::Luhn { code, .. }
+			| Rule::Iban { code, .. }
+			| Rule::Bic { code, .. } => *code = Some(new_code.into()),
+			#[cfg(feature = "phone")]
+			Rule::Phone { code, .. } => *code = Some(new_code.into()),
+			#[cfg(feature = "password")]
+			Rule::Password { code, .. } => *code = Some(new_code.into()),
+			#[cfg(feature = "iso")]
+			Rule::Country { code, .. }
+			| Rule::Currency { code, .. }
+			| Rule::LanguageTag { code, .. }
+			| Rule::Timezone { code, .. } => *code = Some(new_code.into()),
+			#[cfg(feature = "unicode")]
+			Rule::UnicodeScripts { code, .. }
+			| Rule::NoMixedScript { code, .. }
+			| Rule::ConfusableSafe { code, .. } => *code = Some(new_code.into()),
+			#[cfg(feature = "uuid")]
+			Rule::Uuid { code, .. } => *code = Some(new_code.into()),
+			_ => {}
+		}
+
+		self
+	}
+}
@@ -0,0 +1,901 @@
+use std::{borrow::Cow, fmt, mem, ops::Bound};
+
+use serde::{
+	Deserialize, Deserializer, Serialize, Serializer,
+	de::{MapAccess, Visitor},
+	ser::SerializeMap,
+};
+use serde_json::{Map, Value};
+
+use crate::{
+	core::{NestedValidationError, ValidationError, ValidationErrors},
+	dynamic::rule::Rule,
+	functions::{
+		modification::{
+			cases::{
+				camel_case, capitalize, kebab_case, lower_camel_case, shouty_kebab_case, shouty_snake_case, snake_case,
+				train_case,
+			},
+			text::truncate,
+		},
+		validation::{
+			chars::{
+				validate_alpha, validate_alphanumeric, validate_ascii, validate_charset, validate_no_control_chars,
+				validate_no_zero_width, validate_numeric, validate_printable, validate_single_line,
+			},
+			contains::validate_contains,
+			iter::{validate_allowlist, validate_blocklist},
+			length::{measure_length, validate_length},
+			prefix::validate_prefix,
+			range::validate_range,
+			suffix::validate_suffix,
+		},
+	},
+	settings::{FailureMode, ValidationSettings},
+	utils::helpers::{append_error, can_continue, should_fail_fast},
+};
+
+const FAILURE_MODE_KEY: &str = "failure_mode";
+const FIELDS_KEY: &str = "fields";
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Schema {
+	fields: Vec<(String, Vec<Rule>)>,
+	failure_mode: Option<FailureMode>,
+}
+
+impl Schema {
+	pub fn new() -> Self {
+		Schema::default()
+	}
+
+	pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+		serde_json::from_str(json)
+	}
+
+	pub fn field(mut self, name: impl Into<String>, rules: impl IntoIterator<Item = Rule>) -> Self {
+		let name = name.into();
+
+		match self.fields.iter_mut().find(|(field, _)| *field == name) {
+			Some((_, current_rules)) => current_rules.extend(rules),
+			None => self.fields.push((name, rules.into_iter().collect())),
+		}
+
+		self
+	}
+
+	pub fn with_failure_mode(mut self, failure_mode: FailureMode) -> Self {
+		self.failure_mode = Some(failure_mode);
+		self
+	}
+
+	pub fn get_rules(&self, name: &str) -> Option<&[Rule]> {
+		self.fields
+			.iter()
+			.find(|(field, _)| field == name)
+			.map(|(_, rules)| rules.as_slice())
+	}
+
+	pub fn validate(&self, value: &Value) -> Result<(), ValidationErrors> {
+		let mut value = value.clone();
+		self.execute(&mut value, false)
+	}
+
+	pub fn validate_and_modificate(&self, value: &mut Value) -> Result<(), ValidationErrors> {
+		let mut modified = value.clone();
+		self.execute(&mut modified, true)?;
+		*value = modified;
+		Ok(())
+	}
+
+	fn execute(&self, value: &mut Value, modificate: bool) -> Result<(), ValidationErrors> {
+		let mut errors = ValidationErrors::new();
+		let failure_mode = self.failure_mode.unwrap_or_else(ValidationSettings::get_failure_mode);
+
+		if !value.is_object() {
+			append_error(&mut errors, type_error("", "object"), failure_mode, String::new());
+			return Err(errors);
+		}
+
+		for (name, rules) in &self.fields {
+			let mut current = value
+				.as_object_mut()
+				.and_then(|object| object.get_mut(name))
+				.map(mem::take);
+
+			let siblings = value.as_object();
+			let keep_going = match current.as_mut() {
+				Some(current) if !current.is_null() => {
+					execute_rules(rules, current, name, siblings, modificate, failure_mode, &mut errors)
+				}
+				_ => execute_missing(rules, name, failure_mode, &mut errors),
+			};
+
+			if let Some(current) = current
+				&& let Some(slot) = value.as_object_mut().and_then(|object| object.get_mut(name))
+			{
+				*slot = current;
+			}
+
+			if !keep_going {
+				return Err(errors);
+			}
+		}
+
+		if errors.is_empty() { Ok(()) } else { Err(errors) }
+	}
+}
+
+fn execute_missing(rules: &[Rule], field: &str, failure_mode: FailureMode, errors: &mut ValidationErrors) -> bool {
+	for rule in rules {
+		if let Rule::Required { message, code } = rule {
+			let error = ValidationError::builder()
+				.with_field(field.to_string())
+				.as_simple(get_or(code, "required"))
+				.with_message(get_or(message, "is required"))
+				.build();
+
+			append_error(errors, error.into(), failure_mode, field.to_string());
+			return !should_fail_fast(errors, failure_mode, field);
+		}
+	}
+
+	true
+}
+
+fn execute_rules(
+	rules: &[Rule],
+	value: &mut Value,
+	field: &str,
+	siblings: Option<&Map<String, Value>>,
+	modificate: bool,
+	failure_mode: FailureMode,
+	errors: &mut ValidationErrors,
+) -> bool {
+	for rule in rules {
+		if !can_continue(errors, failure_mode, field) {
+			break;
+		}
+
+		if let Rule::ForEach { rules } = rule {
+			match value.as_array_mut() {
+				Some(items) => {
+					for item in items.iter_mut() {
+						if !execute_rules(rules, item, field, siblings, modificate, failure_mode, errors) {
+							return false;
+						}
+					}
+				}
+				None => append_error(errors, type_error(field, "array"), failure_mode, field.to_string()),
+			}
+		} else if let Err(error) = execute_rule(rule, value, field, siblings, modificate) {
+			append_error(errors, error, failure_mode, field.to_string());
+		}
+
+		if should_fail_fast(errors, failure_mode, field) {
+			return false;
+		}
+	}
+
+	true
+}
+
+#[cfg_attr(not(feature = "password"), allow(unused_variables))]
+fn execute_rule(
+	rule: &Rule,
+	value: &mut Value,
+	field: &str,
+	siblings: Option<&Map<String, Value>>,
+	modificate: bool,
+) -> Result<(), ValidationError> {
+	match rule {
+		Rule::Required { .. } | Rule::ForEach { .. } => Ok(()),
+		Rule::Length {
+			min,
+			max,
+			unit,
+			message,
+			code,
+		} => {
+			let len = match value {
+				Value::String(value) => measure_length(value, unit.unwrap_or_else(ValidationSettings::get_length_unit)),
+				Value::Array(value) => value.len(),
+				Value::Object(value) => value.len(),
+				_ => return Err(type_error(field, "string, array or object")),
+			};
+
+			validate_length(
+				&len,
+				(to_bound(min), to_bound(max)),
+				field.to_string(),
+				get_or(code, "length"),
+				get_or(message, "length out of range"),
+			)
+		}
+		Rule::Range {
+			min,
+			max,
+			message,
+			code,
+		} => {
+			let number = value.as_f64().ok_or_else(|| type_error(field, "number"))?;
+
+			validate_range(
+				&number,
+				(to_bound(min), to_bound(max)),
+				field.to_string(),
+				get_or(code, "range"),
+				get_or(message, "out of range"),
+			)
+		}
+		Rule::Contains { slice, message, code } => validate_contains(
+			as_str(value, field)?,
+			slice,
+			field.to_string(),
+			get_or(code, "slice"),
+			get_or(message, "invalid format"),
+		),
+		Rule::Prefix { prefix, message, code } => validate_prefix(
+			as_str(value, field)?,
+			prefix,
+			field.to_string(),
+			get_or(code, "prefix"),
+			get_or(message, "invalid prefix"),
+		),
+		Rule::Suffix { suffix, message, code } => validate_suffix(
+			as_str(value, field)?,
+			suffix,
+			field.to_string(),
+			get_or(code, "suffix"),
+			get_or(message, "invalid suffix"),
+		),
+		Rule::Ascii { message, code } => validate_ascii(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "ascii"),
+			get_or(message, "must contain only ascii characters"),
+		),
+		Rule::Alphanumeric { message, code } => validate_alphanumeric(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "alphanumeric"),
+			get_or(message, "must contain only letters and digits"),
+		),
+		Rule::Alpha { message, code } => validate_alpha(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "alpha"),
+			get_or(message, "must contain only letters"),
+		),
+		Rule::Numeric { message, code } => validate_numeric(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "numeric"),
+			get_or(message, "must contain only digits"),
+		),
+		Rule::Printable { message, code } => validate_printable(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "printable"),
+			get_or(message, "has non-printable characters"),
+		),
+		Rule::NoControlChars { message, code } => validate_no_control_chars(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "no_control_chars"),
+			get_or(message, "has control characters"),
+		),
+		Rule::NoZeroWidth { message, code } => validate_no_zero_width(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "no_zero_width"),
+			get_or(message, "has invisible characters"),
+		),
+		Rule::SingleLine { message, code } => validate_single_line(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "single_line"),
+			get_or(message, "must be a single line"),
+		),
+		Rule::Charset { allowed, message, code } => validate_charset(
+			as_str(value, field)?,
+			allowed,
+			field.to_string(),
+			get_or(code, "charset"),
+			get_or(message, "has characters outside the allowed set"),
+		),
+		Rule::Allowlist { items, message, code } => match value {
+			Value::Array(values) => validate_allowlist(
+				values.iter(),
+				items.iter(),
+				field.to_string(),
+				get_or(code, "allowlist"),
+				get_or(message, "has item outside allowlist"),
+			),
+			value => validate_allowlist(
+				std::iter::once(&*value),
+				items.iter(),
+				field.to_string(),
+				get_or(code, "allowlist"),
+				get_or(message, "has item outside allowlist"),
+			),
+		},
+		Rule::Blocklist { items, message, code } => match value {
+			Value::Array(values) => validate_blocklist(
+				values.iter(),
+				items.iter(),
+				field.to_string(),
+				get_or(code, "blocklist"),
+				get_or(message, "has item inside blocklist"),
+			),
+			value => validate_blocklist(
+				std::iter::once(&*value),
+				items.iter(),
+				field.to_string(),
+				get_or(code, "blocklist"),
+				get_or(message, "has item inside blocklist"),
+			),
+		},
+		#[cfg(feature = "email")]
+		Rule::Email {
+			allow_display_name,
+			allowed_domains,
+			blocked_domains,
+			require_tld,
+			allow_ip_domain,
+			allow_disposable,
+			message,
+			code,
+		} => {
+			let allowed_domains: Vec<&str> = allowed_domains.iter().map(String::as_str).collect();
+			let blocked_domains: Vec<&str> = blocked_domains.iter().map(String::as_str).collect();
+			let policy = crate::functions::validation::email::EmailPolicy {
+				allow_display_name: *allow_display_name,
+				allowed_domains: &allowed_domains,
+				blocked_domains: &blocked_domains,
+				require_tld: require_tld.unwrap_or(true),
+				allow_ip_domain: *allow_ip_domain,
+				allow_disposable: *allow_disposable,
+			};
+
+			crate::functions::validation::email::validate_email_with_policy(
+				as_str(value, field)?,
+				&policy,
+				field.to_string(),
+				get_or(code, "email"),
+				get_or(message, "invalid email format"),
+			)
+		}
+		#[cfg(feature = "pattern")]
+		Rule::Pattern { pattern, message, code } => crate::functions::validation::pattern::validate_pattern(
+			as_str(value, field)?,
+			pattern.clone(),
+			field.to_string(),
+			get_or(code, "pattern"),
+			get_or(message, "outside the accepted pattern"),
+		),
+		#[cfg(feature = "url")]
+		Rule::Url {
+			schemes,
+			require_host,
+			allowed_hosts,
+			blocked_hosts,
+			deny_private_ips,
+			max_length,
+			message,
+			code,
+		} => {
+			let schemes: Vec<&str> = schemes.iter().map(String::as_str).collect();
+			let allowed_hosts: Vec<&str> = allowed_hosts.iter().map(String::as_str).collect();
+			let blocked_hosts: Vec<&str> = blocked_hosts.iter().map(String::as_str).collect();
+			let policy = crate::functions::validation::url::UrlPolicy {
+				schemes: &schemes,
+				require_host: *require_host,
+				allowed_hosts: &allowed_hosts,
+				blocked_hosts: &blocked_hosts,
+				deny_private_ips: *deny_private_ips,
+				max_length: *max_length,
+			};
+
+			crate::functions::validation::url::validate_url_with_policy(
+				as_str(value, field)?,
+				&policy,
+				field.to_string(),
+				get_or(code, "url"),
+				get_or(message, "invalid url format"),
+			)
+		}
+		#[cfg(feature = "ip")]
+		Rule::Ip {
+			in_networks,
+			not_in_networks,
+			class,
+			message,
+			code,
+		} => with_ip_policy(in_networks, not_in_networks, *class, |policy| {
+			crate::functions::validation::ip::validate_ip_with_policy(
+				as_str(value, field)?,
+				policy,
+				field.to_string(),
+				get_or(code, "ip"),
+				get_or(message, "invalid ip format"),
+			)
+		}),
+		#[cfg(feature = "ip")]
+		Rule::Ipv4 {
+			in_networks,
+			not_in_networks,
+			class,
+			message,
+			code,
+		} => with_ip_policy(in_networks, not_in_networks, *class, |policy| {
+			crate::functions::validation::ip::validate_ipv4_with_policy(
+				as_str(value, field)?,
+				policy,
+				field.to_string(),
+				get_or(code, "ipv4"),
+				get_or(message, "invalid ipv4 format"),
+			)
+		}),
+		#[cfg(feature = "ip")]
+		Rule::Ipv6 {
+			in_networks,
+			not_in_networks,
+			class,
+			message,
+			code,
+		} => with_ip_policy(in_networks, not_in_networks, *class, |policy| {
+			crate::functions::validation::ip::validate_ipv6_with_policy(
+				as_str(value, field)?,
+				policy,
+				field.to_string(),
+				get_or(code, "ipv6"),
+				get_or(message, "invalid ipv6 format"),
+			)
+		}),
+		#[cfg(feature = "ip")]
+		Rule::Cidr {
+			min_prefix,
+			max_prefix,
+			strict,
+			message,
+			code,
+		} => crate::functions::validation::ip::validate_cidr(
+			as_str(value, field)?,
+			*min_prefix,
+			*max_prefix,
+			*strict,
+			field.to_string(),
+			get_or(code, "cidr"),
+			get_or(message, "invalid cidr format"),
+		),
+		#[cfg(feature = "ip")]
+		Rule::SocketAddr { message, code } => crate::functions::validation::ip::validate_socket_addr(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "socket_addr"),
+			get_or(message, "invalid socket address format"),
+		),
+		#[cfg(feature = "ip")]
+		Rule::Port {
+			min,
+			max,
+			message,
+			code,
+		} => {
+			let range = (to_bound(min), to_bound(max));
+			let field = field.to_string();
+			let code = get_or(code, "port");
+			let message = get_or(message, "invalid port");
+
+			match value {
+				Value::String(value) => {
+					crate::functions::validation::ip::validate_port(value.as_str(), range, field, code, message)
+				}
+				Value::Number(value) => crate::functions::validation::ip::validate_port(
+					&value.as_u64().unwrap_or(u64::MAX),
+					range,
+					field,
+					code,
+					message,
+				),
+				_ => Err(type_error(&field, "string or number")),
+			}
+		}
+		#[cfg(feature = "domain")]
+		Rule::Hostname { message, code } => crate::functions::validation::domain::validate_hostname(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "hostname"),
+			get_or(message, "invalid hostname"),
+		),
+		#[cfg(feature = "domain")]
+		Rule::Domain {
+			require_tld,
+			allow_wildcard,
+			suffix_of,
+			message,
+			code,
+		} => {
+			let policy = crate::functions::validation::domain::DomainPolicy {
+				require_tld: require_tld.unwrap_or(true),
+				allow_wildcard: *allow_wildcard,
+				suffix_of: suffix_of.as_deref(),
+			};
+
+			crate::functions::validation::domain::validate_domain(
+				as_str(value, field)?,
+				&policy,
+				field.to_string(),
+				get_or(code, "domain"),
+				get_or(message, "invalid domain"),
+			)
+		}
+		#[cfg(feature = "payments")]
+		Rule::CreditCard { brands, message, code } => {
+			let brands: Vec<&str> = brands.iter().map(String::as_str).collect();
+
+			crate::functions::validation::payments::validate_credit_card(
+				as_str(value, field)?,
+				&brands,
+				field.to_string(),
+				get_or(code, "credit_card"),
+				get_or(message, "invalid credit card number"),
+			)
+		}
+		#[cfg(feature = "payments")]
+		Rule::Luhn { message, code } => crate::functions::validation::payments::validate_luhn(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "luhn"),
+			get_or(message, "invalid luhn checksum"),
+		),
+		#[cfg(feature = "payments")]
+		Rule::Iban {
+			countries,
+			message,
+			code,
+		} => {
+			let countries: Vec<&str> = countries.iter().map(String::as_str).collect();
+
+			crate::functions::validation::payments::validate_iban(
+				as_str(value, field)?,
+				&countries,
+				field.to_string(),
+				get_or(code, "iban"),
+				get_or(message, "invalid iban"),
+			)
+		}
+		#[cfg(feature = "payments")]
+		Rule::Bic { message, code } => crate::functions::validation::payments::validate_bic(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "bic"),
+			get_or(message, "invalid bic"),
+		),
+		#[cfg(feature = "phone")]
+		Rule::Phone {
+			region,
+			regions,
+			types,
+			message,
+			code,
+		} => {
+			let regions: Vec<&str> = regions.iter().map(String::as_str).collect();
+			let types: Vec<&str> = types.iter().map(String::as_str).collect();
+			let policy = crate::functions::validation::phone::PhonePolicy {
+				region: region.as_deref(),
+				regions: &regions,
+				types: &types,
+			};
+
+			crate::functions::validation::phone::validate_phone(
+				as_str(value, field)?,
+				&policy,
+				field.to_string(),
+				get_or(code, "phone"),
+				get_or(message, "invalid phone number"),
+			)
+		}
+		#[cfg(feature = "iso")]
+		Rule::Country { format, message, code } => crate::functions::validation::iso::validate_country(
+			as_str(value, field)?,
+			format.as_deref(),
+			field.to_string(),
+			get_or(code, "country"),
+			get_or(message, "invalid country code"),
+		),
+		#[cfg(feature = "iso")]
+		Rule::Currency { message, code } => crate::functions::validation::iso::validate_currency(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "currency"),
+			get_or(message, "invalid currency code"),
+		),
+		#[cfg(feature = "iso")]
+		Rule::LanguageTag { message, code } => crate::functions::validation::iso::validate_language_tag(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "language_tag"),
+			get_or(message, "invalid language tag"),
+		),
+		#[cfg(feature = "iso")]
+		Rule::Timezone { message, code } => crate::functions::validation::iso::validate_timezone(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "timezone"),
+			get_or(message, "invalid timezone"),
+		),
+		#[cfg(feature = "password")]
+		Rule::Password {
+			min_entropy,
+			min_score,
+			require,
+			forbid_fields,
+			max_repeats,
+			reference_year,
+			message,
+			code,
+		} => {
+			let require: Vec<&str> = require.iter().map(String::as_str).collect();
+			let forbidden: Vec<Option<&str>> = forbid_fields
+				.iter()
+				.map(|name| siblings.and_then(|siblings| siblings.get(name)).and_then(Value::as_str))
+				.collect();
+
+			let policy = crate::functions::validation::password::PasswordPolicy {
+				min_entropy: *min_entropy,
+				min_score: *min_score,
+				require: &require,
+				forbidden: &forbidden,
+				max_repeats: *max_repeats,
+				reference_year: *reference_year,
+			};
+
+			crate::functions::validation::password::validate_password(
+				as_str(value, field)?,
+				&policy,
+				field.to_string(),
+				get_or(code, "password"),
+				get_or(message, "weak password"),
+			)
+		}
+		#[cfg(feature = "unicode")]
+		Rule::UnicodeScripts { allowed, message, code } => {
+			let allowed: Vec<&str> = allowed.iter().map(String::as_str).collect();
+			let allowed = crate::functions::validation::unicode::parse_scripts(&allowed);
+
+			crate::functions::validation::unicode::validate_unicode_scripts(
+				as_str(value, field)?,
+				&allowed,
+				field.to_string(),
+				get_or(code, "unicode_scripts"),
+				get_or(message, "has characters from disallowed scripts"),
+			)
+		}
+		#[cfg(feature = "unicode")]
+		Rule::NoMixedScript { message, code } => crate::functions::validation::unicode::validate_no_mixed_script(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "no_mixed_script"),
+			get_or(message, "mixes characters from different scripts"),
+		),
+		#[cfg(feature = "unicode")]
+		Rule::ConfusableSafe { level, message, code } => crate::functions::validation::unicode::validate_confusable_safe(
+			as_str(value, field)?,
+			level.as_deref(),
+			field.to_string(),
+			get_or(code, "confusable_safe"),
+			get_or(message, "has confusable characters"),
+		),
+		#[cfg(feature = "uuid")]
+		Rule::Uuid { message, code } => crate::functions::validation::uuid::validate_uuid(
+			as_str(value, field)?,
+			field.to_string(),
+			get_or(code, "uuid"),
+			get_or(message, "invalid uuid format"),
+		),
+		Rule::Nested { schema, code } => {
+			if !value.is_object() {
+				return Err(type_error(field, "object"));
+			}
+
+			schema.execute(value, modificate).map_err(|errors| {
+				NestedValidationError::from_with_code(errors, field.to_string(), get_or(code, "nested")).into()
+			})
+		}
+		modification if modificate => execute_modification(modification, value, field),
+		_ => Ok(()),
+	}
+}
+
+fn execute_modification(rule: &Rule, value: &mut Value, field: &str) -> Result<(), ValidationError> {
+	let Value::String(value) = value else {
+		return Err(type_error(field, "string"));
+	};
+
+	match rule {
+		Rule::Trim => *value = value.trim().to_string(),
+		Rule::TrimStart => *value = value.trim_start().to_string(),
+		Rule::TrimEnd => *value = value.trim_end().to_string(),
+		Rule::Truncate { max, unit } => truncate(value, *max, unit.unwrap_or_else(ValidationSettings::get_length_unit)),
+		#[cfg(feature = "email")]
+		Rule::NormalizeEmail { strip_tag } => crate::functions::modification::email::normalize_email(value, *strip_tag),
+		#[cfg(feature = "domain")]
+		Rule::Punycode => crate::functions::modification::domain::punycode(value),
+		#[cfg(feature = "phone")]
+		Rule::E164 { region } => crate::functions::modification::phone::e164(value, region.as_deref()),
+		Rule::Uppercase => *value = value.to_uppercase(),
+		Rule::Lowercase => *value = value.to_lowercase(),
+		Rule::DigitsOnly => crate::functions::modification::text::digits_only(value),
+		Rule::Capitalize => capitalize(value),
+		Rule::CamelCase => camel_case(value),
+		Rule::LowerCamelCase => lower_camel_case(value),
+		Rule::SnakeCase => snake_case(value),
+		Rule::ShoutySnakeCase => shouty_snake_case(value),
+		Rule::KebabCase => kebab_case(value),
+		Rule::ShoutyKebabCase => shouty_kebab_case(value),
+		Rule::TrainCase => train_case(value),
+		_ => {}
+	}
+
+	Ok(())
+}
+
+#[cfg(feature = "ip")]
+fn with_ip_policy<T>(
+	in_networks: &[String],
+	not_in_networks: &[String],
+	class: Option<crate::utils::ip::IpClass>,
+	validate: impl FnOnce(&crate::utils::ip::IpPolicy) -> T,
+) -> T {
+	let in_networks: Vec<_> = in_networks
+		.iter()
+		.map(|network| crate::utils::ip::IpNetwork::parse(network))
+		.collect();
+	let not_in_networks: Vec<_> = not_in_networks
+		.iter()
+		.map(|network| crate::utils::ip::IpNetwork::parse(network))
+		.collect();
+
+	validate(&crate::utils::ip::IpPolicy {
+		in_networks: &in_networks,
+		not_in_networks: &not_in_networks,
+		class,
+	})
+}
+
+fn as_str<'a>(value: &'a Value, field: &str) -> Result<&'a str, ValidationError> {
+	value.as_str().ok_or_else(|| type_error(field, "string"))
+}
+
+fn type_error(field: &str, expected: &str) -> ValidationError {
+	ValidationError::builder()
+		.with_field(field.to_string())
+		.as_simple("type")
+		.with_message(format!("expected {}", expected))
+		.build()
+		.into()
+}
+
+fn to_bound<T: Copy>(value: &Option<T>) -> Bound<T> {
+	match value {
+		Some(value) => Bound::Included(*value),
+		None => Bound::Unbounded,
+	}
+}
+
+fn get_or(value: &Option<String>, default: &'static str) -> Cow<'static, str> {
+	match value {
+		Some(value) => Cow::Owned(value.clone()),
+		None => Cow::Borrowed(default),
+	}
+}
+
+struct FieldsRef<'a>(&'a [(String, Vec<Rule>)]);
+
+impl Serialize for FieldsRef<'_> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let mut map = serializer.serialize_map(Some(self.0.len()))?;
+
+		for (name, rules) in self.0 {
+			map.serialize_entry(name, rules)?;
+		}
+
+		map.end()
+	}
+}
+
+impl Serialize for Schema {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		let len = 1 + usize::from(self.failure_mode.is_some());
+		let mut map = serializer.serialize_map(Some(len))?;
+
+		if let Some(failure_mode) = &self.failure_mode {
+			map.serialize_entry(FAILURE_MODE_KEY, failure_mode)?;
+		}
+
+		map.serialize_entry(FIELDS_KEY, &FieldsRef(&self.fields))?;
+		map.end()
+	}
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FailureModeEntry {
+	FailureMode(FailureMode),
+	Rules(Vec<Rule>),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FieldsEntry {
+	Fields(Fields),
+	Rules(Vec<Rule>),
+}
+
+struct Fields(Vec<(String, Vec<Rule>)>);
+
+impl<'de> Deserialize<'de> for Fields {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct FieldsVisitor;
+
+		impl<'de> Visitor<'de> for FieldsVisitor {
+			type Value = Fields;
+
+			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+				formatter.write_str("a map of field names to rules")
+			}
+
+			fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Fields, A::Error> {
+				let mut fields = Vec::new();
+
+				while let Some(name) = access.next_key::<String>()? {
+					fields.push((name, access.next_value::<Vec<Rule>>()?));
+				}
+
+				Ok(Fields(fields))
+			}
+		}
+
+		deserializer.deserialize_map(FieldsVisitor)
+	}
+}
+
+impl<'de> Deserialize<'de> for Schema {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct SchemaVisitor;
+
+		impl<'de> Visitor<'de> for SchemaVisitor {
+			type Value = Schema;
+
+			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+				formatter.write_str("a map of field names to rules")
+			}
+
+			fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Schema, A::Error> {
+				let mut schema = Schema::new();
+
+				while let Some(name) = access.next_key::<String>()? {
+					schema = match name.as_str() {
+						FAILURE_MODE_KEY => match access.next_value()? {
+							FailureModeEntry::FailureMode(failure_mode) => schema.with_failure_mode(failure_mode),
+							FailureModeEntry::Rules(rules) => schema.field(name, rules),
+						},
+						FIELDS_KEY => match access.next_value()? {
+							FieldsEntry::Fields(Fields(fields)) => fields
+								.into_iter()
+								.fold(schema, |schema, (name, rules)| schema.field(name, rules)),
+							FieldsEntry::Rules(rules) => schema.field(name, rules),
+						},
+						_ => schema.field(name, access.next_value::<Vec<Rule>>()?),
+					};
+				}
+
+				Ok(schema)
+			}
+		}
+
+		deserializer.deserialize_map(SchemaVisitor)
+	}
+}
@@ -1,39 +1,73 @@
-pub fn capitalize(value: &mut String) {
+use ::validy::functions::modification::text::Text;
+
+pub fn capitalize<T: Text + ?Sized>(value: &mut T) {
 	use heck::ToTitleCase;
-	*value = value.to_title_case();
+	let cased = value.as_text().to_title_case();
+
+	if cased != value.as_text() {
+		value.replace(cased);
+	}
 }
 
-pub fn camel_case(value: &mut String) {
+pub fn camel_case<T: Text + ?Sized>(value: &mut T) {
 	use heck::ToUpperCamelCase;
-	*value = value.to_upper_camel_case();
+	let cased = value.as_text().to_upper_camel_case();
+
+	if cased != value.as_text() {
+		value.replace(cased);
+	}
 }
 
-pub fn lower_camel_case(value: &mut String) {
+pub fn lower_camel_case<T: Text + ?Sized>(value: &mut T) {
 	use heck::ToLowerCamelCase;
-	*value = value.to_lower_camel_case()
+	let cased = value.as_text().to_lower_camel_case();
+
+	if cased != value.as_text() {
+		value.replace(cased);
+	}
 }
 
-pub fn snake_case(value: &mut String) {
+pub fn snake_case<T: Text + ?Sized>(value: &mut T) {
 	use heck::ToSnakeCase;
-	*value = value.to_snake_case()
+	let cased = value.as_text().to_snake_case();
+
+	if cased != value.as_text() {
+		value.replace(cased);
+	}
 }
 
-pub fn shouty_snake_case(value: &mut String) {
+pub fn shouty_snake_case<T: Text + ?Sized>(value: &mut T) {
 	use heck::ToShoutySnakeCase;
-	*value = value.to_shouty_snake_case()
+	let cased = value.as_text().to_shouty_snake_case();
+
+	if cased != value.as_text() {
+		value.replace(cased);
+	}
 }
 
-pub fn kebab_case(value: &mut String) {
+pub fn kebab_case<T: Text + ?Sized>(value: &mut T) {
 	use heck::ToKebabCase;
-	*value = value.to_kebab_case()
+	let cased = value.as_text().to_kebab_case();
+
+	if cased != value.as_text() {
+		value.replace(cased);
+	}
 }
 
-pub fn shouty_kebab_case(value: &mut String) {
+pub fn shouty_kebab_case<T: Text + ?Sized>(value: &mut T) {
 	use heck::ToShoutyKebabCase;
-	*value = value.to_shouty_kebab_case()
+	let cased = value.as_text().to_shouty_kebab_case();
+
+	if cased != value.as_text() {
+		value.replace(cased);
+	}
 }
 
-pub fn train_case(value: &mut String) {
+pub fn train_case<T: Text + ?Sized>(value: &mut T) {
 	use heck::ToTrainCase;
-	*value = value.to_train_case();
+	let cased = value.as_text().to_train_case();
+
+	if cased != value.as_text() {
+		value.replace(cased);
+	}
 }
@@ -0,0 +1,11 @@
+use ::validy::functions::modification::text::Text;
+
+pub fn punycode<T: Text + ?Sized>(value: &mut T) {
+	let text = value.as_text();
+
+	if let Ok(ascii) = idna::domain_to_ascii(text)
+		&& ascii != text
+	{
+		value.replace(ascii);
+	}
+}
@@ -0,0 +1,23 @@
+use ::validy::functions::modification::text::Text;
+
+pub fn normalize_email<T: Text + ?Sized>(value: &mut T, strip_tag: bool) {
+	let text = value.as_text();
+	let Some((local, domain)) = text.rsplit_once('@') else {
+		return;
+	};
+
+	let local = match strip_tag && !local.starts_with('"') {
+		true => local.split_once('+').map_or(local, |(local, _)| local),
+		false => local,
+	};
+
+	let domain = match domain.starts_with('[') {
+		true => domain.to_ascii_lowercase(),
+		false => idna::domain_to_ascii(domain).unwrap_or_else(|_| domain.to_lowercase()),
+	};
+
+	let normalized = format!("{}@{}", local, domain);
+	if normalized != text {
+		value.replace(normalized);
+	}
+}
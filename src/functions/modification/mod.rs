@@ -1 +1,8 @@
 pub mod cases;
+#[cfg(feature = "domain")]
+pub mod domain;
+#[cfg(feature = "email")]
+pub mod email;
+#[cfg(feature = "phone")]
+pub mod phone;
+pub mod text;
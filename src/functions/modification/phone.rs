@@ -0,0 +1,12 @@
+use ::validy::functions::modification::text::Text;
+use ::validy::utils::phone::to_e164;
+
+pub fn e164<T: Text + ?Sized>(value: &mut T, region: Option<&str>) {
+	let text = value.as_text();
+
+	if let Some(number) = to_e164(text, region)
+		&& number != text
+	{
+		value.replace(number);
+	}
+}
@@ -0,0 +1,107 @@
+use std::borrow::Cow;
+
+use ::validy::{settings::LengthUnit, utils::length::truncate_index};
+
+pub trait Text {
+	fn as_text(&self) -> &str;
+	fn keep(&mut self, start: usize, end: usize);
+	fn replace(&mut self, value: String);
+}
+
+impl Text for String {
+	fn as_text(&self) -> &str {
+		self.as_str()
+	}
+
+	fn keep(&mut self, start: usize, end: usize) {
+		self.truncate(end);
+		self.drain(..start);
+	}
+
+	fn replace(&mut self, value: String) {
+		*self = value;
+	}
+}
+
+impl Text for Cow<'_, str> {
+	fn as_text(&self) -> &str {
+		self
+	}
+
+	fn keep(&mut self, start: usize, end: usize) {
+		match self {
+			Cow::Borrowed(value) => *value = &value[start..end],
+			Cow::Owned(value) => value.keep(start, end),
+		}
+	}
+
+	fn replace(&mut self, value: String) {
+		*self = Cow::Owned(value);
+	}
+}
+
+pub fn trim<T: Text + ?Sized>(value: &mut T) {
+	let text = value.as_text();
+	let trimmed = text.trim_start();
+	let start = text.len() - trimmed.len();
+	let end = start + trimmed.trim_end().len();
+
+	if start != 0 || end != text.len() {
+		value.keep(start, end);
+	}
+}
+
+pub fn trim_start<T: Text + ?Sized>(value: &mut T) {
+	let text = value.as_text();
+	let end = text.len();
+	let start = end - text.trim_start().len();
+
+	if start != 0 {
+		value.keep(start, end);
+	}
+}
+
+pub fn trim_end<T: Text + ?Sized>(value: &mut T) {
+	let text = value.as_text();
+	let end = text.trim_end().len();
+
+	if end != text.len() {
+		value.keep(0, end);
+	}
+}
+
+pub fn lowercase<T: Text + ?Sized>(value: &mut T) {
+	let text = value.as_text();
+
+	if text.chars().any(|char| !char.to_lowercase().eq([char])) {
+		let lowercase = text.to_lowercase();
+		value.replace(lowercase);
+	}
+}
+
+pub fn uppercase<T: Text + ?Sized>(value: &mut T) {
+	let text = value.as_text();
+
+	if text.chars().any(|char| !char.to_uppercase().eq([char])) {
+		let uppercase = text.to_uppercase();
+		value.replace(uppercase);
+	}
+}
+
+pub fn digits_only<T: Text + ?Sized>(value: &mut T) {
+	let text = value.as_text();
+
+	if !text.bytes().all(|byte| byte.is_ascii_digit()) {
+		let digits = text.chars().filter(char::is_ascii_digit).collect();
+		value.replace(digits);
+	}
+}
+
+pub fn truncate<T: Text + ?Sized>(value: &mut T, max: usize, unit: LengthUnit) {
+	let text = value.as_text();
+	let end = truncate_index(text, max, unit);
+
+	if end != text.len() {
+		value.keep(0, end);
+	}
+}
@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+pub use crate::utils::domain::{Domain, DomainPolicy};
+
+use crate::core::ValidationError;
+
+pub fn default_domain() -> Domain {
+	Domain::default()
+}
+
+pub fn parse_domain(
+	value: &str,
+	policy: &DomainPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> (Domain, Option<ValidationError>) {
+	match policy.parse(value) {
+		Some(domain) => (domain, None),
+		None => (
+			default_domain(),
+			Some(
+				ValidationError::builder()
+					.with_field(field)
+					.as_simple(code)
+					.with_message(message)
+					.build()
+					.into(),
+			),
+		),
+	}
+}
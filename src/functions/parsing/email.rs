@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+pub use crate::utils::email::EmailPolicy;
+use email_address::EmailAddress;
+
+use crate::core::ValidationError;
+
+pub fn default_email() -> EmailAddress {
+	EmailAddress::new_unchecked("invalid@invalid")
+}
+
+pub fn parse_email(
+	value: &str,
+	policy: &EmailPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> (EmailAddress, Option<ValidationError>) {
+	match policy.parse(value) {
+		Some(email) => (email, None),
+		None => (
+			default_email(),
+			Some(
+				ValidationError::builder()
+					.with_field(field)
+					.as_simple(code)
+					.with_message(message)
+					.build()
+					.into(),
+			),
+		),
+	}
+}
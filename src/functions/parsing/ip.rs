@@ -1,9 +1,12 @@
 use std::{
 	borrow::Cow,
-	net::{IpAddr, Ipv4Addr, Ipv6Addr},
+	net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
 };
 
-use crate::core::ValidationError;
+pub use crate::utils::ip::{IpClass, IpNetwork, IpPolicy};
+use ipnet::{IpNet, Ipv4Net};
+
+use crate::{core::ValidationError, utils::ip::check_cidr};
 
 pub fn default_ip() -> IpAddr {
 	IpAddr::V4(Ipv4Addr::UNSPECIFIED)
@@ -14,10 +17,22 @@ pub fn parse_ip(
 	field: impl Into<Cow<'static, str>>,
 	code: impl Into<Cow<'static, str>>,
 	message: impl Into<Cow<'static, str>>,
+) -> (IpAddr, Option<ValidationError>) {
+	parse_ip_with_policy(value, &IpPolicy::default(), field, code, message)
+}
+
+pub fn parse_ip_with_policy(
+	value: &str,
+	policy: &IpPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
 ) -> (IpAddr, Option<ValidationError>) {
 	let result: Result<IpAddr, _> = value.parse();
 
-	if let Ok(result) = result {
+	if let Ok(result) = result
+		&& policy.allows(&result)
+	{
 		(result, None)
 	} else {
 		(
@@ -43,10 +58,22 @@ pub fn parse_ipv4(
 	field: impl Into<Cow<'static, str>>,
 	code: impl Into<Cow<'static, str>>,
 	message: impl Into<Cow<'static, str>>,
+) -> (Ipv4Addr, Option<ValidationError>) {
+	parse_ipv4_with_policy(value, &IpPolicy::default(), field, code, message)
+}
+
+pub fn parse_ipv4_with_policy(
+	value: &str,
+	policy: &IpPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
 ) -> (Ipv4Addr, Option<ValidationError>) {
 	let result: Result<Ipv4Addr, _> = value.parse();
 
-	if let Ok(result) = result {
+	if let Ok(result) = result
+		&& policy.allows(&IpAddr::V4(result))
+	{
 		(result, None)
 	} else {
 		(
@@ -72,10 +99,22 @@ pub fn parse_ipv6(
 	field: impl Into<Cow<'static, str>>,
 	code: impl Into<Cow<'static, str>>,
 	message: impl Into<Cow<'static, str>>,
+) -> (Ipv6Addr, Option<ValidationError>) {
+	parse_ipv6_with_policy(value, &IpPolicy::default(), field, code, message)
+}
+
+pub fn parse_ipv6_with_policy(
+	value: &str,
+	policy: &IpPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
 ) -> (Ipv6Addr, Option<ValidationError>) {
 	let result: Result<Ipv6Addr, _> = value.parse();
 
-	if let Ok(result) = result {
+	if let Ok(result) = result
+		&& policy.allows(&IpAddr::V6(result))
+	{
 		(result, None)
 	} else {
 		(
@@ -91,3 +130,61 @@ pub fn parse_ipv6(
 		)
 	}
 }
+
+pub fn default_cidr() -> IpNet {
+	IpNet::V4(Ipv4Net::default())
+}
+
+pub fn parse_cidr(
+	value: &str,
+	min_prefix: Option<u8>,
+	max_prefix: Option<u8>,
+	strict: bool,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> (IpNet, Option<ValidationError>) {
+	match check_cidr(value, min_prefix, max_prefix, strict) {
+		Some(network) => (network, None),
+		None => (
+			default_cidr(),
+			Some(
+				ValidationError::builder()
+					.with_field(field)
+					.as_simple(code)
+					.with_message(message)
+					.build()
+					.into(),
+			),
+		),
+	}
+}
+
+pub fn default_socket_addr() -> SocketAddr {
+	SocketAddr::new(default_ip(), 0)
+}
+
+pub fn parse_socket_addr(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> (SocketAddr, Option<ValidationError>) {
+	let result: Result<SocketAddr, _> = value.parse();
+
+	if let Ok(result) = result {
+		(result, None)
+	} else {
+		(
+			default_socket_addr(),
+			Some(
+				ValidationError::builder()
+					.with_field(field)
+					.as_simple(code)
+					.with_message(message)
+					.build()
+					.into(),
+			),
+		)
+	}
+}
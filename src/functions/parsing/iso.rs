@@ -0,0 +1,113 @@
+use std::borrow::Cow;
+
+pub use crate::utils::iso::{CountryCode, Currency, LanguageTag, Tz};
+
+use crate::{
+	core::ValidationError,
+	utils::iso::{country, currency, language_tag, timezone},
+};
+
+pub fn default_country() -> CountryCode {
+	CountryCode::ATA
+}
+
+pub fn parse_country(
+	value: &str,
+	format: Option<&str>,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> (CountryCode, Option<ValidationError>) {
+	match country(value, format) {
+		Some(result) => (result, None),
+		None => (
+			default_country(),
+			Some(
+				ValidationError::builder()
+					.with_field(field)
+					.as_simple(code)
+					.with_message(message)
+					.build()
+					.into(),
+			),
+		),
+	}
+}
+
+pub fn default_currency() -> Currency {
+	currency("XXX").expect("XXX is a known currency")
+}
+
+pub fn parse_currency(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> (Currency, Option<ValidationError>) {
+	match currency(value) {
+		Some(result) => (result, None),
+		None => (
+			default_currency(),
+			Some(
+				ValidationError::builder()
+					.with_field(field)
+					.as_simple(code)
+					.with_message(message)
+					.build()
+					.into(),
+			),
+		),
+	}
+}
+
+pub fn default_language_tag() -> LanguageTag {
+	LanguageTag::parse("und").expect("und is a well-formed language tag")
+}
+
+pub fn parse_language_tag(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> (LanguageTag, Option<ValidationError>) {
+	match language_tag(value) {
+		Some(result) => (result, None),
+		None => (
+			default_language_tag(),
+			Some(
+				ValidationError::builder()
+					.with_field(field)
+					.as_simple(code)
+					.with_message(message)
+					.build()
+					.into(),
+			),
+		),
+	}
+}
+
+pub fn default_timezone() -> Tz {
+	Tz::UTC
+}
+
+pub fn parse_timezone(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> (Tz, Option<ValidationError>) {
+	match timezone(value) {
+		Some(result) => (result, None),
+		None => (
+			default_timezone(),
+			Some(
+				ValidationError::builder()
+					.with_field(field)
+					.as_simple(code)
+					.with_message(message)
+					.build()
+					.into(),
+			),
+		),
+	}
+}
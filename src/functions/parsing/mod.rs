@@ -1,6 +1,16 @@
+#[cfg(feature = "domain")]
+pub mod domain;
+#[cfg(feature = "email")]
+pub mod email;
 #[cfg(feature = "ip")]
 pub mod ip;
+#[cfg(feature = "iso")]
+pub mod iso;
+#[cfg(feature = "phone")]
+pub mod phone;
 #[cfg(feature = "time")]
 pub mod time;
+#[cfg(feature = "url")]
+pub mod url;
 #[cfg(feature = "uuid")]
 pub mod uuid;
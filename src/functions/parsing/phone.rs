@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+pub use crate::utils::phone::PhonePolicy;
+pub use phonenumber::PhoneNumber;
+
+use crate::core::ValidationError;
+
+pub fn default_phone() -> PhoneNumber {
+	phonenumber::parse(None, "+111").expect("+111 is a parseable phone number")
+}
+
+pub fn parse_phone(
+	value: &str,
+	policy: &PhonePolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> (PhoneNumber, Option<ValidationError>) {
+	match policy.parse(value) {
+		Some(number) => (number, None),
+		None => (
+			default_phone(),
+			Some(
+				ValidationError::builder()
+					.with_field(field)
+					.as_simple(code)
+					.with_message(message)
+					.build()
+					.into(),
+			),
+		),
+	}
+}
@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+pub use crate::utils::url::UrlPolicy;
+use url::Url;
+
+use crate::core::ValidationError;
+
+pub fn default_url() -> Url {
+	Url::parse("about:blank").expect("about:blank is a valid url")
+}
+
+pub fn parse_url(
+	value: &str,
+	policy: &UrlPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> (Url, Option<ValidationError>) {
+	match policy.parse(value) {
+		Some(url) => (url, None),
+		None => (
+			default_url(),
+			Some(
+				ValidationError::builder()
+					.with_field(field)
+					.as_simple(code)
+					.with_message(message)
+					.build()
+					.into(),
+			),
+		),
+	}
+}
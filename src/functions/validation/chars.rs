@@ -0,0 +1,175 @@
+use ::validy::core::ValidationError;
+use ::validy::utils::chars::{Charset, is_disallowed_control, is_line_break, is_printable, is_zero_width};
+use std::borrow::Cow;
+
+pub fn validate_ascii(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if !value.is_ascii() {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_alphanumeric(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if !value.chars().all(char::is_alphanumeric) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_alpha(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if !value.chars().all(char::is_alphabetic) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_numeric(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if !value.chars().all(char::is_numeric) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_printable(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if !value.chars().all(is_printable) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_no_control_chars(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if value.chars().any(is_disallowed_control) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_no_zero_width(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if value.chars().any(is_zero_width) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_single_line(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if value.chars().any(is_line_break) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_charset(
+	value: &str,
+	allowed: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	let outside = match Charset::parse(allowed) {
+		Some(charset) => charset.find_outside(value).map(Some),
+		None => Some(None),
+	};
+
+	if let Some(char) = outside {
+		let mut error = ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message);
+
+		if let Some(char) = char {
+			error = error.with_param("char", char.to_string());
+		}
+
+		return Err(error.build().into());
+	}
+
+	Ok(())
+}
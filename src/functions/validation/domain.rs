@@ -0,0 +1,41 @@
+use ::validy::core::ValidationError;
+pub use ::validy::utils::domain::DomainPolicy;
+use ::validy::utils::domain::is_hostname;
+use std::borrow::Cow;
+
+pub fn validate_hostname(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if !is_hostname(value) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_domain(
+	value: &str,
+	policy: &DomainPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if policy.parse(value).is_none() {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
@@ -1,4 +1,5 @@
 use ::validy::core::ValidationError;
+pub use ::validy::utils::email::EmailPolicy;
 use std::borrow::Cow;
 
 pub fn validate_email(
@@ -7,8 +8,17 @@ pub fn validate_email(
 	code: impl Into<Cow<'static, str>>,
 	message: impl Into<Cow<'static, str>>,
 ) -> Result<(), ValidationError> {
-	use email_address::EmailAddress;
-	if !EmailAddress::is_valid(value) {
+	validate_email_with_policy(value, &EmailPolicy::default(), field, code, message)
+}
+
+pub fn validate_email_with_policy(
+	value: &str,
+	policy: &EmailPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if policy.parse(value).is_none() {
 		return Err(ValidationError::builder()
 			.with_field(field)
 			.as_simple(code)
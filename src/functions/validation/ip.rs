@@ -1,7 +1,10 @@
 use ::validy::core::ValidationError;
+use ::validy::utils::ip::check_cidr;
+pub use ::validy::utils::ip::{IpClass, IpNetwork, IpPolicy};
 use std::{
 	borrow::Cow,
-	net::{IpAddr, Ipv4Addr, Ipv6Addr},
+	net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+	ops::RangeBounds,
 };
 
 pub fn validate_ip(
@@ -9,10 +12,20 @@ pub fn validate_ip(
 	field: impl Into<Cow<'static, str>>,
 	code: impl Into<Cow<'static, str>>,
 	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	validate_ip_with_policy(value, &IpPolicy::default(), field, code, message)
+}
+
+pub fn validate_ip_with_policy(
+	value: &str,
+	policy: &IpPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
 ) -> Result<(), ValidationError> {
 	let ip: Result<IpAddr, _> = value.parse();
 
-	if ip.is_err() {
+	if !ip.is_ok_and(|ip| policy.allows(&ip)) {
 		return Err(ValidationError::builder()
 			.with_field(field)
 			.as_simple(code)
@@ -29,10 +42,20 @@ pub fn validate_ipv4(
 	field: impl Into<Cow<'static, str>>,
 	code: impl Into<Cow<'static, str>>,
 	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	validate_ipv4_with_policy(value, &IpPolicy::default(), field, code, message)
+}
+
+pub fn validate_ipv4_with_policy(
+	value: &str,
+	policy: &IpPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
 ) -> Result<(), ValidationError> {
 	let ip: Result<Ipv4Addr, _> = value.parse();
 
-	if ip.is_err() {
+	if !ip.is_ok_and(|ip| policy.allows(&IpAddr::V4(ip))) {
 		return Err(ValidationError::builder()
 			.with_field(field)
 			.as_simple(code)
@@ -49,10 +72,110 @@ pub fn validate_ipv6(
 	field: impl Into<Cow<'static, str>>,
 	code: impl Into<Cow<'static, str>>,
 	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	validate_ipv6_with_policy(value, &IpPolicy::default(), field, code, message)
+}
+
+pub fn validate_ipv6_with_policy(
+	value: &str,
+	policy: &IpPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
 ) -> Result<(), ValidationError> {
 	let ip: Result<Ipv6Addr, _> = value.parse();
 
-	if ip.is_err() {
+	if !ip.is_ok_and(|ip| policy.allows(&IpAddr::V6(ip))) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_cidr(
+	value: &str,
+	min_prefix: Option<u8>,
+	max_prefix: Option<u8>,
+	strict: bool,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if check_cidr(value, min_prefix, max_prefix, strict).is_none() {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_socket_addr(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	let address: Result<SocketAddr, _> = value.parse();
+
+	if address.is_err() {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub trait Port {
+	fn as_port(&self) -> Option<u16>;
+}
+
+impl Port for str {
+	fn as_port(&self) -> Option<u16> {
+		self.parse().ok()
+	}
+}
+
+impl Port for String {
+	fn as_port(&self) -> Option<u16> {
+		self.as_str().as_port()
+	}
+}
+
+macro_rules! impl_integer_port {
+	($($integer:ty),* $(,)?) => {
+		$(
+			impl Port for $integer {
+				fn as_port(&self) -> Option<u16> {
+					u16::try_from(*self).ok()
+				}
+			}
+		)*
+	};
+}
+
+impl_integer_port!(u16, u32, u64, usize, i16, i32, i64, isize);
+
+pub fn validate_port<R: RangeBounds<u16>, T: Port + ?Sized>(
+	value: &T,
+	range: R,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if !value.as_port().is_some_and(|port| range.contains(&port)) {
 		return Err(ValidationError::builder()
 			.with_field(field)
 			.as_simple(code)
@@ -1,4 +1,5 @@
-use ::validy::core::ValidationError;
+pub use ::validy::utils::length::Length;
+use ::validy::{core::ValidationError, settings::LengthUnit};
 use std::{borrow::Cow, ops::RangeBounds};
 
 pub fn validate_length<R, T, U>(
@@ -25,3 +26,7 @@ where
 
 	Ok(())
 }
+
+pub fn measure_length<T: Length + ?Sized>(value: &T, unit: LengthUnit) -> usize {
+	value.measure(unit)
+}
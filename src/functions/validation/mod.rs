@@ -1,4 +1,7 @@
+pub mod chars;
 pub mod contains;
+#[cfg(feature = "domain")]
+pub mod domain;
 #[cfg(feature = "email")]
 pub mod email;
 #[cfg(feature = "axum_multipart_field_data")]
@@ -6,16 +9,26 @@ pub mod field_data;
 pub mod inline;
 #[cfg(feature = "ip")]
 pub mod ip;
+#[cfg(feature = "iso")]
+pub mod iso;
 pub mod iter;
 pub mod length;
+#[cfg(feature = "password")]
+pub mod password;
 #[cfg(feature = "pattern")]
 pub mod pattern;
+#[cfg(feature = "payments")]
+pub mod payments;
+#[cfg(feature = "phone")]
+pub mod phone;
 pub mod prefix;
 pub mod range;
 pub mod suffix;
 #[cfg(feature = "time")]
 pub mod time;
-#[cfg(feature = "pattern")]
+#[cfg(feature = "unicode")]
+pub mod unicode;
+#[cfg(feature = "url")]
 pub mod url;
 #[cfg(feature = "uuid")]
 pub mod uuid;
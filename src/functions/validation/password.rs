@@ -0,0 +1,27 @@
+use ::validy::core::ValidationError;
+pub use ::validy::utils::password::PasswordPolicy;
+use std::borrow::Cow;
+
+pub fn validate_password(
+	value: &str,
+	policy: &PasswordPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if let Err(estimate) = policy.check(value) {
+		let mut error = ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.with_param("score", estimate.score.to_string());
+
+		if !estimate.suggestions.is_empty() {
+			error = error.with_param("suggestions", estimate.suggestions.join(","));
+		}
+
+		return Err(error.build().into());
+	}
+
+	Ok(())
+}
@@ -0,0 +1,96 @@
+use ::validy::core::ValidationError;
+use ::validy::utils::payments::{card_brand, iban_country, is_bic, is_card_number, is_iban, is_luhn_valid};
+use std::borrow::Cow;
+
+pub fn validate_credit_card(
+	value: &str,
+	brands: &[&str],
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	let brand = card_brand(value);
+	let is_allowed = brand
+		.as_deref()
+		.is_some_and(|brand| brands.is_empty() || brands.iter().any(|allowed| allowed.eq_ignore_ascii_case(brand)));
+
+	if !is_allowed || !is_card_number(value) {
+		let mut error = ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message);
+
+		if let Some(brand) = brand {
+			error = error.with_param("brand", brand);
+		}
+
+		return Err(error.build().into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_luhn(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if !is_luhn_valid(value) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_iban(
+	value: &str,
+	countries: &[&str],
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	let country = iban_country(value).map(str::to_ascii_uppercase);
+	let is_allowed = countries.is_empty()
+		|| country
+			.as_deref()
+			.is_some_and(|country| countries.iter().any(|allowed| allowed.eq_ignore_ascii_case(country)));
+
+	if !is_allowed || !is_iban(value) {
+		let mut error = ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message);
+
+		if let Some(country) = country {
+			error = error.with_param("country", country);
+		}
+
+		return Err(error.build().into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_bic(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if !is_bic(value) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
@@ -0,0 +1,22 @@
+use ::validy::core::ValidationError;
+pub use ::validy::utils::phone::PhonePolicy;
+use std::borrow::Cow;
+
+pub fn validate_phone(
+	value: &str,
+	policy: &PhonePolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if policy.parse(value).is_none() {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
@@ -0,0 +1,81 @@
+use ::validy::core::ValidationError;
+pub use ::validy::utils::unicode::{AllowedScripts, parse_scripts};
+use ::validy::utils::unicode::{
+	RestrictionLevel, find_disallowed_script, is_confusable_safe, is_single_script, restriction_level,
+};
+use std::borrow::Cow;
+
+pub fn validate_unicode_scripts(
+	value: &str,
+	allowed: &AllowedScripts,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	let allowed = match allowed {
+		Ok(allowed) => allowed,
+		Err(name) => {
+			return Err(ValidationError::builder()
+				.with_field(field)
+				.as_simple("bad-script")
+				.with_message("can't find a script by provided name")
+				.with_param("script", name.clone())
+				.build()
+				.into());
+		}
+	};
+
+	if let Some(script) = find_disallowed_script(value, allowed) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.with_param("script", script.full_name())
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_no_mixed_script(
+	value: &str,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if !is_single_script(value) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
+
+pub fn validate_confusable_safe(
+	value: &str,
+	level: Option<&str>,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	let level = match level {
+		Some(level) => restriction_level(level),
+		None => Some(RestrictionLevel::HighlyRestrictive),
+	};
+
+	if !level.is_some_and(|level| is_confusable_safe(value, level)) {
+		return Err(ValidationError::builder()
+			.with_field(field)
+			.as_simple(code)
+			.with_message(message)
+			.build()
+			.into());
+	}
+
+	Ok(())
+}
@@ -1,4 +1,5 @@
-use ::validy::{core::ValidationError, utils::regex::RegexManager};
+use ::validy::core::ValidationError;
+pub use ::validy::utils::url::UrlPolicy;
 use std::borrow::Cow;
 
 pub fn validate_url(
@@ -7,26 +8,24 @@ pub fn validate_url(
 	code: impl Into<Cow<'static, str>>,
 	message: impl Into<Cow<'static, str>>,
 ) -> Result<(), ValidationError> {
-	match RegexManager::get_or_create(
-		r"(http(s)?:\/\/.)?(www\.)?[-a-zA-Z0-9@:%._\+~#=]{2,256}\.[a-z]{2,6}\b([-a-zA-Z0-9@:%_\+.~#?&//=]*)",
-	) {
-		Err(_) => Err(ValidationError::builder()
+	validate_url_with_policy(value, &UrlPolicy::default(), field, code, message)
+}
+
+pub fn validate_url_with_policy(
+	value: &str,
+	policy: &UrlPolicy,
+	field: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+	message: impl Into<Cow<'static, str>>,
+) -> Result<(), ValidationError> {
+	if policy.parse(value).is_none() {
+		return Err(ValidationError::builder()
 			.with_field(field)
-			.as_simple("bad_regex")
-			.with_message("can't build regex by provided pattern")
+			.as_simple(code)
+			.with_message(message)
 			.build()
-			.into()),
-		Ok(matcher) => {
-			if !matcher.is_match(value) {
-				return Err(ValidationError::builder()
-					.with_field(field)
-					.as_simple(code)
-					.with_message(message)
-					.build()
-					.into());
-			}
-
-			Ok(())
-		}
+			.into());
 	}
+
+	Ok(())
 }
@@ -2,6 +2,7 @@
 use crate::core::NoContext;
 use ::validy::core::{
 	IntoValidationError, NestedValidationError, SimpleValidationError, ValidationError, ValidationErrors,
+	ValidationParams,
 };
 #[cfg(feature = "axum")]
 use axum::extract::FromRef;
@@ -64,6 +65,7 @@ impl SimpleValidationError {
 			field: field.into(),
 			code: code.into(),
 			message: None,
+			params: ValidationParams::new(),
 		}
 	}
 
@@ -71,6 +73,11 @@ impl SimpleValidationError {
 		self.message = Some(message.into());
 		self
 	}
+
+	pub fn with_param(mut self, key: impl Into<Cow<'static, str>>, value: impl Into<Cow<'static, str>>) -> Self {
+		self.params.insert(key.into(), value.into());
+		self
+	}
 }
 
 impl From<NestedValidationError> for ValidationError {
@@ -91,6 +98,23 @@ impl IntoValidationError for &str {
 			field,
 			code,
 			message: Some(self.to_string().into()),
+			params: ValidationParams::new(),
+		})
+	}
+}
+
+impl<const N: usize> IntoValidationError for (&str, [(&str, &str); N]) {
+	fn into_error(self, field: Cow<'static, str>, code: Cow<'static, str>) -> ValidationError {
+		let (message, params) = self;
+
+		ValidationError::Leaf(SimpleValidationError {
+			field,
+			code,
+			message: Some(message.to_string().into()),
+			params: params
+				.into_iter()
+				.map(|(key, value)| (key.to_string().into(), value.to_string().into()))
+				.collect(),
 		})
 	}
 }
@@ -4,7 +4,12 @@ pub mod axum;
 pub mod builders;
 #[doc = include_str!("../readme.md")]
 pub mod core;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
 pub mod functions;
 mod impls;
 pub mod settings;
 pub mod utils;
+pub mod validated;
+
+pub use validated::Validated;
@@ -3,15 +3,25 @@ use axum::http::StatusCode;
 #[cfg(feature = "pattern")]
 use moka::sync::Cache;
 use parking_lot::RwLock;
-#[cfg(feature = "pattern")]
+#[cfg(any(feature = "pattern", feature = "email"))]
 use parking_lot::RwLockReadGuard;
 #[cfg(feature = "pattern")]
 use regex::Regex;
+#[cfg(feature = "email")]
+use std::collections::HashSet;
 use std::sync::OnceLock;
 #[cfg(feature = "pattern")]
 use std::{borrow::Cow, sync::Arc};
 
+#[cfg(feature = "phone")]
+use crate::utils::phone::PhoneRegion;
+
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+	feature = "dynamic",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "snake_case")
+)]
 pub enum FailureMode {
 	FailFast,
 	FailOncePerField,
@@ -19,20 +29,41 @@ pub enum FailureMode {
 	FullFail,
 }
 
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+	feature = "dynamic",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "snake_case")
+)]
+pub enum LengthUnit {
+	Bytes,
+	Chars,
+	Graphemes,
+	Utf16,
+}
+
 pub struct ValidationSettings {
 	#[cfg(feature = "axum")]
 	pub failure_status_code: RwLock<StatusCode>,
 	#[cfg(feature = "axum")]
 	pub failure_multipart_status_code: RwLock<StatusCode>,
 	pub failure_mode: RwLock<FailureMode>,
+	pub max_depth: RwLock<usize>,
+	pub length_unit: RwLock<LengthUnit>,
 	#[cfg(feature = "pattern")]
 	pub regex_cache: RwLock<Cache<Cow<'static, str>, Arc<Regex>>>,
+	#[cfg(feature = "email")]
+	pub disposable_domains: RwLock<HashSet<String>>,
+	#[cfg(feature = "phone")]
+	pub phone_region: RwLock<Option<PhoneRegion>>,
 }
 
 impl Default for ValidationSettings {
 	fn default() -> Self {
 		Self {
 			failure_mode: RwLock::new(FailureMode::FailOncePerField),
+			max_depth: RwLock::new(32),
+			length_unit: RwLock::new(LengthUnit::Bytes),
 			#[cfg(feature = "axum")]
 			failure_status_code: RwLock::new(StatusCode::BAD_REQUEST),
 			#[cfg(feature = "axum")]
@@ -44,6 +75,10 @@ impl Default for ValidationSettings {
 					.initial_capacity(10)
 					.build(),
 			),
+			#[cfg(feature = "email")]
+			disposable_domains: RwLock::new(HashSet::new()),
+			#[cfg(feature = "phone")]
+			phone_region: RwLock::new(None),
 		}
 	}
 }
@@ -55,8 +90,8 @@ impl ValidationSettings {
 		SETTINGS.get_or_init(ValidationSettings::default)
 	}
 
-	pub fn init(settings: ValidationSettings) -> Result<(), ValidationSettings> {
-		SETTINGS.set(settings)
+	pub fn init(settings: ValidationSettings) -> Result<(), Box<ValidationSettings>> {
+		SETTINGS.set(settings).map_err(Box::new)
 	}
 
 	pub fn set_failure_mode(mode: FailureMode) {
@@ -67,6 +102,22 @@ impl ValidationSettings {
 		*Self::get().failure_mode.read()
 	}
 
+	pub fn set_max_depth(depth: usize) {
+		*Self::get().max_depth.write() = depth;
+	}
+
+	pub fn get_max_depth() -> usize {
+		*Self::get().max_depth.read()
+	}
+
+	pub fn set_length_unit(unit: LengthUnit) {
+		*Self::get().length_unit.write() = unit;
+	}
+
+	pub fn get_length_unit() -> LengthUnit {
+		*Self::get().length_unit.read()
+	}
+
 	#[cfg(feature = "axum")]
 	pub fn set_failure_status_code(code: StatusCode) {
 		*Self::get().failure_status_code.write() = code;
@@ -96,4 +147,52 @@ impl ValidationSettings {
 	pub fn get_regex_cache() -> RwLockReadGuard<'static, Cache<Cow<'static, str>, Arc<Regex>>> {
 		Self::get().regex_cache.read()
 	}
+
+	#[cfg(feature = "email")]
+	pub fn set_disposable_domains<I, S>(domains: I)
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		*Self::get().disposable_domains.write() = domains
+			.into_iter()
+			.map(|domain| domain.as_ref().trim().trim_end_matches('.').to_ascii_lowercase())
+			.filter(|domain| !domain.is_empty() && !domain.starts_with('#'))
+			.collect();
+	}
+
+	#[cfg(feature = "email")]
+	pub fn get_disposable_domains() -> RwLockReadGuard<'static, HashSet<String>> {
+		Self::get().disposable_domains.read()
+	}
+
+	#[cfg(feature = "email")]
+	pub fn is_disposable_domain(domain: &str) -> bool {
+		let domains = Self::get_disposable_domains();
+		if domains.is_empty() {
+			return false;
+		}
+
+		let mut domain = domain.trim_end_matches('.').to_ascii_lowercase();
+		loop {
+			if domains.contains(&domain) {
+				return true;
+			}
+
+			match domain.split_once('.') {
+				Some((_, parent)) => domain = parent.to_string(),
+				None => return false,
+			}
+		}
+	}
+
+	#[cfg(feature = "phone")]
+	pub fn set_phone_region(region: Option<PhoneRegion>) {
+		*Self::get().phone_region.write() = region;
+	}
+
+	#[cfg(feature = "phone")]
+	pub fn get_phone_region() -> Option<PhoneRegion> {
+		*Self::get().phone_region.read()
+	}
 }
@@ -0,0 +1,95 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Charset {
+	ranges: Vec<(char, char)>,
+}
+
+impl Charset {
+	pub fn parse(spec: &str) -> Option<Self> {
+		let mut items = Vec::new();
+		let mut chars = spec.chars();
+
+		while let Some(char) = chars.next() {
+			match char {
+				'\\' => items.push((chars.next()?, true)),
+				char => items.push((char, false)),
+			}
+		}
+
+		let mut ranges = Vec::new();
+		let mut index = 0;
+
+		while index < items.len() {
+			let start = items[index].0;
+
+			match (items.get(index + 1), items.get(index + 2)) {
+				(Some(('-', false)), Some(&(end, _))) => {
+					if start > end {
+						return None;
+					}
+
+					ranges.push((start, end));
+					index += 3;
+				}
+				_ => {
+					ranges.push((start, start));
+					index += 1;
+				}
+			}
+		}
+
+		Some(Charset { ranges })
+	}
+
+	pub fn contains(&self, char: char) -> bool {
+		self.ranges.iter().any(|&(start, end)| (start..=end).contains(&char))
+	}
+
+	pub fn find_outside(&self, value: &str) -> Option<char> {
+		value.chars().find(|char| !self.contains(*char))
+	}
+}
+
+pub fn is_zero_width(char: char) -> bool {
+	matches!(
+		char,
+		'\u{00AD}'
+			| '\u{034F}'
+			| '\u{061C}'
+			| '\u{115F}'..='\u{1160}'
+			| '\u{17B4}'..='\u{17B5}'
+			| '\u{180B}'..='\u{180F}'
+			| '\u{200B}'..='\u{200F}'
+			| '\u{202A}'..='\u{202E}'
+			| '\u{2060}'..='\u{206F}'
+			| '\u{3164}'
+			| '\u{FE00}'..='\u{FE0F}'
+			| '\u{FEFF}'
+			| '\u{FFA0}'
+			| '\u{FFF0}'..='\u{FFF8}'
+			| '\u{1BCA0}'..='\u{1BCA3}'
+			| '\u{1D173}'..='\u{1D17A}'
+			| '\u{E0000}'..='\u{E0FFF}'
+	)
+}
+
+pub fn is_line_break(char: char) -> bool {
+	matches!(
+		char,
+		'\n' | '\u{000B}' | '\u{000C}' | '\r' | '\u{0085}' | '\u{2028}' | '\u{2029}'
+	)
+}
+
+pub fn is_private_use(char: char) -> bool {
+	matches!(
+		char,
+		'\u{E000}'..='\u{F8FF}' | '\u{F0000}'..='\u{FFFFD}' | '\u{100000}'..='\u{10FFFD}'
+	)
+}
+
+pub fn is_printable(char: char) -> bool {
+	!char.is_control() && !is_zero_width(char) && !is_line_break(char) && !is_private_use(char)
+}
+
+pub fn is_disallowed_control(char: char) -> bool {
+	char.is_control() && !matches!(char, '\t' | '\n' | '\r')
+}
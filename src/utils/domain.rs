@@ -0,0 +1,109 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Domain(String);
+
+impl Domain {
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+
+	pub fn labels(&self) -> impl Iterator<Item = &str> {
+		self.0.split('.')
+	}
+
+	pub fn tld(&self) -> &str {
+		self.0.rsplit('.').next().unwrap_or_default()
+	}
+
+	pub fn is_wildcard(&self) -> bool {
+		self.0.starts_with("*.")
+	}
+
+	pub fn to_unicode(&self) -> String {
+		idna::domain_to_unicode(&self.0).0
+	}
+}
+
+impl fmt::Display for Domain {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.0)
+	}
+}
+
+impl AsRef<str> for Domain {
+	fn as_ref(&self) -> &str {
+		&self.0
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DomainPolicy<'a> {
+	pub require_tld: bool,
+	pub allow_wildcard: bool,
+	pub suffix_of: Option<&'a str>,
+}
+
+impl Default for DomainPolicy<'_> {
+	fn default() -> Self {
+		Self {
+			require_tld: true,
+			allow_wildcard: false,
+			suffix_of: None,
+		}
+	}
+}
+
+impl DomainPolicy<'_> {
+	pub fn parse(&self, value: &str) -> Option<Domain> {
+		let value = value.strip_suffix('.').unwrap_or(value);
+		let (wildcard, value) = match value.strip_prefix("*.") {
+			Some(value) if self.allow_wildcard => (true, value),
+			Some(_) => return None,
+			None => (false, value),
+		};
+
+		let domain = idna::domain_to_ascii_strict(value).ok()?;
+		if !is_hostname(&domain) {
+			return None;
+		}
+
+		if self.require_tld {
+			let mut labels = domain.rsplit('.');
+			let tld = labels.next()?;
+			if labels.next().is_none() || tld.bytes().all(|byte| byte.is_ascii_digit()) {
+				return None;
+			}
+		}
+
+		if let Some(suffix) = self.suffix_of {
+			let suffix = idna::domain_to_ascii(suffix.strip_suffix('.').unwrap_or(suffix)).ok()?;
+			let is_subdomain = domain
+				.strip_suffix(&suffix)
+				.is_some_and(|subdomain| subdomain.len() > 1 && subdomain.ends_with('.'));
+
+			if !is_subdomain && (!wildcard || domain != suffix) {
+				return None;
+			}
+		}
+
+		match wildcard {
+			true => Some(Domain(format!("*.{}", domain))),
+			false => Some(Domain(domain)),
+		}
+	}
+}
+
+pub fn is_hostname(value: &str) -> bool {
+	let value = value.strip_suffix('.').unwrap_or(value);
+
+	!value.is_empty()
+		&& value.len() <= 253
+		&& value.split('.').all(|label| {
+			!label.is_empty()
+				&& label.len() <= 63
+				&& !label.starts_with('-')
+				&& !label.ends_with('-')
+				&& label.bytes().all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+		})
+}
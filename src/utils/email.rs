@@ -0,0 +1,77 @@
+use std::net::IpAddr;
+
+use email_address::{EmailAddress, Options};
+
+use crate::{settings::ValidationSettings, utils::hosts::matches_host};
+
+#[derive(Debug, Clone, Copy)]
+pub struct EmailPolicy<'a> {
+	pub allow_display_name: bool,
+	pub allowed_domains: &'a [&'a str],
+	pub blocked_domains: &'a [&'a str],
+	pub require_tld: bool,
+	pub allow_ip_domain: bool,
+	pub allow_disposable: bool,
+}
+
+impl Default for EmailPolicy<'_> {
+	fn default() -> Self {
+		Self {
+			allow_display_name: false,
+			allowed_domains: &[],
+			blocked_domains: &[],
+			require_tld: true,
+			allow_ip_domain: false,
+			allow_disposable: false,
+		}
+	}
+}
+
+impl EmailPolicy<'_> {
+	pub fn parse(&self, value: &str) -> Option<EmailAddress> {
+		let options = Options {
+			minimum_sub_domains: if self.require_tld { 2 } else { 0 },
+			allow_domain_literal: self.allow_ip_domain,
+			allow_display_text: self.allow_display_name,
+		};
+
+		EmailAddress::parse_with_options(value, options)
+			.ok()
+			.filter(|email| self.allows(email))
+	}
+
+	pub fn allows(&self, email: &EmailAddress) -> bool {
+		let domain = email.domain();
+
+		if domain.starts_with('[') {
+			return self.allow_ip_domain && self.allowed_domains.is_empty();
+		}
+
+		if domain.parse::<IpAddr>().is_ok() {
+			return self.allow_ip_domain && self.allowed_domains.is_empty();
+		}
+
+		let Ok(domain) = idna::domain_to_ascii(domain) else {
+			return false;
+		};
+
+		if !self.allowed_domains.is_empty()
+			&& !self
+				.allowed_domains
+				.iter()
+				.any(|pattern| matches_host(pattern, &domain))
+		{
+			return false;
+		}
+
+		if self
+			.blocked_domains
+			.iter()
+			.any(|pattern| matches_host(pattern, &domain))
+		{
+			return false;
+		}
+
+		self.allow_disposable || !ValidationSettings::is_disposable_domain(&domain)
+	}
+}
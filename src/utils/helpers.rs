@@ -1,8 +1,22 @@
-use std::borrow::Cow;
+use serde::{
+	Deserialize, Deserializer, Serialize, Serializer,
+	de::{IgnoredAny, MapAccess, Visitor},
+	ser::SerializeMap,
+};
+use std::{
+	borrow::Cow,
+	cell::Cell,
+	fmt,
+	future::Future,
+	pin::Pin,
+	rc::Rc,
+	sync::Arc,
+	task::{Context, Poll},
+};
 
 use ::validy::{
-	core::{ValidationError, ValidationErrors},
-	settings::FailureMode,
+	core::{NestedValidationError, SimpleValidationError, ValidationError, ValidationErrors},
+	settings::{FailureMode, ValidationSettings},
 };
 
 pub fn can_continue(errors: &ValidationErrors, mode: FailureMode, field_name: &str) -> bool {
@@ -27,3 +41,420 @@ pub fn append_error(
 
 	entry.push(error);
 }
+
+pub fn append_newtype_errors(
+	errors: &mut ValidationErrors,
+	newtype_errors: ValidationErrors,
+	mode: FailureMode,
+	field_name: &'static str,
+) {
+	for error in newtype_errors.into_values().flatten() {
+		let error = match error {
+			ValidationError::Leaf(error) => ValidationError::Leaf(SimpleValidationError {
+				field: field_name.into(),
+				..error
+			}),
+			ValidationError::Node(error) => ValidationError::Node(NestedValidationError {
+				field: field_name.into(),
+				..error
+			}),
+		};
+
+		append_error(errors, error, mode, field_name);
+	}
+}
+
+pub struct PreviousErrors {
+	len: usize,
+	stashed: Option<Vec<ValidationError>>,
+}
+
+pub fn previous_errors(errors: &mut ValidationErrors, mode: FailureMode, field_name: &str) -> PreviousErrors {
+	if matches!(mode, FailureMode::LastFailPerField) {
+		PreviousErrors {
+			len: 0,
+			stashed: errors.remove(field_name),
+		}
+	} else {
+		PreviousErrors {
+			len: errors.get(field_name).map(Vec::len).unwrap_or(0),
+			stashed: None,
+		}
+	}
+}
+
+pub fn take_item_errors(
+	errors: &mut ValidationErrors,
+	items_errors: &mut ValidationErrors,
+	field_name: &str,
+	previous: PreviousErrors,
+	key: impl ToString,
+) {
+	if let Some(entry) = errors.get_mut(field_name)
+		&& entry.len() > previous.len
+	{
+		let item_errors = entry.split_off(previous.len);
+
+		if entry.is_empty() {
+			errors.remove(field_name);
+		}
+
+		items_errors
+			.entry(key.to_string().into())
+			.or_default()
+			.extend(item_errors);
+	}
+
+	if let Some(stashed) = previous.stashed {
+		errors.insert(field_name.to_string().into(), stashed);
+	}
+}
+
+pub fn append_duplicate_key(items_errors: &mut ValidationErrors, key: impl ToString) {
+	let key = key.to_string();
+	let error = ValidationError::builder()
+		.with_field(key.clone())
+		.as_simple("duplicate_key")
+		.with_message("collides with another key")
+		.build();
+
+	items_errors.entry(key.into()).or_default().push(error.into());
+}
+
+pub fn resolve_renamed_keys<K: PartialEq>(
+	mut renames: Vec<(K, K)>,
+	contains_key: impl Fn(&K) -> bool,
+) -> (Vec<(K, K)>, Vec<K>) {
+	let mut rejected = Vec::new();
+
+	loop {
+		let collides = |index: usize, new_key: &K| {
+			let kept = contains_key(new_key) && !renames.iter().any(|(old_key, _)| old_key == new_key);
+			let renamed = renames
+				.iter()
+				.enumerate()
+				.any(|(other, (_, other_key))| other != index && other_key == new_key);
+
+			kept || renamed || rejected.contains(new_key)
+		};
+
+		let colliding: Vec<usize> = renames
+			.iter()
+			.enumerate()
+			.filter(|(index, (_, new_key))| collides(*index, new_key))
+			.map(|(index, _)| index)
+			.collect();
+
+		if colliding.is_empty() {
+			return (renames, rejected);
+		}
+
+		for index in colliding.into_iter().rev() {
+			let (old_key, _) = renames.remove(index);
+			rejected.push(old_key);
+		}
+	}
+}
+
+pub fn describe_errors(errors: &ValidationErrors) -> String {
+	let mut fields: Vec<_> = errors.iter().collect();
+	fields.sort_by_key(|(field, _)| *field);
+
+	fields
+		.into_iter()
+		.map(|(field, errors)| {
+			let errors: Vec<String> = errors
+				.iter()
+				.map(|error| match error {
+					ValidationError::Leaf(error) => match &error.message {
+						Some(message) => format!("{} ({})", error.code, message),
+						None => error.code.to_string(),
+					},
+					ValidationError::Node(error) => format!("{} {{ {} }}", error.code, describe_errors(&error.errors)),
+				})
+				.collect();
+
+			format!("{}: {}", field, errors.join(", "))
+		})
+		.collect::<Vec<String>>()
+		.join("; ")
+}
+
+pub fn deserialize_patch<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+	T: Deserialize<'de>,
+	D: Deserializer<'de>,
+{
+	Option::<T>::deserialize(deserializer).map(Some)
+}
+
+#[derive(Deserialize)]
+#[serde(transparent)]
+struct BorrowedCow<'a>(#[serde(borrow)] Cow<'a, str>);
+
+pub fn deserialize_borrowed_cow<'de: 'a, 'a, D>(deserializer: D) -> Result<Option<Cow<'a, str>>, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	Option::<BorrowedCow<'a>>::deserialize(deserializer).map(|value| value.map(|value| value.0))
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct UnknownFields(pub Vec<String>);
+
+impl<'de> Deserialize<'de> for UnknownFields {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct UnknownFieldsVisitor;
+
+		impl<'de> Visitor<'de> for UnknownFieldsVisitor {
+			type Value = UnknownFields;
+
+			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+				formatter.write_str("a map")
+			}
+
+			fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+				let mut fields = Vec::new();
+
+				while let Some(field) = map.next_key::<String>()? {
+					map.next_value::<IgnoredAny>()?;
+					fields.push(field);
+				}
+
+				Ok(UnknownFields(fields))
+			}
+		}
+
+		deserializer.deserialize_map(UnknownFieldsVisitor)
+	}
+}
+
+impl Serialize for UnknownFields {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_map(Some(0))?.end()
+	}
+}
+
+pub trait AsSibling {
+	fn as_sibling(&self) -> Option<&str>;
+}
+
+impl AsSibling for str {
+	fn as_sibling(&self) -> Option<&str> {
+		Some(self)
+	}
+}
+
+impl AsSibling for String {
+	fn as_sibling(&self) -> Option<&str> {
+		Some(self.as_str())
+	}
+}
+
+impl AsSibling for Cow<'_, str> {
+	fn as_sibling(&self) -> Option<&str> {
+		Some(self.as_ref())
+	}
+}
+
+impl<T: AsSibling + ?Sized> AsSibling for &T {
+	fn as_sibling(&self) -> Option<&str> {
+		(**self).as_sibling()
+	}
+}
+
+impl<T: AsSibling> AsSibling for Option<T> {
+	fn as_sibling(&self) -> Option<&str> {
+		self.as_ref().and_then(AsSibling::as_sibling)
+	}
+}
+
+pub trait IntoNested<W> {
+	type Pointer<T>;
+
+	fn into_nested(self) -> W;
+	fn wrap<T>(value: T) -> Self::Pointer<T>;
+}
+
+impl<W> IntoNested<W> for W {
+	type Pointer<T> = T;
+
+	fn into_nested(self) -> W {
+		self
+	}
+
+	fn wrap<T>(value: T) -> T {
+		value
+	}
+}
+
+impl<W> IntoNested<W> for Box<W> {
+	type Pointer<T> = Box<T>;
+
+	fn into_nested(self) -> W {
+		*self
+	}
+
+	fn wrap<T>(value: T) -> Box<T> {
+		Box::new(value)
+	}
+}
+
+impl<W: Clone> IntoNested<W> for Rc<W> {
+	type Pointer<T> = Rc<T>;
+
+	fn into_nested(self) -> W {
+		Rc::unwrap_or_clone(self)
+	}
+
+	fn wrap<T>(value: T) -> Rc<T> {
+		Rc::new(value)
+	}
+}
+
+impl<W: Clone> IntoNested<W> for Arc<W> {
+	type Pointer<T> = Arc<T>;
+
+	fn into_nested(self) -> W {
+		Arc::unwrap_or_clone(self)
+	}
+
+	fn wrap<T>(value: T) -> Arc<T> {
+		Arc::new(value)
+	}
+}
+
+#[derive(Debug)]
+pub enum NestedFailure {
+	Errors(ValidationErrors),
+	MaxDepth,
+}
+
+impl From<ValidationErrors> for NestedFailure {
+	fn from(errors: ValidationErrors) -> Self {
+		NestedFailure::Errors(errors)
+	}
+}
+
+pub fn into_nested_error(
+	failure: NestedFailure,
+	field_name: impl Into<Cow<'static, str>>,
+	code: impl Into<Cow<'static, str>>,
+) -> ValidationError {
+	match failure {
+		NestedFailure::Errors(errors) => NestedValidationError::from_with_code(errors, field_name, code).into(),
+		NestedFailure::MaxDepth => SimpleValidationError::new(field_name, "max_depth")
+			.with_message("exceeds the maximum nesting depth")
+			.into(),
+	}
+}
+
+pub fn merge_errors(
+	errors: &mut ValidationErrors,
+	failure: NestedFailure,
+	mode: FailureMode,
+	field_name: &'static str,
+) -> bool {
+	match failure {
+		NestedFailure::Errors(nested_errors) => {
+			for (key, items) in nested_errors {
+				for error in items {
+					append_error(errors, error, mode, key.clone());
+				}
+			}
+		}
+		failure => append_error(
+			errors,
+			into_nested_error(failure, field_name, "nested"),
+			mode,
+			field_name,
+		),
+	}
+
+	matches!(mode, FailureMode::FailFast)
+}
+
+thread_local! {
+	static NESTED_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+struct DepthGuard(usize);
+
+impl DepthGuard {
+	fn enter(depth: usize) -> Self {
+		DepthGuard(NESTED_DEPTH.replace(depth))
+	}
+}
+
+impl Drop for DepthGuard {
+	fn drop(&mut self) {
+		NESTED_DEPTH.set(self.0);
+	}
+}
+
+fn next_depth() -> Option<usize> {
+	let depth = NESTED_DEPTH.get() + 1;
+	(depth <= ValidationSettings::get_max_depth()).then_some(depth)
+}
+
+pub fn nested_scope<T>(validate: impl FnOnce() -> Result<T, ValidationErrors>) -> Result<T, NestedFailure> {
+	let depth = next_depth().ok_or(NestedFailure::MaxDepth)?;
+	let _guard = DepthGuard::enter(depth);
+	validate().map_err(NestedFailure::Errors)
+}
+
+pub fn parse_nested<P, W, T>(
+	wrapper: P,
+	parse: impl FnOnce(W) -> Result<T, ValidationErrors>,
+) -> Result<P::Pointer<T>, NestedFailure>
+where
+	P: IntoNested<W>,
+{
+	nested_scope(|| parse(wrapper.into_nested())).map(P::wrap)
+}
+
+pub fn unparse_nested<P, T, W>(value: P) -> P::Pointer<W>
+where
+	P: IntoNested<T>,
+	W: From<T>,
+{
+	P::wrap(W::from(value.into_nested()))
+}
+
+struct DepthScope<F> {
+	future: F,
+	depth: usize,
+}
+
+impl<F: Future + Unpin> Future for DepthScope<F> {
+	type Output = F::Output;
+
+	fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+		let _guard = DepthGuard::enter(self.depth);
+		Pin::new(&mut self.future).poll(context)
+	}
+}
+
+pub async fn async_nested_scope<T, F>(validate: F) -> Result<T, NestedFailure>
+where
+	F: Future<Output = Result<T, ValidationErrors>> + Unpin,
+{
+	let depth = next_depth().ok_or(NestedFailure::MaxDepth)?;
+	DepthScope {
+		future: validate,
+		depth,
+	}
+	.await
+	.map_err(NestedFailure::Errors)
+}
+
+pub async fn async_parse_nested<P, W, T, F>(
+	wrapper: P,
+	parse: impl FnOnce(W) -> F,
+) -> Result<P::Pointer<T>, NestedFailure>
+where
+	P: IntoNested<W>,
+	F: Future<Output = Result<T, ValidationErrors>> + Unpin,
+{
+	async_nested_scope(parse(wrapper.into_nested())).await.map(P::wrap)
+}
@@ -0,0 +1,26 @@
+use std::net::IpAddr;
+
+pub fn matches_host(pattern: &str, host: &str) -> bool {
+	let pattern = strip_brackets(pattern.trim_end_matches('.'));
+	let host = strip_brackets(host.trim_end_matches('.'));
+
+	if let (Ok(pattern), Ok(host)) = (pattern.parse::<IpAddr>(), host.parse::<IpAddr>()) {
+		return pattern == host;
+	}
+
+	match pattern.strip_prefix("*") {
+		Some("") => true,
+		Some(suffix) if suffix.starts_with('.') => {
+			host.len() > suffix.len()
+				&& host.is_char_boundary(host.len() - suffix.len())
+				&& host[host.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+		}
+		_ => pattern.eq_ignore_ascii_case(host),
+	}
+}
+
+fn strip_brackets(host: &str) -> &str {
+	host.strip_prefix('[')
+		.and_then(|host| host.strip_suffix(']'))
+		.unwrap_or(host)
+}
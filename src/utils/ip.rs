@@ -0,0 +1,178 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+#[cfg(feature = "ip")]
+use ipnet::IpNet;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+	feature = "dynamic",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(rename_all = "snake_case")
+)]
+pub enum IpClass {
+	Public,
+	Private,
+	Loopback,
+	Multicast,
+	GlobalUnicast,
+}
+
+impl IpClass {
+	pub fn contains(&self, ip: &IpAddr) -> bool {
+		match (self, ip.to_canonical()) {
+			(IpClass::Public, ip) => is_public_ip(&ip),
+			(IpClass::Private, IpAddr::V4(ip)) => ip.is_private() || is_shared_ipv4(&ip),
+			(IpClass::Private, IpAddr::V6(ip)) => is_unique_local_ipv6(&ip),
+			(IpClass::Loopback, ip) => ip.is_loopback(),
+			(IpClass::Multicast, ip) => ip.is_multicast(),
+			(IpClass::GlobalUnicast, IpAddr::V4(ip)) => is_public_ipv4(&ip),
+			(IpClass::GlobalUnicast, IpAddr::V6(ip)) => (ip.segments()[0] & 0xe000) == 0x2000 && is_public_ipv6(&ip),
+		}
+	}
+}
+
+#[cfg(feature = "ip")]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IpNetwork {
+	Valid(IpNet),
+	Invalid,
+}
+
+#[cfg(feature = "ip")]
+impl IpNetwork {
+	pub fn parse(network: &str) -> Self {
+		match network.parse::<IpNet>() {
+			Ok(network) => IpNetwork::Valid(network),
+			Err(_) => match network.parse::<IpAddr>() {
+				Ok(network) => IpNetwork::Valid(IpNet::from(network)),
+				Err(_) => IpNetwork::Invalid,
+			},
+		}
+	}
+
+	fn contains(&self, ip: &IpAddr) -> Option<bool> {
+		match self {
+			IpNetwork::Valid(network) => Some(network.contains(ip) || network.contains(&ip.to_canonical())),
+			IpNetwork::Invalid => None,
+		}
+	}
+}
+
+#[cfg(feature = "ip")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IpPolicy<'a> {
+	pub in_networks: &'a [IpNetwork],
+	pub not_in_networks: &'a [IpNetwork],
+	pub class: Option<IpClass>,
+}
+
+#[cfg(feature = "ip")]
+impl IpPolicy<'_> {
+	pub fn allows(&self, ip: &IpAddr) -> bool {
+		if !self.in_networks.is_empty()
+			&& !self
+				.in_networks
+				.iter()
+				.any(|network| network.contains(ip).unwrap_or(false))
+		{
+			return false;
+		}
+
+		if self
+			.not_in_networks
+			.iter()
+			.any(|network| network.contains(ip).unwrap_or(true))
+		{
+			return false;
+		}
+
+		self.class.is_none_or(|class| class.contains(ip))
+	}
+}
+
+#[cfg(feature = "ip")]
+pub fn check_cidr(value: &str, min_prefix: Option<u8>, max_prefix: Option<u8>, strict: bool) -> Option<IpNet> {
+	let network: IpNet = value.parse().ok()?;
+	let prefix = network.prefix_len();
+
+	if min_prefix.is_some_and(|min_prefix| prefix < min_prefix)
+		|| max_prefix.is_some_and(|max_prefix| prefix > max_prefix)
+		|| (strict && network.addr() != network.network())
+	{
+		return None;
+	}
+
+	Some(network)
+}
+
+pub fn is_public_ip(ip: &IpAddr) -> bool {
+	match ip.to_canonical() {
+		IpAddr::V4(ip) => is_public_ipv4(&ip),
+		IpAddr::V6(ip) => is_public_ipv6(&ip),
+	}
+}
+
+pub fn is_internal_ip(ip: &IpAddr) -> bool {
+	match ip.to_canonical() {
+		IpAddr::V4(ip) => is_internal_ipv4(&ip),
+		IpAddr::V6(ip) => match nat64_ipv4(&ip) {
+			Some(ip) => is_internal_ipv4(&ip),
+			None => ip.is_loopback() || ip.is_unspecified() || is_unique_local_ipv6(&ip) || is_link_local_ipv6(&ip),
+		},
+	}
+}
+
+fn is_public_ipv4(ip: &Ipv4Addr) -> bool {
+	let [first, second, third, _] = ip.octets();
+
+	!(is_internal_ipv4(ip)
+		|| ip.is_multicast()
+		|| ip.is_documentation()
+		|| first >= 240
+		|| (first == 192 && second == 0 && third == 0)
+		|| (first == 198 && (18..20).contains(&second)))
+}
+
+fn is_public_ipv6(ip: &Ipv6Addr) -> bool {
+	if let Some(ip) = nat64_ipv4(ip) {
+		return is_public_ipv4(&ip);
+	}
+
+	let segments = ip.segments();
+	!(is_internal_ip(&IpAddr::V6(*ip))
+		|| ip.is_multicast()
+		|| (segments[0] == 0x2001 && segments[1] == 0x0db8)
+		|| (segments[0] == 0x0100 && segments[1..4] == [0, 0, 0]))
+}
+
+fn is_internal_ipv4(ip: &Ipv4Addr) -> bool {
+	ip.is_loopback()
+		|| ip.is_private()
+		|| ip.is_link_local()
+		|| ip.is_broadcast()
+		|| ip.octets()[0] == 0
+		|| is_shared_ipv4(ip)
+}
+
+fn is_shared_ipv4(ip: &Ipv4Addr) -> bool {
+	let [first, second, ..] = ip.octets();
+	first == 100 && (64..128).contains(&second)
+}
+
+fn is_unique_local_ipv6(ip: &Ipv6Addr) -> bool {
+	(ip.segments()[0] & 0xfe00) == 0xfc00
+}
+
+fn is_link_local_ipv6(ip: &Ipv6Addr) -> bool {
+	(ip.segments()[0] & 0xffc0) == 0xfe80
+}
+
+fn nat64_ipv4(ip: &Ipv6Addr) -> Option<Ipv4Addr> {
+	let segments = ip.segments();
+	if segments[..6] != [0x64, 0xff9b, 0, 0, 0, 0] {
+		return None;
+	}
+
+	let [.., high, low] = segments;
+	Some(Ipv4Addr::from(((high as u32) << 16) | low as u32))
+}
@@ -0,0 +1,126 @@
+use std::{
+	borrow::Cow,
+	collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+	rc::Rc,
+	sync::Arc,
+};
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::settings::LengthUnit;
+
+pub trait Length {
+	fn measure(&self, unit: LengthUnit) -> usize;
+}
+
+impl Length for str {
+	fn measure(&self, unit: LengthUnit) -> usize {
+		match unit {
+			LengthUnit::Bytes => self.len(),
+			LengthUnit::Chars => self.chars().count(),
+			LengthUnit::Graphemes => self.graphemes(true).count(),
+			LengthUnit::Utf16 => self.encode_utf16().count(),
+		}
+	}
+}
+
+impl Length for String {
+	fn measure(&self, unit: LengthUnit) -> usize {
+		self.as_str().measure(unit)
+	}
+}
+
+impl Length for Cow<'_, str> {
+	fn measure(&self, unit: LengthUnit) -> usize {
+		self.as_ref().measure(unit)
+	}
+}
+
+impl<T: Length + ?Sized> Length for &T {
+	fn measure(&self, unit: LengthUnit) -> usize {
+		(**self).measure(unit)
+	}
+}
+
+impl<T: Length + ?Sized> Length for &mut T {
+	fn measure(&self, unit: LengthUnit) -> usize {
+		(**self).measure(unit)
+	}
+}
+
+impl<T: Length + ?Sized> Length for Box<T> {
+	fn measure(&self, unit: LengthUnit) -> usize {
+		(**self).measure(unit)
+	}
+}
+
+impl<T: Length + ?Sized> Length for Rc<T> {
+	fn measure(&self, unit: LengthUnit) -> usize {
+		(**self).measure(unit)
+	}
+}
+
+impl<T: Length + ?Sized> Length for Arc<T> {
+	fn measure(&self, unit: LengthUnit) -> usize {
+		(**self).measure(unit)
+	}
+}
+
+macro_rules! impl_collection_measurable {
+	($($collection:ty => [$($generics:tt)*]),* $(,)?) => {
+		$(
+			impl<$($generics)*> Length for $collection {
+				fn measure(&self, _: LengthUnit) -> usize {
+					self.len()
+				}
+			}
+		)*
+	};
+}
+
+impl_collection_measurable!(
+	[T] => [T],
+	Vec<T> => [T],
+	VecDeque<T> => [T],
+	LinkedList<T> => [T],
+	BinaryHeap<T> => [T],
+	BTreeSet<T> => [T],
+	BTreeMap<K, V> => [K, V],
+	HashSet<T, S> => [T, S],
+	HashMap<K, V, S> => [K, V, S],
+);
+
+impl<T, const N: usize> Length for [T; N] {
+	fn measure(&self, _: LengthUnit) -> usize {
+		N
+	}
+}
+
+pub fn truncate_index(value: &str, max: usize, unit: LengthUnit) -> usize {
+	match unit {
+		LengthUnit::Bytes => {
+			let mut end = max.min(value.len());
+			while !value.is_char_boundary(end) {
+				end -= 1;
+			}
+
+			end
+		}
+		LengthUnit::Chars => value.char_indices().nth(max).map_or(value.len(), |(index, _)| index),
+		LengthUnit::Graphemes => value
+			.grapheme_indices(true)
+			.nth(max)
+			.map_or(value.len(), |(index, _)| index),
+		LengthUnit::Utf16 => {
+			let mut units = 0;
+			for (index, char) in value.char_indices() {
+				units += char.len_utf16();
+				if units > max {
+					return index;
+				}
+			}
+
+			value.len()
+		}
+	}
+}
@@ -37,6 +37,7 @@ macro_rules! validation_error {
 			field: std::borrow::Cow::from($field),
 			code: std::borrow::Cow::from($code),
 			message: Some(std::borrow::Cow::from($message)),
+			params: ::validy::core::ValidationParams::new(),
 		})
 	};
 	($field:expr, $code:expr) => {
@@ -44,6 +45,7 @@ macro_rules! validation_error {
 			field: std::borrow::Cow::from($field),
 			code: std::borrow::Cow::from($code),
 			message: None,
+			params: ::validy::core::ValidationParams::new(),
 		})
 	};
 }
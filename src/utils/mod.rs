@@ -1,7 +1,28 @@
 #[cfg(feature = "macro_rules_assertions")]
 pub mod assertions;
+pub mod chars;
+#[cfg(feature = "domain")]
+pub mod domain;
+#[cfg(feature = "email")]
+pub mod email;
 pub mod helpers;
+#[cfg(any(feature = "email", feature = "url"))]
+pub mod hosts;
+pub mod ip;
+#[cfg(feature = "iso")]
+pub mod iso;
+pub mod length;
 #[cfg(feature = "macro_rules")]
 pub mod macros_rules;
+#[cfg(feature = "password")]
+pub mod password;
+#[cfg(feature = "payments")]
+pub mod payments;
+#[cfg(feature = "phone")]
+pub mod phone;
 #[cfg(feature = "pattern")]
 pub mod regex;
+#[cfg(feature = "unicode")]
+pub mod unicode;
+#[cfg(feature = "url")]
+pub mod url;
@@ -0,0 +1,237 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use zxcvbn::{
+	Match,
+	matching::patterns::{DatePattern, MatchPattern, RegexPattern},
+	zxcvbn,
+};
+
+const DEFAULT_MIN_SCORE: u8 = 3;
+const MIN_YEAR_SPACE: f64 = 20.0;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PasswordPolicy<'a> {
+	pub min_entropy: Option<f64>,
+	pub min_score: Option<u8>,
+	pub require: &'a [&'a str],
+	pub forbidden: &'a [Option<&'a str>],
+	pub max_repeats: Option<usize>,
+	pub reference_year: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordEstimate {
+	pub guesses: f64,
+	pub entropy: f64,
+	pub score: u8,
+	pub suggestions: Vec<&'static str>,
+}
+
+impl PasswordPolicy<'_> {
+	pub fn check(&self, value: &str) -> Result<(), PasswordEstimate> {
+		let personal = self
+			.forbidden
+			.iter()
+			.flatten()
+			.flat_map(|value| personal_tokens(value))
+			.collect::<Vec<String>>();
+
+		let inputs = personal.iter().map(String::as_str).collect::<Vec<&str>>();
+		let mut estimate = match self.reference_year {
+			Some(reference_year) => estimate_password_at(value, &inputs, reference_year),
+			None => estimate_password(value, &inputs),
+		};
+		let mut suggestions = Vec::new();
+
+		for class in self.require {
+			if !has_class(value, class) {
+				suggestions.push(match *class {
+					"upper" => "add_upper",
+					"lower" => "add_lower",
+					"digit" => "add_digit",
+					"symbol" => "add_symbol",
+					_ => "unknown_requirement",
+				});
+			}
+		}
+
+		if let Some(max_repeats) = self.max_repeats
+			&& longest_repeat(value) > max_repeats
+		{
+			suggestions.push("avoid_repeats");
+		}
+
+		let lowercase = value.to_lowercase();
+		if personal.iter().any(|token| lowercase.contains(token.as_str())) {
+			suggestions.push("avoid_personal_info");
+		}
+
+		let is_weak = match (self.min_score, self.min_entropy) {
+			(None, None) => estimate.score < DEFAULT_MIN_SCORE,
+			(min_score, min_entropy) => {
+				min_score.is_some_and(|min_score| estimate.score < min_score)
+					|| min_entropy.is_some_and(|min_entropy| estimate.entropy < min_entropy)
+			}
+		};
+
+		if is_weak {
+			suggestions.append(&mut estimate.suggestions);
+			suggestions.push("add_more_words");
+		}
+
+		if suggestions.is_empty() {
+			return Ok(());
+		}
+
+		let mut seen = Vec::with_capacity(suggestions.len());
+		suggestions.retain(|suggestion| {
+			let is_new = !seen.contains(suggestion);
+			seen.push(*suggestion);
+			is_new
+		});
+
+		estimate.suggestions = suggestions;
+		Err(estimate)
+	}
+}
+
+pub fn estimate_password(value: &str, user_inputs: &[&str]) -> PasswordEstimate {
+	estimate(value, user_inputs, None)
+}
+
+pub fn estimate_password_at(value: &str, user_inputs: &[&str], reference_year: i32) -> PasswordEstimate {
+	estimate(value, user_inputs, Some(reference_year))
+}
+
+fn estimate(value: &str, user_inputs: &[&str], reference_year: Option<i32>) -> PasswordEstimate {
+	let result = zxcvbn(value, user_inputs);
+	let mut guesses = result.guesses() as f64;
+
+	if let Some(reference_year) = reference_year {
+		let current_year = current_year();
+		for year in result.sequence().iter().filter_map(match_year) {
+			guesses *= year_space(year, reference_year) / year_space(year, current_year);
+		}
+	}
+
+	let score = match guesses {
+		guesses if guesses < 1e3 + 5.0 => 0,
+		guesses if guesses < 1e6 + 5.0 => 1,
+		guesses if guesses < 1e8 + 5.0 => 2,
+		guesses if guesses < 1e10 + 5.0 => 3,
+		_ => 4,
+	};
+
+	let mut suggestions: Vec<&'static str> = Vec::new();
+	for found in result.sequence() {
+		if let Some(suggestion) = suggestion(found, user_inputs)
+			&& !suggestions.contains(&suggestion)
+		{
+			suggestions.push(suggestion);
+		}
+	}
+
+	if score < DEFAULT_MIN_SCORE {
+		suggestions.push("add_more_words");
+	}
+
+	PasswordEstimate {
+		guesses,
+		entropy: guesses.log2(),
+		score,
+		suggestions,
+	}
+}
+
+fn suggestion(found: &Match, user_inputs: &[&str]) -> Option<&'static str> {
+	match &found.pattern {
+		MatchPattern::Dictionary(pattern)
+			if user_inputs
+				.iter()
+				.any(|input| input.eq_ignore_ascii_case(&pattern.matched_word)) =>
+		{
+			Some("avoid_personal_info")
+		}
+		MatchPattern::Dictionary(pattern) if pattern.l33t => Some("avoid_predictable_substitutions"),
+		MatchPattern::Dictionary(_) => Some("avoid_common_passwords"),
+		MatchPattern::Spatial(_) => Some("avoid_keyboard_patterns"),
+		MatchPattern::Repeat(_) => Some("avoid_repeats"),
+		MatchPattern::Sequence(_) => Some("avoid_sequences"),
+		MatchPattern::Date(_) => Some("avoid_dates"),
+		MatchPattern::Regex(pattern) if pattern.regex_name == "recent_year" => Some("avoid_dates"),
+		_ => None,
+	}
+}
+
+fn match_year(found: &Match) -> Option<i32> {
+	match &found.pattern {
+		MatchPattern::Date(DatePattern { year, .. }) => Some(*year),
+		MatchPattern::Regex(RegexPattern {
+			regex_name,
+			regex_match,
+		}) if regex_name == "recent_year" => regex_match.first()?.parse().ok(),
+		_ => None,
+	}
+}
+
+fn year_space(year: i32, reference_year: i32) -> f64 {
+	f64::from((year - reference_year).abs()).max(MIN_YEAR_SPACE)
+}
+
+fn current_year() -> i32 {
+	let seconds = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map_or(0, |duration| duration.as_secs() as i64);
+
+	let days = seconds.div_euclid(86_400) + 719_468;
+	let era = days.div_euclid(146_097);
+	let day_of_era = days - era * 146_097;
+	let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let month = (5 * day_of_year + 2) / 153;
+	let year = year_of_era + era * 400 + i64::from(month >= 10);
+
+	year as i32
+}
+
+fn has_class(value: &str, class: &str) -> bool {
+	match class {
+		"upper" => value.chars().any(char::is_uppercase),
+		"lower" => value.chars().any(char::is_lowercase),
+		"digit" => value.chars().any(|char| char.is_ascii_digit()),
+		"symbol" => value
+			.chars()
+			.any(|char| !char.is_alphanumeric() && !char.is_whitespace()),
+		_ => false,
+	}
+}
+
+fn longest_repeat(value: &str) -> usize {
+	let mut longest = 0;
+	let mut current = 0;
+	let mut previous = None;
+
+	for char in value.chars() {
+		current = if previous == Some(char) { current + 1 } else { 1 };
+		longest = longest.max(current);
+		previous = Some(char);
+	}
+
+	longest
+}
+
+fn personal_tokens(value: &str) -> Vec<String> {
+	let value = value.trim().to_lowercase();
+	let value = value.split_once('@').map_or(value.as_str(), |(local, _)| local);
+
+	let mut tokens = vec![value.to_string()];
+	tokens.extend(
+		value
+			.split(|char: char| !char.is_alphanumeric())
+			.filter(|token| *token != value)
+			.map(str::to_string),
+	);
+
+	tokens.retain(|token| token.chars().count() >= 3);
+	tokens
+}
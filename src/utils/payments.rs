@@ -0,0 +1,105 @@
+use card_validate::Validate;
+
+pub fn card_brand(value: &str) -> Option<String> {
+	Validate::evaluate_type(value).ok().map(|brand| brand.name())
+}
+
+pub fn is_card_number(value: &str) -> bool {
+	Validate::from(value).is_ok()
+}
+
+pub fn is_luhn_valid(value: &str) -> bool {
+	if value.len() < 2 || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+		return false;
+	}
+
+	let checksum: u32 = value
+		.bytes()
+		.rev()
+		.enumerate()
+		.map(|(index, byte)| {
+			let digit = (byte - b'0') as u32;
+			match index % 2 {
+				0 => digit,
+				_ if digit > 4 => digit * 2 - 9,
+				_ => digit * 2,
+			}
+		})
+		.sum();
+
+	checksum.is_multiple_of(10)
+}
+
+pub fn iban_country(value: &str) -> Option<&str> {
+	let country = value.trim_start().get(..2)?;
+	country
+		.bytes()
+		.all(|byte| byte.is_ascii_alphabetic())
+		.then_some(country)
+}
+
+pub fn is_iban(value: &str) -> bool {
+	let iban: Vec<u8> = value
+		.bytes()
+		.filter(|byte| *byte != b' ')
+		.map(|byte| byte.to_ascii_uppercase())
+		.collect();
+
+	let Some(length) = iban.get(..2).and_then(iban_length) else {
+		return false;
+	};
+
+	if iban.len() != length
+		|| !iban[2..4].iter().all(u8::is_ascii_digit)
+		|| !iban[4..].iter().all(u8::is_ascii_alphanumeric)
+		|| matches!(&iban[2..4], b"00" | b"01" | b"99")
+	{
+		return false;
+	}
+
+	let remainder = iban[4..]
+		.iter()
+		.chain(&iban[..4])
+		.fold(0u32, |remainder, byte| match byte {
+			b'0'..=b'9' => (remainder * 10 + (byte - b'0') as u32) % 97,
+			_ => (remainder * 100 + (byte - b'A' + 10) as u32) % 97,
+		});
+
+	remainder == 1
+}
+
+pub fn is_bic(value: &str) -> bool {
+	let bytes = value.as_bytes();
+
+	matches!(bytes.len(), 8 | 11)
+		&& bytes[..6].iter().all(u8::is_ascii_uppercase)
+		&& bytes[6..]
+			.iter()
+			.all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit())
+}
+
+fn iban_length(country: &[u8]) -> Option<usize> {
+	let length = match country {
+		b"NO" => 15,
+		b"BE" => 16,
+		b"DK" | b"FI" | b"FK" | b"FO" | b"GL" | b"NL" | b"SD" => 18,
+		b"MK" | b"SI" => 19,
+		b"AT" | b"BA" | b"EE" | b"KZ" | b"LT" | b"LU" | b"MN" | b"XK" => 20,
+		b"CH" | b"HR" | b"LI" | b"LV" => 21,
+		b"BG" | b"BH" | b"CR" | b"DE" | b"GB" | b"GE" | b"IE" | b"ME" | b"RS" | b"VA" => 22,
+		b"AE" | b"GI" | b"IL" | b"IQ" | b"OM" | b"SO" | b"TL" => 23,
+		b"AD" | b"CZ" | b"ES" | b"MD" | b"PK" | b"RO" | b"SA" | b"SE" | b"SK" | b"TN" | b"VG" => 24,
+		b"LY" | b"PT" | b"ST" => 25,
+		b"IS" | b"TR" => 26,
+		b"BI" | b"DJ" | b"FR" | b"GR" | b"IT" | b"MC" | b"MR" | b"SM" => 27,
+		b"AL" | b"AZ" | b"BY" | b"CY" | b"DO" | b"GT" | b"HN" | b"HU" | b"LB" | b"NI" | b"PL" | b"SV" => 28,
+		b"BR" | b"EG" | b"PS" | b"QA" | b"UA" => 29,
+		b"JO" | b"KW" | b"MU" | b"YE" => 30,
+		b"MT" | b"SC" => 31,
+		b"LC" => 32,
+		b"RU" => 33,
+		_ => return None,
+	};
+
+	Some(length)
+}
@@ -0,0 +1,75 @@
+use phonenumber::{Mode, PhoneNumber, Type, metadata::DATABASE};
+
+pub use phonenumber::country::Id as PhoneRegion;
+
+use crate::settings::ValidationSettings;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PhonePolicy<'a> {
+	pub region: Option<&'a str>,
+	pub regions: &'a [&'a str],
+	pub types: &'a [&'a str],
+}
+
+impl PhonePolicy<'_> {
+	pub fn parse(&self, value: &str) -> Option<PhoneNumber> {
+		let is_international = value.trim_start().trim_start_matches("tel:").starts_with('+');
+		let region = match self.region {
+			_ if is_international => None,
+			Some(region) => Some(region.to_ascii_uppercase().parse::<PhoneRegion>().ok()?),
+			None => ValidationSettings::get_phone_region(),
+		};
+
+		let number = phonenumber::parse(region, value).ok()?;
+		if !number.is_valid() {
+			return None;
+		}
+
+		if !self.regions.is_empty() {
+			let region = number.country().id()?;
+			if !self
+				.regions
+				.iter()
+				.any(|allowed| allowed.eq_ignore_ascii_case(region.as_ref()))
+			{
+				return None;
+			}
+		}
+
+		if !self.types.is_empty() {
+			let kind = number.number_type(&DATABASE);
+			if !self.types.iter().any(|allowed| matches_type(allowed, kind)) {
+				return None;
+			}
+		}
+
+		Some(number)
+	}
+}
+
+pub fn to_e164(value: &str, region: Option<&str>) -> Option<String> {
+	let policy = PhonePolicy {
+		region,
+		..Default::default()
+	};
+
+	policy
+		.parse(value)
+		.map(|number| number.format().mode(Mode::E164).to_string())
+}
+
+fn matches_type(name: &str, kind: Type) -> bool {
+	match name {
+		"mobile" => matches!(kind, Type::Mobile | Type::FixedLineOrMobile),
+		"fixed_line" => matches!(kind, Type::FixedLine | Type::FixedLineOrMobile),
+		"toll_free" => kind == Type::TollFree,
+		"premium_rate" => kind == Type::PremiumRate,
+		"shared_cost" => kind == Type::SharedCost,
+		"personal_number" => kind == Type::PersonalNumber,
+		"voip" => kind == Type::Voip,
+		"pager" => kind == Type::Pager,
+		"uan" => kind == Type::Uan,
+		"voicemail" => kind == Type::Voicemail,
+		_ => false,
+	}
+}
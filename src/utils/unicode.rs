@@ -0,0 +1,76 @@
+pub use unicode_script::Script;
+pub use unicode_security::RestrictionLevel;
+
+use unicode_script::UnicodeScript;
+use unicode_security::{MixedScript, RestrictionLevelDetection, is_potential_mixed_script_confusable_char};
+
+pub fn script(name: &str) -> Option<Script> {
+	Script::from_full_name(name).or_else(|| Script::from_short_name(name))
+}
+
+pub type AllowedScripts = Result<Vec<Script>, String>;
+
+pub fn parse_scripts(names: &[&str]) -> AllowedScripts {
+	names
+		.iter()
+		.map(|name| script(name).ok_or_else(|| name.to_string()))
+		.collect()
+}
+
+pub fn find_disallowed_script(value: &str, allowed: &[Script]) -> Option<Script> {
+	value.chars().find_map(|char| {
+		let extension = char.script_extension();
+
+		if extension.is_common()
+			|| extension.is_inherited()
+			|| allowed.iter().any(|script| extension.contains_script(*script))
+		{
+			None
+		} else {
+			Some(char.script())
+		}
+	})
+}
+
+pub fn is_single_script(value: &str) -> bool {
+	value.is_single_script()
+}
+
+pub fn restriction_level(name: &str) -> Option<RestrictionLevel> {
+	match name {
+		"ascii" => Some(RestrictionLevel::ASCIIOnly),
+		"single_script" => Some(RestrictionLevel::SingleScript),
+		"highly_restrictive" => Some(RestrictionLevel::HighlyRestrictive),
+		"moderately_restrictive" => Some(RestrictionLevel::ModeratelyRestrictive),
+		"minimally_restrictive" => Some(RestrictionLevel::MinimallyRestrictive),
+		_ => None,
+	}
+}
+
+pub fn is_confusable_safe(value: &str, level: RestrictionLevel) -> bool {
+	value.check_restriction_level(level) && !is_whole_script_confusable(value)
+}
+
+pub fn is_whole_script_confusable(value: &str) -> bool {
+	let mut scripts: Vec<(Script, bool)> = Vec::new();
+
+	for char in value.chars() {
+		let script = char.script();
+
+		if matches!(
+			script,
+			Script::Common | Script::Inherited | Script::Latin | Script::Unknown
+		) {
+			continue;
+		}
+
+		let confusable = is_potential_mixed_script_confusable_char(char);
+
+		match scripts.iter_mut().find(|(current, _)| *current == script) {
+			Some((_, all_confusable)) => *all_confusable &= confusable,
+			None => scripts.push((script, confusable)),
+		}
+	}
+
+	scripts.iter().any(|(_, all_confusable)| *all_confusable)
+}
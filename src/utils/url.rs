@@ -0,0 +1,70 @@
+use std::net::IpAddr;
+
+use url::{Host, Url};
+
+use crate::utils::{hosts::matches_host, ip::is_internal_ip};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UrlPolicy<'a> {
+	pub schemes: &'a [&'a str],
+	pub require_host: bool,
+	pub allowed_hosts: &'a [&'a str],
+	pub blocked_hosts: &'a [&'a str],
+	pub deny_private_ips: bool,
+	pub max_length: Option<usize>,
+}
+
+impl UrlPolicy<'_> {
+	pub fn parse(&self, value: &str) -> Option<Url> {
+		if self.max_length.is_some_and(|max_length| value.len() > max_length) {
+			return None;
+		}
+
+		Url::parse(value).ok().filter(|url| self.allows(url))
+	}
+
+	pub fn allows(&self, url: &Url) -> bool {
+		if !self.schemes.is_empty()
+			&& !self
+				.schemes
+				.iter()
+				.any(|scheme| scheme.eq_ignore_ascii_case(url.scheme()))
+		{
+			return false;
+		}
+
+		let (Some(host), Some(host_name)) = (url.host(), url.host_str()) else {
+			return !self.require_host && self.allowed_hosts.is_empty();
+		};
+
+		if !self.allowed_hosts.is_empty()
+			&& !self
+				.allowed_hosts
+				.iter()
+				.any(|pattern| matches_host(pattern, host_name))
+		{
+			return false;
+		}
+
+		if self
+			.blocked_hosts
+			.iter()
+			.any(|pattern| matches_host(pattern, host_name))
+		{
+			return false;
+		}
+
+		!(self.deny_private_ips && is_private_host(&host))
+	}
+}
+
+fn is_private_host(host: &Host<&str>) -> bool {
+	match host {
+		Host::Domain(domain) => {
+			let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+			domain == "localhost" || domain.ends_with(".localhost") || domain == "metadata.google.internal"
+		}
+		Host::Ipv4(ip) => is_internal_ip(&IpAddr::V4(*ip)),
+		Host::Ipv6(ip) => is_internal_ip(&IpAddr::V6(*ip)),
+	}
+}
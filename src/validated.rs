@@ -0,0 +1,63 @@
+use std::ops::Deref;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+use crate::{core::SpecificValidateAndParse, utils::helpers::describe_errors};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Validated<T>(T);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checked<T>(pub(crate) T);
+
+macro_rules! impl_validated_value {
+	($($name:ident),*) => {
+		$(
+			impl<T> $name<T> {
+				pub fn into_inner(self) -> T {
+					self.0
+				}
+			}
+
+			impl<T> Deref for $name<T> {
+				type Target = T;
+
+				fn deref(&self) -> &Self::Target {
+					&self.0
+				}
+			}
+
+			impl<T> AsRef<T> for $name<T> {
+				fn as_ref(&self) -> &T {
+					&self.0
+				}
+			}
+
+			impl<T: Serialize> Serialize for $name<T> {
+				fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+					self.0.serialize(serializer)
+				}
+			}
+		)*
+	};
+}
+
+impl_validated_value!(Validated, Checked);
+
+impl<'de, T> Deserialize<'de> for Validated<T>
+where
+	T: SpecificValidateAndParse,
+	T::Wrapper: Deserialize<'de>,
+{
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let wrapper = T::Wrapper::deserialize(deserializer)?;
+
+		match T::specific_validate_and_parse(wrapper) {
+			Ok(value) => Ok(Validated(value)),
+			Err(errors) => Err(D::Error::custom(format!(
+				"validation failed: {}",
+				describe_errors(&errors)
+			))),
+		}
+	}
+}
@@ -9,5 +9,6 @@ pub mod modificate;
 pub mod modificate_with_context;
 pub mod payload;
 pub mod payload_with_context;
-pub mod with_context;
+pub mod unknown_fields;
 pub mod valid;
+pub mod with_context;
@@ -0,0 +1,105 @@
+use axum::{
+	Json, Router,
+	http::{Method, Request, StatusCode, header},
+	response::IntoResponse,
+	routing::post,
+};
+use http_body_util::BodyExt;
+use serde::Serialize;
+use serde_json::{Value, json};
+use tower::ServiceExt;
+use validy::core::Validate;
+
+use crate::utils::multipart_body::build_multipart_body;
+
+#[derive(Debug, Validate, Serialize)]
+#[validate(payload, axum, multipart, deny_unknown_fields)]
+#[wrapper_attribute(try_from_multipart(rename_all = "camelCase"))]
+pub struct TestDTO {
+	#[wrapper_attribute(form_data(field_name = "user_name"))]
+	#[modificate(trim)]
+	#[validate(length(3..=120))]
+	pub name: String,
+
+	#[wrapper_attribute(form_data(limit = "1KiB"))]
+	#[validate(email)]
+	pub email: String,
+
+	pub tag: Option<String>,
+
+	pub display_name: Option<String>,
+}
+
+pub async fn test_handle(data: TestDTO) -> impl IntoResponse {
+	(StatusCode::CREATED, Json(data))
+}
+
+async fn send(app: &Router, fields: &[(&str, &str)]) -> (StatusCode, Value) {
+	let (content_type, body) = build_multipart_body(fields);
+	let request = Request::builder()
+		.method(Method::POST)
+		.uri("/test")
+		.header(header::CONTENT_TYPE, content_type)
+		.body(body)
+		.unwrap();
+
+	let response = app.clone().oneshot(request).await.unwrap();
+	let status = response.status();
+	let bytes = response.into_body().collect().await.unwrap().to_bytes();
+	let body = serde_json::from_slice(&bytes).unwrap_or_else(|_| json!(String::from_utf8_lossy(&bytes)));
+
+	(status, body)
+}
+
+#[tokio::test]
+async fn should_report_unknown_fields() {
+	let app = Router::new().route("/test", post(test_handle));
+
+	let (status, body) = send(
+		&app,
+		&[
+			("user_name", " Alice "),
+			("email", "alice@test.com"),
+			("displayName", "Ali"),
+		],
+	)
+	.await;
+	assert_eq!(status, StatusCode::CREATED);
+	assert_eq!(
+		body,
+		json!({ "name": "Alice", "email": "alice@test.com", "tag": null, "display_name": "Ali" })
+	);
+
+	let (status, body) = send(
+		&app,
+		&[("user_name", "Alice"), ("emial", "alice@test.com"), ("tga", "a")],
+	)
+	.await;
+	assert_eq!(status, StatusCode::BAD_REQUEST);
+	assert_eq!(
+		body,
+		json!({ "emial": [{ "code": "unknown_field", "message": "is unknown" }] })
+	);
+
+	let (status, body) = send(&app, &[("user_name", "Alice"), ("display_name", "Ali")]).await;
+	assert_eq!(status, StatusCode::BAD_REQUEST);
+	assert_eq!(
+		body,
+		json!({ "display_name": [{ "code": "unknown_field", "message": "is unknown" }] })
+	);
+
+	let (status, _) = send(
+		&app,
+		&[
+			("user_name", "Alice"),
+			("user_name", "Bob"),
+			("email", "alice@test.com"),
+		],
+	)
+	.await;
+	assert_eq!(status, StatusCode::BAD_REQUEST);
+
+	let large = "x".repeat(2048);
+	let (status, _) = send(&app, &[("user_name", "Alice"), ("email", &large)]).await;
+	assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+}
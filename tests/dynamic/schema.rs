@@ -0,0 +1,555 @@
+use serde_json::json;
+use validy::{
+	assert_errors, assert_validation,
+	dynamic::{rule::Rule, schema::Schema},
+	settings::{FailureMode, LengthUnit},
+	validation_errors,
+};
+
+#[test]
+fn should_load_schemas_from_json_and_toml() {
+	let from_json = Schema::from_json(
+		r#"{
+			"email": [{ "rule": "email" }, { "rule": "length", "max": 254 }],
+			"name": [{ "rule": "required", "message": "name is required" }, { "rule": "trim" }]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	let from_toml: Schema = toml::from_str(
+		r#"
+			email = [{ rule = "email" }, { rule = "length", max = 254 }]
+			name = [{ rule = "required", message = "name is required" }, { rule = "trim" }]
+		"#,
+	)
+	.expect("should be a valid schema");
+
+	let from_code = Schema::new()
+		.field("email", [Rule::email(), Rule::length(None, Some(254))])
+		.field("name", [Rule::required().with_message("name is required"), Rule::Trim]);
+
+	assert_eq!(from_json, from_code);
+	assert_eq!(from_toml, from_code);
+
+	let result = Schema::from_json(r#"{ "email": [{ "rule": "unknown" }] }"#);
+	assert!(result.is_err());
+}
+
+#[test]
+fn should_validate_values() {
+	let schema = Schema::new()
+		.field(
+			"name",
+			[
+				Rule::required().with_message("name is required"),
+				Rule::length(Some(3), Some(5)),
+			],
+		)
+		.field("email", [Rule::email().with_code("bad_format")])
+		.field("age", [Rule::range(Some(18.0), None)])
+		.field("role", [Rule::allowlist([json!("admin"), json!("user")])])
+		.field(
+			"tags",
+			[Rule::length(None, Some(2)), Rule::for_each([Rule::prefix("#")])],
+		);
+
+	let value = json!({
+		"email": "test@gmail.com",
+		"age": 18,
+		"role": "admin",
+		"tags": ["#a", "#b"]
+	});
+
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"name" => ("required", "name is required"),
+	});
+
+	let value = json!({
+		"name": "abcdef",
+		"email": "test",
+		"age": 17.5,
+		"role": "guest",
+		"tags": ["#a", "b", "c"]
+	});
+
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"name" => ("length", "length out of range"),
+		"email" => ("bad_format", "invalid email format"),
+		"age" => ("range", "out of range"),
+		"role" => ("allowlist", "has item outside allowlist"),
+		"tags" => ("length", "length out of range"),
+	});
+
+	let value = json!({ "name": "abc", "email": null, "age": "18", "tags": ["#a", "b"] });
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"age" => ("type", "expected number"),
+		"tags" => ("prefix", "invalid prefix"),
+	});
+
+	let value = json!({ "name": "abc" });
+	let result = schema.validate(&value);
+	assert_validation!(result, value);
+
+	let value = json!(["abc"]);
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"" => ("type", "expected object"),
+	});
+}
+
+#[test]
+fn should_validate_nested_values() {
+	let schema = Schema::new().field(
+		"address",
+		[Rule::nested(
+			Schema::new()
+				.field("street", [Rule::required()])
+				.field("number", [Rule::range(Some(1.0), None)]),
+		)],
+	);
+
+	let value = json!({ "address": { "number": 0 } });
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"address" => ("nested", validation_errors! {
+			"street" => ("required", "is required"),
+			"number" => ("range", "out of range"),
+		}),
+	});
+
+	let value = json!({ "address": "street" });
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"address" => ("type", "expected object"),
+	});
+}
+
+#[test]
+fn should_modificate_values() {
+	let schema = Schema::new()
+		.field("name", [Rule::Trim, Rule::length(Some(3), None), Rule::Capitalize])
+		.field("code", [Rule::for_each([Rule::Trim, Rule::SnakeCase])]);
+
+	let mut value = json!({ "name": "  a  ", "code": [" Code A "] });
+	let result = schema.validate_and_modificate(&mut value);
+	assert_errors!(result, value, {
+		"name" => ("length", "length out of range"),
+	});
+
+	assert_eq!(value, json!({ "name": "  a  ", "code": [" Code A "] }));
+
+	let mut value = json!({ "name": "  john doe  ", "code": [" Code A ", "codeB"] });
+	let result = schema.validate_and_modificate(&mut value);
+	assert_validation!(result, value);
+	assert_eq!(value, json!({ "name": "John Doe", "code": ["code_a", "code_b"] }));
+
+	let value = json!({ "name": "  a  " });
+	let result = schema.validate(&value);
+	assert_validation!(result, value);
+}
+
+#[test]
+fn should_respect_length_units() {
+	let schema = Schema::from_json(
+		r#"{
+			"name": [{ "rule": "truncate", "max": 4, "unit": "graphemes" }, { "rule": "length", "max": 4, "unit": "bytes" }]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	assert_eq!(
+		schema,
+		Schema::new().field(
+			"name",
+			[
+				Rule::truncate(4).with_unit(LengthUnit::Graphemes),
+				Rule::length(None, Some(4)).with_unit(LengthUnit::Bytes),
+			]
+		)
+	);
+
+	let mut value = json!({ "name": "abcdef" });
+	let result = schema.validate_and_modificate(&mut value);
+	assert_validation!(result, value);
+	assert_eq!(value, json!({ "name": "abcd" }));
+
+	let mut value = json!({ "name": "山田太郎さん" });
+	let result = schema.validate_and_modificate(&mut value);
+	assert_errors!(result, value, {
+		"name" => ("length", "length out of range"),
+	});
+}
+
+#[test]
+fn should_respect_failure_modes() {
+	let value = json!({ "a": "abc", "b": "abc" });
+	let rules = [Rule::length(Some(5), None), Rule::prefix("b")];
+
+	let schema = Schema::new()
+		.field("a", rules.clone())
+		.field("b", rules.clone())
+		.with_failure_mode(FailureMode::FailFast);
+
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"a" => ("length", "length out of range"),
+	});
+
+	let schema = schema.with_failure_mode(FailureMode::FailOncePerField);
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"a" => ("length", "length out of range"),
+		"b" => ("length", "length out of range"),
+	});
+
+	let schema = schema.with_failure_mode(FailureMode::LastFailPerField);
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"a" => ("prefix", "invalid prefix"),
+		"b" => ("prefix", "invalid prefix"),
+	});
+
+	let schema = schema.with_failure_mode(FailureMode::FullFail);
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"a" => [("length", "length out of range"), ("prefix", "invalid prefix")],
+		"b" => [("length", "length out of range"), ("prefix", "invalid prefix")],
+	});
+
+	let from_json = Schema::from_json(
+		r#"{
+			"failure_mode": "full_fail",
+			"a": [{ "rule": "length", "min": 5 }, { "rule": "prefix", "prefix": "b" }],
+			"b": [{ "rule": "length", "min": 5 }, { "rule": "prefix", "prefix": "b" }]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	assert_eq!(from_json, schema);
+
+	let json = serde_json::to_string(&schema).expect("should serialize");
+	assert_eq!(Schema::from_json(&json).expect("should be a valid schema"), schema);
+}
+
+#[test]
+fn should_keep_reserved_field_names() {
+	let schema = Schema::new()
+		.field("failure_mode", [Rule::length(Some(5), None)])
+		.field("fields", [Rule::prefix("b")])
+		.with_failure_mode(FailureMode::FullFail);
+
+	let json = serde_json::to_string(&schema).expect("should serialize");
+	assert_eq!(Schema::from_json(&json).expect("should be a valid schema"), schema);
+
+	let toml = toml::to_string(&schema).expect("should serialize");
+	assert_eq!(
+		toml::from_str::<Schema>(&toml).expect("should be a valid schema"),
+		schema
+	);
+
+	let from_json = Schema::from_json(
+		r#"{
+			"failure_mode": [{ "rule": "length", "min": 5 }],
+			"fields": [{ "rule": "prefix", "prefix": "b" }]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	assert_eq!(from_json.with_failure_mode(FailureMode::FullFail), schema);
+
+	let value = json!({ "failure_mode": "abc", "fields": "abc" });
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"failure_mode" => ("length", "length out of range"),
+		"fields" => ("prefix", "invalid prefix"),
+	});
+}
+
+#[test]
+fn should_apply_email_policies() {
+	let schema = Schema::from_json(
+		r#"{
+			"email": [
+				{ "rule": "normalize_email", "strip_tag": true },
+				{ "rule": "email", "allowed_domains": ["example.com"], "require_tld": false }
+			]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	let mut value = json!({ "email": "user+news@EXAMPLE.com" });
+	let result = schema.validate_and_modificate(&mut value);
+	assert_validation!(result, value);
+	assert_eq!(value, json!({ "email": "user@example.com" }));
+
+	let mut value = json!({ "email": "user@example.org" });
+	let result = schema.validate_and_modificate(&mut value);
+	assert_errors!(result, value, {
+		"email" => ("email", "invalid email format"),
+	});
+}
+
+#[test]
+fn should_apply_ip_policies() {
+	let schema = Schema::from_json(
+		r#"{
+			"source": [{ "rule": "ip", "in": ["10.0.0.0/8"], "not_in": ["10.0.0.0/24"] }],
+			"target": [{ "rule": "ipv4", "class": "public" }],
+			"network": [{ "rule": "cidr", "max_prefix": 24, "strict": true }],
+			"listen": [{ "rule": "socket_addr" }],
+			"port": [{ "rule": "port", "min": 1024 }]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	let value = json!({
+		"source": "10.1.0.1",
+		"target": "8.8.8.8",
+		"network": "10.1.0.0/16",
+		"listen": "0.0.0.0:8080",
+		"port": 8080
+	});
+
+	let result = schema.validate(&value);
+	assert_validation!(result, value);
+
+	let value = json!({
+		"source": "10.0.0.1",
+		"target": "192.168.0.1",
+		"network": "10.1.0.1/16",
+		"listen": "0.0.0.0",
+		"port": "80"
+	});
+
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"source" => ("ip", "invalid ip format"),
+		"target" => ("ipv4", "invalid ipv4 format"),
+		"network" => ("cidr", "invalid cidr format"),
+		"listen" => ("socket_addr", "invalid socket address format"),
+		"port" => ("port", "invalid port"),
+	});
+}
+
+#[test]
+fn should_apply_domain_policies() {
+	let schema = Schema::from_json(
+		r#"{
+			"host": [{ "rule": "hostname" }],
+			"domain": [
+				{ "rule": "punycode" },
+				{ "rule": "domain", "allow_wildcard": true, "suffix_of": "example.com" }
+			]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	let mut value = json!({ "host": "smtp-relay", "domain": "*.Bücher.example.com" });
+	let result = schema.validate_and_modificate(&mut value);
+	assert_validation!(result, value);
+	assert_eq!(value["domain"], json!("*.xn--bcher-kva.example.com"));
+
+	let mut value = json!({ "host": "smtp_relay", "domain": "example.org" });
+	let result = schema.validate_and_modificate(&mut value);
+	assert_errors!(result, value, {
+		"host" => ("hostname", "invalid hostname"),
+		"domain" => ("domain", "invalid domain"),
+	});
+}
+
+#[test]
+fn should_apply_payment_rules() {
+	let schema = Schema::from_json(
+		r#"{
+			"card": [{ "rule": "digits_only" }, { "rule": "credit_card", "brands": ["visa"] }],
+			"account": [{ "rule": "iban", "countries": ["DE"] }],
+			"bank": [{ "rule": "bic" }],
+			"reference": [{ "rule": "luhn" }]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	let mut value = json!({
+		"card": "4111 1111 1111 1111",
+		"account": "DE89 3704 0044 0532 0130 00",
+		"bank": "DEUTDEFF",
+		"reference": "79927398713"
+	});
+
+	let result = schema.validate_and_modificate(&mut value);
+	assert_validation!(result, value);
+	assert_eq!(value["card"], json!("4111111111111111"));
+
+	let mut value = json!({
+		"card": "5555-5555-5555-4444",
+		"account": "GB82WEST12345698765432",
+		"bank": "DEUT",
+		"reference": "79927398710"
+	});
+
+	let result = schema.validate_and_modificate(&mut value);
+	assert_errors!(result, value, {
+		"card" => ("credit_card", ("invalid credit card number", [("brand", "mastercard")])),
+		"account" => ("iban", ("invalid iban", [("country", "GB")])),
+		"bank" => ("bic", "invalid bic"),
+		"reference" => ("luhn", "invalid luhn checksum"),
+	});
+}
+
+#[test]
+fn should_apply_phone_rules() {
+	let schema = Schema::from_json(
+		r#"{
+			"phone": [
+				{ "rule": "e164", "region": "BR" },
+				{ "rule": "phone", "regions": ["BR"], "types": ["mobile"] }
+			]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	let mut value = json!({ "phone": "(11) 98765-4321" });
+	let result = schema.validate_and_modificate(&mut value);
+	assert_validation!(result, value);
+	assert_eq!(value, json!({ "phone": "+5511987654321" }));
+
+	let mut value = json!({ "phone": "+1 201-555-0123" });
+	let result = schema.validate_and_modificate(&mut value);
+	assert_errors!(result, value, {
+		"phone" => ("phone", "invalid phone number"),
+	});
+}
+
+#[test]
+fn should_apply_iso_rules() {
+	let schema = Schema::from_json(
+		r#"{
+			"country": [{ "rule": "country", "format": "alpha2" }],
+			"currency": [{ "rule": "currency" }],
+			"language": [{ "rule": "language_tag" }],
+			"timezone": [{ "rule": "timezone" }]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	let mut value = json!({
+		"country": "BR",
+		"currency": "BRL",
+		"language": "pt-BR",
+		"timezone": "America/Sao_Paulo"
+	});
+
+	let result = schema.validate_and_modificate(&mut value);
+	assert_validation!(result, value);
+
+	let mut value = json!({
+		"country": "BRA",
+		"currency": "REAL",
+		"language": "pt_BR",
+		"timezone": "Brazil/Sao_Paulo"
+	});
+
+	let result = schema.validate_and_modificate(&mut value);
+	assert_errors!(result, value, {
+		"country" => ("country", "invalid country code"),
+		"currency" => ("currency", "invalid currency code"),
+		"language" => ("language_tag", "invalid language tag"),
+		"timezone" => ("timezone", "invalid timezone"),
+	});
+}
+
+#[test]
+fn should_apply_password_rules() {
+	let schema = Schema::from_json(
+		r#"{
+			"password": [
+				{ "rule": "password", "min_score": 2, "require": ["digit"], "forbid_fields": ["email"] }
+			]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	let mut value = json!({ "email": "johnny@example.com", "password": "Correct-Horse-42" });
+	let result = schema.validate_and_modificate(&mut value);
+	assert_validation!(result, value);
+	assert_eq!(value["password"], "Correct-Horse-42");
+
+	let mut value = json!({ "email": "johnny@example.com", "password": "Johnny-Battery-42" });
+	let result = schema.validate_and_modificate(&mut value);
+	assert_errors!(result, value, {
+		"password" => ("password", ("weak password", [("score", "4"), ("suggestions", "avoid_personal_info")])),
+	});
+
+	let value = json!({ "password": "password" });
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"password" => ("password", ("weak password", [("score", "0"), ("suggestions", "add_digit,avoid_common_passwords,add_more_words")])),
+	});
+}
+
+#[test]
+fn should_apply_character_rules() {
+	let schema = Schema::from_json(
+		r#"{
+			"username": [{ "rule": "charset", "allowed": "a-z0-9_-" }, { "rule": "no_zero_width" }],
+			"name": [{ "rule": "printable" }, { "rule": "single_line" }],
+			"code": [{ "rule": "ascii" }, { "rule": "alphanumeric" }],
+			"digits": [{ "rule": "numeric" }],
+			"bio": [{ "rule": "no_control_chars" }]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	let value = json!({
+		"username": "john_doe-1",
+		"name": "John Doe",
+		"code": "ABC123",
+		"digits": "0042",
+		"bio": "Line one\nLine two"
+	});
+
+	let result = schema.validate(&value);
+	assert_validation!(result, value);
+
+	let value = json!({
+		"username": "John",
+		"name": "John\nDoe",
+		"code": "ÀBC123",
+		"digits": "42a",
+		"bio": "bell\u{7}"
+	});
+
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"username" => ("charset", ("has characters outside the allowed set", [("char", "J")])),
+		"name" => ("printable", "has non-printable characters"),
+		"code" => ("ascii", "must contain only ascii characters"),
+		"digits" => ("numeric", "must contain only digits"),
+		"bio" => ("no_control_chars", "has control characters"),
+	});
+}
+
+#[test]
+fn should_apply_unicode_rules() {
+	let schema = Schema::from_json(
+		r#"{
+			"username": [{ "rule": "confusable_safe", "level": "single_script" }],
+			"name": [{ "rule": "unicode_scripts", "allowed": ["Latin"] }, { "rule": "no_mixed_script" }]
+		}"#,
+	)
+	.expect("should be a valid schema");
+
+	let value = json!({ "username": "josé", "name": "José Silva" });
+	let result = schema.validate(&value);
+	assert_validation!(result, value);
+
+	let value = json!({ "username": "раура", "name": "Пётр" });
+	let result = schema.validate(&value);
+	assert_errors!(result, value, {
+		"username" => ("confusable_safe", "has confusable characters"),
+		"name" => ("unicode_scripts", ("has characters from disallowed scripts", [("script", "Cyrillic")])),
+	});
+}
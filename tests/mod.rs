@@ -1,10 +1,14 @@
 #[cfg(test)]
 pub mod axum;
 #[cfg(test)]
+pub mod dynamic;
+#[cfg(test)]
 pub mod modifications;
 #[cfg(test)]
 pub mod parsings;
 #[cfg(test)]
+pub mod serde;
+#[cfg(test)]
 pub mod utils;
 #[cfg(test)]
 pub mod validations;
@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+
+use serde::Deserialize;
+use validy::core::{Validate, ValidateAndModificate, ValidateAndParse};
+
+use validy::{assert_modification, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+#[validate(modificate)]
+struct Test {
+	#[modificate(digits_only)]
+	pub a: String,
+	#[modificate(digits_only)]
+	pub b: Option<String>,
+}
+
+#[derive(Debug, Validate)]
+#[validate(payload)]
+struct BorrowedTest<'a> {
+	#[modificate(digits_only)]
+	pub a: Cow<'a, str>,
+}
+
+#[test]
+fn should_modificate_digits_only() {
+	let cases = [
+		("", ""),
+		("4111111111111111", "4111111111111111"),
+		("4111 1111 1111 1111", "4111111111111111"),
+		("4111-1111-1111-1111", "4111111111111111"),
+		("+1 (555) 010-9999", "15550109999"),
+		("abc", ""),
+		("١٢٣4", "4"),
+	];
+
+	let mut test = Test::default();
+	for (case, expected) in cases.iter() {
+		test.a = case.to_string();
+		test.b = Some(case.to_string());
+		let result = test.validate_and_modificate();
+
+		assert_validation!(result, test);
+		assert_modification!(test.a, expected.to_string(), test);
+		assert_modification!(test.b, Some(expected.to_string()), test);
+	}
+}
+
+#[test]
+fn should_keep_digits_borrowed() {
+	let wrapper = BorrowedTestWrapper {
+		a: Some(Cow::Borrowed("4111111111111111")),
+	};
+
+	let result = BorrowedTest::validate_and_parse(wrapper).unwrap();
+	assert!(matches!(result.a, Cow::Borrowed("4111111111111111")));
+}
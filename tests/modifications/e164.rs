@@ -0,0 +1,57 @@
+use std::borrow::Cow;
+
+use serde::Deserialize;
+use validy::core::{Validate, ValidateAndModificate, ValidateAndParse};
+
+use validy::{assert_modification, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+#[validate(modificate)]
+struct Test {
+	#[modificate(e164(region = "US"))]
+	pub a: String,
+	#[modificate(e164(region = "BR"))]
+	pub b: Option<String>,
+}
+
+#[derive(Debug, Validate)]
+#[validate(payload)]
+struct BorrowedTest<'a> {
+	#[modificate(e164)]
+	pub a: Cow<'a, str>,
+}
+
+#[test]
+fn should_modificate_e164s() {
+	let cases = [
+		("", "", ""),
+		("+5511987654321", "+5511987654321", "+5511987654321"),
+		("+55 (11) 98765-4321", "+5511987654321", "+5511987654321"),
+		("+1 201-555-0123", "+12015550123", "+12015550123"),
+		("(201) 555-0123", "+12015550123", "(201) 555-0123"),
+		("(11) 98765-4321", "(11) 98765-4321", "+5511987654321"),
+		("+447700900123", "+447700900123", "+447700900123"),
+		("phone", "phone", "phone"),
+	];
+
+	let mut test = Test::default();
+	for (case, a, b) in cases.iter() {
+		test.a = case.to_string();
+		test.b = Some(case.to_string());
+		let result = test.validate_and_modificate();
+
+		assert_validation!(result, test);
+		assert_modification!(test.a, a.to_string(), test);
+		assert_modification!(test.b, Some(b.to_string()), test);
+	}
+}
+
+#[test]
+fn should_keep_e164s_borrowed() {
+	let wrapper = BorrowedTestWrapper {
+		a: Some(Cow::Borrowed("+5511987654321")),
+	};
+
+	let result = BorrowedTest::validate_and_parse(wrapper).unwrap();
+	assert!(matches!(result.a, Cow::Borrowed("+5511987654321")));
+}
@@ -5,6 +5,8 @@ pub mod capitalize;
 pub mod custom;
 
 pub mod custom_with_context;
+pub mod digits_only;
+pub mod e164;
 pub mod fail_fast;
 pub mod fail_once_per_field;
 pub mod full_fail;
@@ -13,6 +15,8 @@ pub mod kebab_case;
 pub mod last_fail_per_field;
 pub mod lower_camel_case;
 pub mod lowercase;
+pub mod normalize_email;
+pub mod punycode;
 pub mod shouty_kebab_case;
 pub mod shouty_snake_case;
 pub mod snake_case;
@@ -20,4 +24,5 @@ pub mod train_case;
 pub mod trim;
 pub mod trim_end;
 pub mod trim_start;
+pub mod truncate;
 pub mod uppercase;
@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+
+use serde::Deserialize;
+use validy::core::{Validate, ValidateAndModificate, ValidateAndParse};
+
+use validy::{assert_modification, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+#[validate(modificate)]
+struct Test {
+	#[modificate(normalize_email)]
+	pub a: String,
+	#[modificate(normalize_email(strip_tag = true))]
+	pub b: Option<String>,
+}
+
+#[derive(Debug, Validate)]
+#[validate(payload)]
+struct BorrowedTest<'a> {
+	#[modificate(normalize_email)]
+	pub a: Cow<'a, str>,
+}
+
+#[test]
+fn should_modificate_normalize_emails() {
+	let cases = [
+		("", "", ""),
+		("user", "user", "user"),
+		("user@example.com", "user@example.com", "user@example.com"),
+		("User@EXAMPLE.Com", "User@example.com", "User@example.com"),
+		("user+news@Example.com", "user+news@example.com", "user@example.com"),
+		("user@Bücher.de", "user@xn--bcher-kva.de", "user@xn--bcher-kva.de"),
+		(r#""a+b"@example.com"#, r#""a+b"@example.com"#, r#""a+b"@example.com"#),
+	];
+
+	let mut test = Test::default();
+	for (case, a, b) in cases.iter() {
+		test.a = case.to_string();
+		test.b = Some(case.to_string());
+		let result = test.validate_and_modificate();
+
+		assert_validation!(result, test);
+		assert_modification!(test.a, a.to_string(), test);
+		assert_modification!(test.b, Some(b.to_string()), test);
+	}
+}
+
+#[test]
+fn should_keep_normalized_emails_borrowed() {
+	let wrapper = BorrowedTestWrapper {
+		a: Some(Cow::Borrowed("user@example.com")),
+	};
+
+	let result = BorrowedTest::validate_and_parse(wrapper).unwrap();
+	assert!(matches!(result.a, Cow::Borrowed("user@example.com")));
+}
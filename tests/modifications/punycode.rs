@@ -0,0 +1,56 @@
+use std::borrow::Cow;
+
+use serde::Deserialize;
+use validy::core::{Validate, ValidateAndModificate, ValidateAndParse};
+
+use validy::{assert_modification, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+#[validate(modificate)]
+struct Test {
+	#[modificate(punycode)]
+	pub a: String,
+	#[modificate(punycode)]
+	pub b: Option<String>,
+}
+
+#[derive(Debug, Validate)]
+#[validate(payload)]
+struct BorrowedTest<'a> {
+	#[modificate(punycode)]
+	pub a: Cow<'a, str>,
+}
+
+#[test]
+fn should_modificate_punycodes() {
+	let cases = [
+		("", ""),
+		("example.com", "example.com"),
+		("Example.COM", "example.com"),
+		("bücher.de", "xn--bcher-kva.de"),
+		("münchen.example.com", "xn--mnchen-3ya.example.com"),
+		("xn--bcher-kva.de", "xn--bcher-kva.de"),
+		("not a domain", "not a domain"),
+	];
+
+	let mut test = Test::default();
+	for (case, expected) in cases.iter() {
+		test.a = case.to_string();
+		test.b = Some(case.to_string());
+		let result = test.validate_and_modificate();
+
+		assert_validation!(result, test);
+		assert_modification!(test.a, expected.to_string(), test);
+		assert_modification!(test.b, Some(expected.to_string()), test);
+	}
+}
+
+#[test]
+fn should_keep_punycodes_borrowed() {
+	let wrapper = BorrowedTestWrapper {
+		a: Some(Cow::Borrowed("example.com")),
+	};
+
+	let result = BorrowedTest::validate_and_parse(wrapper).unwrap();
+	assert!(matches!(result.a, Cow::Borrowed("example.com")));
+}
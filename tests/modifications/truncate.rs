@@ -0,0 +1,71 @@
+use std::borrow::Cow;
+
+use serde::Deserialize;
+use validy::core::{Validate, ValidateAndModificate, ValidateAndParse};
+
+use validy::{assert_modification, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+#[validate(modificate)]
+struct Test {
+	#[modificate(truncate(4, "chars"))]
+	pub a: String,
+	#[modificate(truncate(4, unit = "graphemes"))]
+	pub b: Option<String>,
+	#[modificate(truncate(max = 4, unit = "utf16"))]
+	pub c: String,
+	#[modificate(truncate(4, "bytes"))]
+	pub d: String,
+}
+
+#[derive(Debug, Validate)]
+#[validate(payload)]
+struct BorrowedTest<'a> {
+	#[modificate(truncate(4, "chars"))]
+	pub a: Cow<'a, str>,
+}
+
+#[test]
+fn should_modificate_truncates() {
+	let cases = [
+		("", ["", "", "", ""]),
+		("abc", ["abc", "abc", "abc", "abc"]),
+		("abcdef", ["abcd", "abcd", "abcd", "abcd"]),
+		("山田太郎さん", ["山田太郎", "山田太郎", "山田太郎", "山"]),
+		("😀😀😀", ["😀😀😀", "😀😀😀", "😀😀", "😀"]),
+		(
+			"e\u{301}e\u{301}e\u{301}",
+			[
+				"e\u{301}e\u{301}",
+				"e\u{301}e\u{301}e\u{301}",
+				"e\u{301}e\u{301}",
+				"e\u{301}e",
+			],
+		),
+	];
+
+	let mut test = Test::default();
+	for (case, expected) in cases.iter() {
+		test.a = case.to_string();
+		test.b = Some(case.to_string());
+		test.c = case.to_string();
+		test.d = case.to_string();
+		let result = test.validate_and_modificate();
+
+		assert_validation!(result, test);
+		assert_modification!(test.a, expected[0].to_string(), test);
+		assert_modification!(test.b, Some(expected[1].to_string()), test);
+		assert_modification!(test.c, expected[2].to_string(), test);
+		assert_modification!(test.d, expected[3].to_string(), test);
+	}
+}
+
+#[test]
+fn should_truncate_borrowed_values_without_allocating() {
+	let wrapper = BorrowedTestWrapper {
+		a: Some(Cow::Borrowed("abcdef")),
+	};
+
+	let result = BorrowedTest::validate_and_parse(wrapper).unwrap();
+	assert!(matches!(result.a, Cow::Borrowed("abcd")));
+}
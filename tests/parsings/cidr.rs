@@ -0,0 +1,59 @@
+use ipnet::IpNet;
+
+use validy::core::{Validate, ValidateAndParse};
+
+use validy::{assert_errors, assert_parsed};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[special(from_type(String))]
+	#[parse(cidr)]
+	pub a: IpNet,
+	#[special(from_type(String))]
+	#[parse(cidr(max_prefix = 24, strict = true))]
+	pub b: Option<IpNet>,
+}
+
+#[test]
+fn should_parse_cidrs() {
+	let cases = ["10.0.0.0/8", "192.168.1.1/32", "2001:db8::/32"];
+
+	let mut wrapper = TestWrapper::default();
+	let mut result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"a" => ("required", "is required"),
+	});
+
+	for case in cases.iter() {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+
+		let expected: IpNet = case.parse().expect("should be a valid cidr");
+		assert_parsed!(result, wrapper, Test { a: expected, b: None });
+	}
+
+	wrapper.a = Some("10.0.0.1".to_string());
+	result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"a" => ("cidr", "invalid cidr format"),
+	});
+
+	wrapper.a = Some("10.0.0.0/8".to_string());
+	for case in ["10.0.0.0/25", "10.0.0.1/24"] {
+		wrapper.b = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+		assert_errors!(result, wrapper, {
+			"b" => ("cidr", "invalid cidr format"),
+		});
+	}
+
+	wrapper.b = Some("10.1.0.0/16".to_string());
+	let parsed = Test::validate_and_parse(wrapper.clone()).expect("should be valid");
+	assert_eq!(parsed.b.map(|network| network.prefix_len()), Some(16));
+
+	let reversed = TestWrapper::from(parsed);
+	assert_eq!(reversed.a.as_deref(), Some("10.0.0.0/8"));
+	assert_eq!(reversed.b.as_deref(), Some("10.1.0.0/16"));
+}
@@ -0,0 +1,61 @@
+use validy::core::{Validate, ValidateAndParse};
+use validy::functions::parsing::iso::CountryCode;
+
+use validy::{assert_errors, assert_parsed};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[special(from_type(String))]
+	#[parse(country)]
+	pub a: CountryCode,
+	#[special(from_type(String))]
+	#[parse(country(format = "alpha3"))]
+	pub b: Option<CountryCode>,
+}
+
+#[test]
+fn should_parse_countries() {
+	let cases = [
+		("BR", CountryCode::BRA),
+		("BRA", CountryCode::BRA),
+		("US", CountryCode::USA),
+		("DEU", CountryCode::DEU),
+	];
+
+	let mut wrapper = TestWrapper::default();
+	let mut result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"a" => ("required", "is required"),
+	});
+
+	for (case, expected) in cases.iter() {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+		assert_parsed!(result, wrapper, Test { a: *expected, b: None });
+	}
+
+	for case in ["br", "XX", "BRAZ", ""] {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+		assert_errors!(result, wrapper, {
+			"a" => ("country", "invalid country code"),
+		});
+	}
+
+	wrapper.a = Some("BRA".to_string());
+	wrapper.b = Some("PT".to_string());
+	result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"b" => ("country", "invalid country code"),
+	});
+
+	wrapper.b = Some("PRT".to_string());
+	let parsed = Test::validate_and_parse(wrapper.clone()).expect("should be valid");
+	assert_eq!(parsed.b, Some(CountryCode::PRT));
+
+	let reversed = TestWrapper::from(parsed);
+	assert_eq!(reversed.a.as_deref(), Some("BR"));
+	assert_eq!(reversed.b.as_deref(), Some("PRT"));
+}
@@ -0,0 +1,62 @@
+use validy::core::{Validate, ValidateAndParse};
+use validy::functions::parsing::iso::Currency;
+
+use validy::{assert_errors, assert_parsed};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[special(from_type(String))]
+	#[parse(currency)]
+	pub a: Currency,
+	#[special(from_type(String))]
+	#[parse(currency("custom message", "custom_code"))]
+	pub b: Option<Currency>,
+}
+
+#[test]
+fn should_parse_currencies() {
+	let brl = Currency {
+		code: "BRL",
+		numeric: 986,
+		minor_units: Some(2),
+		name: "Brazilian Real",
+	};
+
+	let mut wrapper = TestWrapper::default();
+	let mut result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"a" => ("required", "is required"),
+	});
+
+	wrapper.a = Some("BRL".to_string());
+	result = Test::validate_and_parse(wrapper.clone());
+	assert_parsed!(result, wrapper, Test { a: brl, b: None });
+
+	for case in ["brl", "ABC", "BR", ""] {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+		assert_errors!(result, wrapper, {
+			"a" => ("currency", "invalid currency code"),
+		});
+	}
+
+	wrapper.a = Some("BRL".to_string());
+	wrapper.b = Some("EURO".to_string());
+	result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"b" => ("custom_code", "custom message"),
+	});
+
+	wrapper.b = Some("JPY".to_string());
+	let parsed = Test::validate_and_parse(wrapper.clone()).expect("should be valid");
+	let b = parsed.b.expect("should be parsed");
+	assert_eq!(b.code, "JPY");
+	assert_eq!(b.numeric, 392);
+	assert_eq!(b.minor_units, Some(0));
+
+	let reversed = TestWrapper::from(parsed);
+	assert_eq!(reversed.a.as_deref(), Some("BRL"));
+	assert_eq!(reversed.b.as_deref(), Some("JPY"));
+}
@@ -0,0 +1,69 @@
+use validy::core::{Validate, ValidateAndParse};
+use validy::functions::parsing::domain::Domain;
+
+use validy::{assert_errors, assert_parsed};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[special(from_type(String))]
+	#[parse(domain)]
+	pub a: Domain,
+	#[special(from_type(String))]
+	#[parse(domain(allow_wildcard = true, suffix_of = "example.com"))]
+	pub b: Option<Domain>,
+}
+
+#[test]
+fn should_parse_domains() {
+	let cases = [
+		("example.com", "example.com"),
+		("Sub.Example.COM.", "sub.example.com"),
+		("bücher.de", "xn--bcher-kva.de"),
+	];
+
+	let mut wrapper = TestWrapper::default();
+	let mut result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"a" => ("required", "is required"),
+	});
+
+	for (case, expected) in cases.iter() {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+
+		let parsed = result.as_ref().expect("should be valid").a.clone();
+		assert_eq!(parsed.as_str(), *expected);
+		assert_parsed!(result, wrapper, Test { a: parsed, b: None });
+	}
+
+	for case in ["localhost", "*.example.com", "ex_ample.com", ""] {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+		assert_errors!(result, wrapper, {
+			"a" => ("domain", "invalid domain"),
+		});
+	}
+
+	wrapper.a = Some("bücher.de".to_string());
+	wrapper.b = Some("example.org".to_string());
+	result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"b" => ("domain", "invalid domain"),
+	});
+
+	wrapper.b = Some("*.App.Example.com".to_string());
+	let parsed = Test::validate_and_parse(wrapper.clone()).expect("should be valid");
+	assert_eq!(parsed.a.to_unicode(), "bücher.de");
+	assert_eq!(parsed.a.tld(), "de");
+
+	let b = parsed.b.as_ref().expect("should be parsed");
+	assert!(b.is_wildcard());
+	assert_eq!(b.as_str(), "*.app.example.com");
+	assert_eq!(b.labels().collect::<Vec<_>>(), ["*", "app", "example", "com"]);
+
+	let reversed = TestWrapper::from(parsed);
+	assert_eq!(reversed.a.as_deref(), Some("xn--bcher-kva.de"));
+	assert_eq!(reversed.b.as_deref(), Some("*.app.example.com"));
+}
@@ -0,0 +1,62 @@
+use email_address::EmailAddress;
+
+use validy::core::{Validate, ValidateAndParse};
+
+use validy::{assert_errors, assert_parsed};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[special(from_type(String))]
+	#[parse(email)]
+	pub a: EmailAddress,
+	#[special(from_type(String))]
+	#[parse(email(allow_display_name = true, allowed_domains = ["example.com"]))]
+	pub b: Option<EmailAddress>,
+}
+
+#[test]
+fn should_parse_emails() {
+	let cases = ["user@example.com", "first.last+tag@mail.example.org", "user@bücher.de"];
+
+	let mut wrapper = TestWrapper::default();
+	let mut result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"a" => ("required", "is required"),
+	});
+
+	for case in cases.iter() {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+
+		let expected = case.parse::<EmailAddress>().expect("should be a valid email");
+		assert_parsed!(result, wrapper, Test { a: expected, b: None });
+	}
+
+	for case in ["user", "user@localhost", "User <user@example.com>"] {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+		assert_errors!(result, wrapper, {
+			"a" => ("email", "invalid email format"),
+		});
+	}
+
+	wrapper.a = Some("user@example.com".to_string());
+	wrapper.b = Some("user@example.org".to_string());
+	result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"b" => ("email", "invalid email format"),
+	});
+
+	wrapper.b = Some("Jane Doe <jane@example.com>".to_string());
+	let parsed = Test::validate_and_parse(wrapper.clone()).expect("should be valid");
+	let b = parsed.b.as_ref().expect("should be parsed");
+	assert_eq!(b.display_part(), "Jane Doe");
+	assert_eq!(b.local_part(), "jane");
+	assert_eq!(b.domain(), "example.com");
+
+	let reversed = TestWrapper::from(parsed);
+	assert_eq!(reversed.a.as_deref(), Some("user@example.com"));
+	assert_eq!(reversed.b.as_deref(), Some("Jane Doe <jane@example.com>"));
+}
@@ -2,6 +2,9 @@ use std::net::IpAddr;
 use std::str::FromStr;
 
 use validy::core::{Validate, ValidateAndParse};
+use validy::functions::parsing::ip::{
+	IpClass, IpPolicy, parse_ip, parse_ip_with_policy, parse_ipv4_with_policy, parse_ipv6,
+};
 
 use validy::{assert_errors, assert_parsed};
 
@@ -17,6 +20,17 @@ struct Test {
 	pub b: Option<IpAddr>,
 }
 
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload)]
+#[wrapper_derive(Debug, Clone)]
+struct PolicyTest {
+	#[validate(ip(class = "private"))]
+	pub a: String,
+	#[special(from_type(String))]
+	#[parse(ip(in = ["10.0.0.0/8"], class = "private"))]
+	pub b: IpAddr,
+}
+
 #[test]
 fn should_parse_ips() {
 	let cases = [
@@ -79,3 +93,43 @@ fn should_parse_ips() {
 		"a" => ("ip", "invalid ip format"),
 	});
 }
+
+#[test]
+fn should_parse_ips_with_policies() {
+	let mut wrapper = PolicyTestWrapper {
+		a: Some("192.168.0.1".to_string()),
+		b: Some("10.1.2.3".to_string()),
+	};
+
+	let result = PolicyTest::validate_and_parse(wrapper.clone()).expect("should be valid");
+	assert_eq!(result.b, IpAddr::from_str("10.1.2.3").expect("should be a valid ip"));
+
+	for case in ["192.168.0.1", "8.8.8.8"] {
+		wrapper.b = Some(case.to_string());
+		let result = PolicyTest::validate_and_parse(wrapper.clone());
+		assert_errors!(result, wrapper, {
+			"b" => ("ip", "invalid ip format"),
+		});
+	}
+}
+
+#[test]
+fn should_parse_ips_with_functions() {
+	let (ip, error) = parse_ip("10.0.0.1", "a", "ip", "invalid ip format");
+	assert_eq!(ip, IpAddr::from_str("10.0.0.1").expect("should be a valid ip"));
+	assert!(error.is_none());
+
+	let policy = IpPolicy {
+		class: Some(IpClass::Public),
+		..Default::default()
+	};
+
+	let (_, error) = parse_ip_with_policy("10.0.0.1", &policy, "a", "ip", "invalid ip format");
+	assert!(error.is_some());
+
+	let (_, error) = parse_ipv4_with_policy("8.8.8.8", &policy, "a", "ipv4", "invalid ipv4 format");
+	assert!(error.is_none());
+
+	let (_, error) = parse_ipv6("::1", "a", "ipv6", "invalid ipv6 format");
+	assert!(error.is_none());
+}
@@ -0,0 +1,61 @@
+use validy::core::{Validate, ValidateAndParse};
+use validy::functions::parsing::iso::LanguageTag;
+
+use validy::{assert_errors, assert_parsed};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[special(from_type(String))]
+	#[parse(language_tag)]
+	pub a: LanguageTag,
+	#[special(from_type(String))]
+	#[parse(language_tag("custom message", "custom_code"))]
+	pub b: Option<LanguageTag>,
+}
+
+#[test]
+fn should_parse_language_tags() {
+	let cases = ["en", "en-US", "pt-BR", "zh-Hant-TW"];
+
+	let mut wrapper = TestWrapper::default();
+	let mut result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"a" => ("required", "is required"),
+	});
+
+	for case in cases.iter() {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+
+		let expected = LanguageTag::parse(case).expect("should be a valid language tag");
+		assert_parsed!(result, wrapper, Test { a: expected, b: None });
+	}
+
+	for case in ["en_US", "english", "xx", ""] {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+		assert_errors!(result, wrapper, {
+			"a" => ("language_tag", "invalid language tag"),
+		});
+	}
+
+	wrapper.a = Some("en".to_string());
+	wrapper.b = Some("pt_BR".to_string());
+	result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"b" => ("custom_code", "custom message"),
+	});
+
+	wrapper.b = Some("sr-Latn-RS".to_string());
+	let parsed = Test::validate_and_parse(wrapper.clone()).expect("should be valid");
+	let b = parsed.b.as_ref().expect("should be parsed");
+	assert_eq!(b.primary_language(), "sr");
+	assert_eq!(b.script(), Some("Latn"));
+	assert_eq!(b.region(), Some("RS"));
+
+	let reversed = TestWrapper::from(parsed);
+	assert_eq!(reversed.a.as_deref(), Some("en"));
+	assert_eq!(reversed.b.as_deref(), Some("sr-Latn-RS"));
+}
@@ -1,7 +1,12 @@
 pub mod async_custom;
 pub mod async_custom_with_context;
+pub mod cidr;
+pub mod country;
+pub mod currency;
 pub mod custom;
 pub mod custom_with_context;
+pub mod domain;
+pub mod email;
 pub mod fail_fast;
 pub mod fail_once_per_field;
 pub mod full_fail;
@@ -9,8 +14,13 @@ pub mod inline;
 pub mod ip;
 pub mod ipv4;
 pub mod ipv6;
+pub mod language_tag;
 pub mod last_fail_per_field;
 pub mod naive_date;
 pub mod naive_time;
+pub mod phone;
+pub mod socket_addr;
 pub mod time;
+pub mod timezone;
+pub mod url;
 pub mod uuid;
@@ -0,0 +1,62 @@
+use phonenumber::{PhoneNumber, country::Id};
+
+use validy::core::{Validate, ValidateAndParse};
+
+use validy::{assert_errors, assert_parsed};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[special(from_type(String))]
+	#[parse(phone)]
+	pub a: PhoneNumber,
+	#[special(from_type(String))]
+	#[parse(phone(region = "BR", types = ["mobile"]))]
+	pub b: Option<PhoneNumber>,
+}
+
+#[test]
+fn should_parse_phones() {
+	let cases = ["+5511987654321", "+1 201-555-0123", "+44 20 7946 0000"];
+
+	let mut wrapper = TestWrapper::default();
+	let mut result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"a" => ("required", "is required"),
+	});
+
+	for case in cases.iter() {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+
+		let expected = phonenumber::parse(None, case).expect("should be a valid phone");
+		assert_parsed!(result, wrapper, Test { a: expected, b: None });
+	}
+
+	for case in ["+447700900123", "phone", ""] {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+		assert_errors!(result, wrapper, {
+			"a" => ("phone", "invalid phone number"),
+		});
+	}
+
+	wrapper.a = Some("+1 201-555-0123".to_string());
+	wrapper.b = Some("(11) 3333-4444".to_string());
+	result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"b" => ("phone", "invalid phone number"),
+	});
+
+	wrapper.b = Some("(11) 98765-4321".to_string());
+	let parsed = Test::validate_and_parse(wrapper.clone()).expect("should be valid");
+	let b = parsed.b.as_ref().expect("should be parsed");
+	assert_eq!(b.country().id(), Some(Id::BR));
+	assert_eq!(b.code().value(), 55);
+	assert_eq!(b.national().value(), 11987654321);
+
+	let reversed = TestWrapper::from(parsed);
+	assert_eq!(reversed.a.as_deref(), Some("+12015550123"));
+	assert_eq!(reversed.b.as_deref(), Some("+5511987654321"));
+}
@@ -0,0 +1,42 @@
+use std::net::SocketAddr;
+
+use validy::core::{Validate, ValidateAndParse};
+
+use validy::{assert_errors, assert_parsed};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[special(from_type(String))]
+	#[parse(socket_addr)]
+	pub a: SocketAddr,
+	#[special(from_type(String))]
+	#[parse(socket_addr("custom message", "custom_code"))]
+	pub b: Option<SocketAddr>,
+}
+
+#[test]
+fn should_parse_socket_addrs() {
+	let cases = ["127.0.0.1:8080", "[::1]:443"];
+
+	let mut wrapper = TestWrapper::default();
+	let mut result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"a" => ("required", "is required"),
+	});
+
+	for case in cases.iter() {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+
+		let expected: SocketAddr = case.parse().expect("should be a valid socket address");
+		assert_parsed!(result, wrapper, Test { a: expected, b: None });
+	}
+
+	wrapper.b = Some("localhost:80".to_string());
+	result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
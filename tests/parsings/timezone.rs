@@ -0,0 +1,60 @@
+use validy::core::{Validate, ValidateAndParse};
+use validy::functions::parsing::iso::Tz;
+
+use validy::{assert_errors, assert_parsed};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[special(from_type(String))]
+	#[parse(timezone)]
+	pub a: Tz,
+	#[special(from_type(String))]
+	#[parse(timezone("custom message", "custom_code"))]
+	pub b: Option<Tz>,
+}
+
+#[test]
+fn should_parse_timezones() {
+	let cases = [
+		("America/Sao_Paulo", Tz::America__Sao_Paulo),
+		("Europe/London", Tz::Europe__London),
+		("UTC", Tz::UTC),
+	];
+
+	let mut wrapper = TestWrapper::default();
+	let mut result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"a" => ("required", "is required"),
+	});
+
+	for (case, expected) in cases.iter() {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+		assert_parsed!(result, wrapper, Test { a: *expected, b: None });
+	}
+
+	for case in ["america/sao_paulo", "Mars/Olympus_Mons", ""] {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+		assert_errors!(result, wrapper, {
+			"a" => ("timezone", "invalid timezone"),
+		});
+	}
+
+	wrapper.a = Some("UTC".to_string());
+	wrapper.b = Some("Europe/Atlantis".to_string());
+	result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"b" => ("custom_code", "custom message"),
+	});
+
+	wrapper.b = Some("Asia/Tokyo".to_string());
+	let parsed = Test::validate_and_parse(wrapper.clone()).expect("should be valid");
+	assert_eq!(parsed.b, Some(Tz::Asia__Tokyo));
+
+	let reversed = TestWrapper::from(parsed);
+	assert_eq!(reversed.a.as_deref(), Some("UTC"));
+	assert_eq!(reversed.b.as_deref(), Some("Asia/Tokyo"));
+}
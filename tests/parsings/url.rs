@@ -0,0 +1,63 @@
+use url::Url;
+
+use validy::core::{Validate, ValidateAndParse};
+
+use validy::{assert_errors, assert_parsed};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[special(from_type(String))]
+	#[parse(url)]
+	pub a: Url,
+	#[special(from_type(String))]
+	#[parse(url(schemes = ["https"], deny_private_ips = true))]
+	pub b: Option<Url>,
+}
+
+#[test]
+fn should_parse_urls() {
+	let cases = [
+		"https://example.com/",
+		"http://example.com:8080/path?query=1#hash",
+		"mailto:user@example.com",
+	];
+
+	let mut wrapper = TestWrapper::default();
+	let mut result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"a" => ("required", "is required"),
+	});
+
+	for case in cases.iter() {
+		wrapper.a = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+
+		let expected = Url::parse(case).expect("should be a valid url");
+		assert_parsed!(result, wrapper, Test { a: expected, b: None });
+	}
+
+	wrapper.a = Some("example.com".to_string());
+	result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"a" => ("url", "invalid url format"),
+	});
+
+	wrapper.a = Some("https://example.com".to_string());
+	for case in ["http://example.com", "https://127.0.0.1"] {
+		wrapper.b = Some(case.to_string());
+		result = Test::validate_and_parse(wrapper.clone());
+
+		assert_errors!(result, wrapper, {
+			"b" => ("url", "invalid url format"),
+		});
+	}
+
+	wrapper.b = Some("https://example.com/b".to_string());
+	let parsed = Test::validate_and_parse(wrapper.clone()).expect("should be valid");
+	assert_eq!(parsed.b.as_ref().map(Url::as_str), Some("https://example.com/b"));
+
+	let reversed = TestWrapper::from(parsed);
+	assert_eq!(reversed.a.as_deref(), Some("https://example.com/"));
+}
@@ -0,0 +1,75 @@
+use serde::Serialize;
+use validy::{Validated, core::Validate};
+
+#[derive(Debug, Clone, Default, Validate, PartialEq, Serialize)]
+#[validate(payload)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[modificate(trim)]
+	#[validate(length(3..=10))]
+	pub name: String,
+	#[special(from_type(String))]
+	#[parse(custom(parse))]
+	pub port: u16,
+}
+
+fn parse(value: String, _field: &str) -> validy::core::ParseResult<u16> {
+	(value.parse::<u16>().unwrap_or(0), None)
+}
+
+#[derive(Debug, Default, Validate, PartialEq, Serialize)]
+#[validate(payload)]
+#[wrapper_derive(Debug, Clone)]
+struct Outer {
+	#[special(from_type(TestWrapper))]
+	#[special(nested(Test, TestWrapper))]
+	pub inner: Test,
+}
+
+#[test]
+fn should_validate_during_deserialization() {
+	let result = serde_json::from_str::<Validated<Test>>(r#"{ "name": "  john  ", "port": "8080" }"#);
+	let validated = result.expect("should be valid");
+
+	assert_eq!(validated.name, "john");
+	assert_eq!(validated.as_ref().port, 8080);
+	assert_eq!(
+		validated.into_inner(),
+		Test {
+			name: "john".to_string(),
+			port: 8080,
+		}
+	);
+
+	let result = toml::from_str::<Validated<Test>>("name = \"john\"\nport = \"80\"");
+	assert_eq!(result.expect("should be valid").port, 80);
+}
+
+#[test]
+fn should_surface_validation_errors_as_serde_errors() {
+	let result = serde_json::from_str::<Validated<Test>>(r#"{ "name": "jo" }"#);
+	let error = result.expect_err("should be invalid").to_string();
+	assert_eq!(
+		error,
+		"validation failed: name: length (length out of range); port: required (is required)"
+	);
+
+	let result = serde_json::from_str::<Validated<Outer>>(r#"{ "inner": { "name": "jo", "port": "1" } }"#);
+	let error = result.expect_err("should be invalid").to_string();
+	assert_eq!(
+		error,
+		"validation failed: inner: nested { name: length (length out of range) }"
+	);
+
+	let result = toml::from_str::<Validated<Test>>("name = 1");
+	assert!(result.is_err());
+}
+
+#[test]
+fn should_serialize_transparently() {
+	let validated: Validated<Test> =
+		serde_json::from_str(r#"{ "name": "john", "port": "8080" }"#).expect("should be valid");
+
+	let json = serde_json::to_string(&validated).expect("should serialize");
+	assert_eq!(json, r#"{"name":"john","port":8080}"#);
+}
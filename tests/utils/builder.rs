@@ -0,0 +1,152 @@
+use std::borrow::Cow;
+
+use validy::core::{ParseResult, Validate, ValidationError};
+use validy::{assert_errors, validation_error};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, builder)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[modificate(trim)]
+	#[validate(length(3..=10))]
+	pub name: String,
+	#[special(from_type(String))]
+	#[parse(custom(parse))]
+	pub port: u16,
+	pub tag: Option<String>,
+}
+
+fn parse(value: String, field: &str) -> ParseResult<u16> {
+	match value.parse::<u16>() {
+		Ok(value) => (value, None),
+		Err(_) => (0, Some(validation_error!(field.to_string(), "parse", "invalid port"))),
+	}
+}
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, builder, context = bool)]
+#[wrapper_derive(Debug, Clone)]
+struct ContextTest {
+	#[validate(custom_with_context(validate, []))]
+	pub flag: bool,
+}
+
+fn validate(value: &bool, field: &str, context: &bool) -> Result<(), ValidationError> {
+	if *value != *context {
+		return Err(validation_error!(field.to_string(), "custom_code", "custom message"));
+	}
+
+	Ok(())
+}
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, builder, asynchronous)]
+#[wrapper_derive(Debug, Clone)]
+struct AsyncTest {
+	#[validate(length(3..=10))]
+	pub name: String,
+}
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, builder)]
+#[wrapper_derive(Debug, Clone)]
+struct RawTest {
+	#[validate(length(1..=10))]
+	pub r#type: String,
+}
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, builder)]
+#[wrapper_derive(Debug, Clone)]
+struct BorrowedTest<'a> {
+	#[validate(length(3..=10))]
+	pub name: &'a str,
+	#[modificate(trim)]
+	pub label: Option<Cow<'a, str>>,
+}
+
+#[test]
+fn should_build_payloads() {
+	let result = Test::builder().name(" john ").port("8080").build();
+	assert_eq!(
+		result,
+		Ok(Test {
+			name: "john".to_string(),
+			port: 8080,
+			tag: None,
+		})
+	);
+
+	let result = Test::builder().name("john").port("80").tag("admin").build();
+	assert_eq!(result.map(|test| test.tag), Ok(Some("admin".to_string())));
+
+	let result = Test::builder().name("jo").port("abc").build();
+	assert_errors!(result, "builder", {
+		"name" => ("length", "length out of range"),
+		"port" => ("parse", "invalid port"),
+	});
+
+	let result = Test::builder().build();
+	assert_errors!(result, "builder", {
+		"name" => ("required", "is required"),
+		"port" => ("required", "is required"),
+	});
+
+	let result = ContextTest::builder().flag(true).build(&true);
+	assert_eq!(result, Ok(ContextTest { flag: true }));
+
+	let result = ContextTest::builder().flag(true).build(&false);
+	assert_errors!(result, "builder", {
+		"flag" => ("custom_code", "custom message"),
+	});
+}
+
+#[tokio::test]
+async fn should_build_async_payloads() {
+	let result = AsyncTest::builder().name("john").build_async().await;
+	assert_eq!(
+		result,
+		Ok(AsyncTest {
+			name: "john".to_string(),
+		})
+	);
+
+	let result = AsyncTest::builder().name("jo").build_async().await;
+	assert_errors!(result, "builder", {
+		"name" => ("length", "length out of range"),
+	});
+}
+
+#[test]
+fn should_build_payloads_with_raw_identifiers() {
+	let result = RawTest::builder().r#type("admin").build();
+	assert_eq!(
+		result,
+		Ok(RawTest {
+			r#type: "admin".to_string()
+		})
+	);
+
+	let result = RawTest::builder().r#type("").build();
+	assert_errors!(result, "", {
+		"type" => ("length", "length out of range"),
+	});
+}
+
+#[test]
+fn should_build_borrowed_payloads() {
+	let name = String::from("john");
+	let result = BorrowedTest::builder().name(name.as_str()).label(" admin ").build();
+	assert_eq!(
+		result,
+		Ok(BorrowedTest {
+			name: "john",
+			label: Some(Cow::Borrowed("admin")),
+		})
+	);
+
+	let result = BorrowedTest::builder().name("jo").build();
+	assert_errors!(result, "builder", {
+		"name" => ("length", "length out of range"),
+	});
+}
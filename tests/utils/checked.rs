@@ -0,0 +1,119 @@
+use serde::Serialize;
+use validy::core::{
+	AsyncValidateAndParse, Checked, Validate, ValidateAndModificate, ValidateAndParse, ValidateWithContext,
+	ValidationError,
+};
+use validy::{assert_errors, validation_error};
+
+#[derive(Debug, Clone, Default, Validate, PartialEq, Serialize)]
+#[validate(modificate)]
+struct Test {
+	#[modificate(trim)]
+	#[validate(length(3..=10))]
+	pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+struct ValidateTest {
+	#[validate(length(3..=10))]
+	pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(payload)]
+#[wrapper_derive(Debug, Clone, Default)]
+struct ParseTest {
+	#[validate(length(3..=10))]
+	pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(payload, asynchronous)]
+#[wrapper_derive(Debug, Clone, Default)]
+struct AsyncParseTest {
+	#[validate(length(3..=10))]
+	pub name: String,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(context = bool)]
+struct ContextTest {
+	#[validate(custom_with_context(validate, []))]
+	pub flag: bool,
+}
+
+fn validate(value: &bool, field: &str, context: &bool) -> Result<(), ValidationError> {
+	if *value != *context {
+		return Err(validation_error!(field.to_string(), "custom_code", "custom message"));
+	}
+
+	Ok(())
+}
+
+fn create(checked: Checked<Test>) -> String {
+	checked.name.clone()
+}
+
+#[test]
+fn should_check_validations() {
+	let test = ValidateTest {
+		name: "john".to_string(),
+	};
+
+	let result = test.clone().validate_checked();
+	assert_eq!(result.map(Checked::into_inner), Ok(test));
+
+	let test = ValidateTest::default();
+	assert_errors!(test.clone().validate_checked(), test, {
+		"name" => ("length", "length out of range"),
+	});
+
+	let test = Test {
+		name: "  ab  ".to_string(),
+	};
+
+	let result = test.clone().validate_and_modificate_checked();
+	assert_errors!(result, test, {
+		"name" => ("length", "length out of range"),
+	});
+
+	let test = Test {
+		name: "  john  ".to_string(),
+	};
+
+	let checked = test.validate_and_modificate_checked().expect("should be valid");
+	assert_eq!(checked.as_ref().name, "john");
+	assert_eq!(serde_json::to_string(&checked).unwrap(), r#"{"name":"john"}"#);
+	assert_eq!(create(checked), "john");
+
+	let test = ContextTest { flag: true };
+	assert!(test.clone().validate_with_context_checked(&true).is_ok());
+	assert_errors!(test.clone().validate_with_context_checked(&false), test, {
+		"flag" => ("custom_code", "custom message"),
+	});
+}
+
+#[tokio::test]
+async fn should_check_parses() {
+	let wrapper = ParseTestWrapper {
+		name: Some("john".to_string()),
+	};
+
+	let checked = ParseTest::validate_and_parse_checked(wrapper).expect("should be valid");
+	assert_eq!(checked.name, "john");
+
+	let wrapper = ParseTestWrapper::default();
+	let result = ParseTest::validate_and_parse_checked(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"name" => ("required", "is required"),
+	});
+
+	let wrapper = AsyncParseTestWrapper {
+		name: Some("jo".to_string()),
+	};
+
+	let result = AsyncParseTest::async_validate_and_parse_checked(wrapper.clone()).await;
+	assert_errors!(result, wrapper, {
+		"name" => ("length", "length out of range"),
+	});
+}
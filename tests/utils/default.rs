@@ -0,0 +1,71 @@
+use validy::core::{Validate, ValidateAndParse};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload)]
+struct Test {
+	#[special(default = 20)]
+	#[validate(range(1..=100))]
+	pub limit: u32,
+	#[special(default)]
+	pub offset: u32,
+	#[special(default = "name".to_string())]
+	#[modificate(uppercase)]
+	pub order: String,
+	#[special(from_type(String))]
+	#[special(default = "asc".to_string())]
+	#[parse(inline(|x: String| x == "asc"))]
+	pub ascending: bool,
+	#[special(default = "all".to_string())]
+	pub filter: Option<String>,
+}
+
+#[test]
+fn should_use_defaults_for_missing_fields() {
+	let test = Test::validate_and_parse(TestWrapper::default()).expect("should be valid");
+
+	assert_eq!(
+		test,
+		Test {
+			limit: 20,
+			offset: 0,
+			order: "NAME".to_string(),
+			ascending: true,
+			filter: Some("all".to_string()),
+		}
+	);
+}
+
+#[test]
+fn should_prefer_present_values() {
+	let wrapper = TestWrapper {
+		limit: Some(50),
+		offset: Some(10),
+		order: Some("date".to_string()),
+		ascending: Some("desc".to_string()),
+		filter: Some("active".to_string()),
+	};
+
+	let test = Test::validate_and_parse(wrapper).expect("should be valid");
+
+	assert_eq!(
+		test,
+		Test {
+			limit: 50,
+			offset: 10,
+			order: "DATE".to_string(),
+			ascending: false,
+			filter: Some("active".to_string()),
+		}
+	);
+}
+
+#[test]
+fn should_validate_present_values() {
+	let wrapper = TestWrapper {
+		limit: Some(500),
+		..Default::default()
+	};
+
+	let errors = Test::validate_and_parse(wrapper).expect_err("should be invalid");
+	assert!(errors.contains_key("limit"));
+}
@@ -1,2 +1,9 @@
+pub mod builder;
+pub mod checked;
+pub mod default;
 pub mod field_data;
 pub mod multipart_body;
+pub mod newtype;
+pub mod partial;
+pub mod reversible;
+pub mod unknown_fields;
@@ -0,0 +1,171 @@
+use std::{fmt, str::FromStr};
+
+use validy::assert_errors;
+use validy::core::{ValidNewtype, Validate, ValidateAndParse};
+
+#[derive(Debug, Clone, PartialEq, ValidNewtype)]
+#[newtype(from_str, display)]
+#[modificate(trim, lowercase)]
+#[validate(email, length(..=254))]
+struct Email(String);
+
+#[derive(Debug, Clone, Copy, PartialEq, ValidNewtype)]
+#[newtype(from_str)]
+#[validate(range(1..=65535))]
+struct Port(u32);
+
+#[derive(Debug, Clone, PartialEq, ValidNewtype)]
+#[modificate(trim)]
+#[validate(length(1..=32))]
+struct Slug(String);
+
+impl fmt::Display for Slug {
+	fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(formatter, "/{}", self.as_ref())
+	}
+}
+
+impl FromStr for Slug {
+	type Err = String;
+
+	fn from_str(value: &str) -> Result<Self, Self::Err> {
+		Slug::try_from(value.trim_start_matches('/').to_string()).map_err(|_| "invalid slug".to_string())
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, ValidNewtype)]
+#[validate(length(1..=3))]
+struct Tags<T>(Vec<T>);
+
+#[derive(Debug, Clone, Validate, PartialEq)]
+#[validate(payload, partial, reversible)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[special(newtype)]
+	#[modificate(trim)]
+	pub email: Email,
+	#[special(newtype)]
+	pub port: Option<Port>,
+	#[special(newtype)]
+	pub tags: Tags<String>,
+	#[validate(length(1..=10))]
+	pub name: String,
+}
+
+#[test]
+fn should_validate_newtypes() {
+	let email = Email::try_from("  Test@Gmail.com ".to_string()).expect("should be valid");
+	assert_eq!(email.as_ref(), "test@gmail.com");
+	assert_eq!(email.len(), 14);
+	assert_eq!(email.to_string(), "test@gmail.com");
+	assert_eq!(email.clone().into_inner(), "test@gmail.com".to_string());
+
+	let result = Email::try_from("test".to_string());
+	assert_errors!(result, "test", {
+		"value" => ("email", "invalid email format"),
+	});
+
+	let port = Port::from_str("8080").expect("should be valid");
+	assert_eq!(*port, 8080);
+
+	let result = Port::from_str("0");
+	assert_errors!(result, "0", {
+		"value" => ("range", "out of range"),
+	});
+
+	let result = Port::from_str("abc");
+	assert_errors!(result, "abc", {
+		"value" => ("parse", "invalid format"),
+	});
+
+	let slug: Slug = "/posts".parse().expect("should be valid");
+	assert_eq!(slug.to_string(), "/posts");
+	assert_eq!(Slug::from_str("/"), Err("invalid slug".to_string()));
+}
+
+#[test]
+fn should_validate_newtypes_with_serde() {
+	let email: Email = serde_json::from_str(r#"" TEST@gmail.com""#).expect("should be valid");
+	assert_eq!(serde_json::to_string(&email).unwrap(), r#""test@gmail.com""#);
+
+	let result = serde_json::from_str::<Email>(r#""test""#);
+	assert_eq!(
+		result.expect_err("should be invalid").to_string(),
+		"value: email (invalid email format)"
+	);
+
+	let result = serde_json::from_str::<Email>(r#"" TEST@gmail.com""#);
+	assert!(result.is_ok());
+}
+
+#[test]
+fn should_validate_generic_newtypes() {
+	let tags = Tags::try_from(vec![1, 2]).expect("should be valid");
+	assert_eq!(tags.len(), 2);
+	assert_eq!(tags.into_inner(), vec![1, 2]);
+
+	let result = Tags::<u8>::try_new(vec![]);
+	assert_errors!(result, "[]", {
+		"value" => ("length", "length out of range"),
+	});
+
+	let tags: Tags<String> = serde_json::from_str(r#"["a", "b"]"#).expect("should be valid");
+	assert_eq!(serde_json::to_string(&tags).unwrap(), r#"["a","b"]"#);
+}
+
+#[test]
+fn should_validate_newtype_payload_fields() {
+	let wrapper: TestWrapper =
+		serde_json::from_str(r#"{ "email": " TEST@gmail.com ", "port": 8080, "tags": ["a"], "name": "test" }"#)
+			.expect("should deserialize");
+	let result = Test::validate_and_parse(wrapper.clone());
+	let test = result.expect("should be valid");
+	assert_eq!(test.email.as_ref(), "test@gmail.com");
+	assert_eq!(test.port.map(Port::into_inner), Some(8080));
+	assert_eq!(test.tags.as_ref(), &vec!["a".to_string()]);
+
+	let reversed = TestWrapper::from(test);
+	assert_eq!(reversed.email.as_deref(), Some("test@gmail.com"));
+	assert_eq!(reversed.port, Some(8080));
+
+	let wrapper: TestWrapper =
+		serde_json::from_str(r#"{ "email": "test", "port": 0, "tags": [], "name": "" }"#).expect("should deserialize");
+	let result = Test::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"email" => ("email", "invalid email format"),
+		"port" => ("range", "out of range"),
+		"tags" => ("length", "length out of range"),
+		"name" => ("length", "length out of range"),
+	});
+}
+
+#[test]
+fn should_validate_newtype_patch_fields() {
+	let mut test = Test {
+		email: Email::try_new("test@gmail.com".to_string()).unwrap(),
+		port: None,
+		tags: Tags::try_new(vec!["a".to_string()]).unwrap(),
+		name: "test".to_string(),
+	};
+
+	let patch = TestPatch {
+		email: Some(Some("other@gmail.com".to_string())),
+		port: Some(Some(8080)),
+		..Default::default()
+	};
+
+	assert!(patch.apply_to(&mut test).is_ok());
+	assert_eq!(test.email.as_ref(), "other@gmail.com");
+	assert_eq!(test.port.map(Port::into_inner), Some(8080));
+
+	let patch = TestPatch {
+		email: Some(Some("other".to_string())),
+		port: Some(None),
+		..Default::default()
+	};
+
+	let result = patch.clone().apply_to(&mut test);
+	assert_errors!(result, patch, {
+		"email" => ("email", "invalid email format"),
+	});
+}
@@ -0,0 +1,106 @@
+use serde_json::json;
+use validy::assert_errors;
+use validy::core::Validate;
+
+#[derive(Debug, Clone, Validate, PartialEq)]
+#[validate(payload, partial)]
+#[wrapper_derive(Debug, Clone)]
+struct Test {
+	#[modificate(trim)]
+	#[validate(length(3..=10, "name must have between 3 and 10 characters", "bad_name"))]
+	pub name: String,
+	#[special(from_type(String))]
+	#[parse(inline(|x: String| x.parse::<u8>().unwrap_or(0)))]
+	#[validate(inline(|x: &u8| *x >= 18, [], "must be an adult", "underage"))]
+	pub age: u8,
+	pub nickname: Option<String>,
+}
+
+fn target() -> Test {
+	Test {
+		name: "john".to_string(),
+		age: 30,
+		nickname: Some("jo".to_string()),
+	}
+}
+
+#[test]
+fn should_keep_absent_fields() {
+	let mut test = target();
+	let patch = TestPatch::default();
+
+	assert!(patch.apply_to(&mut test).is_ok());
+	assert_eq!(test, target());
+}
+
+#[test]
+fn should_apply_present_fields() {
+	let mut test = target();
+	let patch = TestPatch {
+		name: Some(Some("  mary  ".to_string())),
+		age: Some(Some("42".to_string())),
+		nickname: None,
+	};
+
+	assert!(patch.apply_to(&mut test).is_ok());
+	assert_eq!(
+		test,
+		Test {
+			name: "mary".to_string(),
+			age: 42,
+			nickname: Some("jo".to_string()),
+		}
+	);
+}
+
+#[test]
+fn should_clear_optional_fields_on_null() {
+	let mut test = target();
+	let patch = TestPatch {
+		nickname: Some(None),
+		..Default::default()
+	};
+
+	assert!(patch.apply_to(&mut test).is_ok());
+	assert_eq!(test.nickname, None);
+}
+
+#[test]
+fn should_reject_null_on_required_fields() {
+	let mut test = target();
+	let patch = TestPatch {
+		name: Some(None),
+		..Default::default()
+	};
+
+	let result = patch.clone().apply_to(&mut test);
+	assert_errors!(result, patch, {
+		"name" => ("required", "is required"),
+	});
+	assert_eq!(test, target());
+}
+
+#[test]
+fn should_not_touch_target_on_invalid_patch() {
+	let mut test = target();
+	let patch = TestPatch {
+		name: Some(Some("mary".to_string())),
+		age: Some(Some("12".to_string())),
+		nickname: Some(None),
+	};
+
+	let result = patch.clone().apply_to(&mut test);
+	assert_errors!(result, patch, {
+		"age" => ("underage", "must be an adult"),
+	});
+	assert_eq!(test, target());
+}
+
+#[test]
+fn should_deserialize_absent_null_and_values() {
+	let patch: TestPatch = serde_json::from_value(json!({ "name": "mary", "nickname": null })).unwrap();
+
+	assert_eq!(patch.name, Some(Some("mary".to_string())));
+	assert_eq!(patch.age, None);
+	assert_eq!(patch.nickname, Some(None));
+}
@@ -0,0 +1,86 @@
+use std::net::IpAddr;
+
+use uuid::Uuid;
+use validy::core::{ParseResult, Validate, ValidateAndParse};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone, PartialEq)]
+struct Test {
+	#[modificate(trim)]
+	#[validate(length(3..=10))]
+	pub name: String,
+	#[special(from_type(String))]
+	#[parse(custom(parse, unparse = to_text))]
+	pub port: u16,
+	#[special(from_type(String))]
+	#[parse(uuid)]
+	pub id: Uuid,
+	#[special(from_type(String))]
+	#[parse(ip)]
+	pub ip: Option<IpAddr>,
+	#[special(from_type(String))]
+	#[parse(inline(|x: String| x.len(), unparse = |x: usize| "a".repeat(x)))]
+	pub size: usize,
+	pub tag: Option<String>,
+	#[special(from_type(NestedTestWrapper))]
+	#[special(nested(NestedTest, NestedTestWrapper))]
+	pub nested: NestedTest,
+}
+
+#[derive(Debug, Default, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone, PartialEq)]
+struct NestedTest {
+	#[special(from_type(Vec<String>))]
+	#[special(for_each(
+		config(from_item = String, from_collection = Vec<String>, to_collection = Vec<u32>),
+		parse(inline(|x: String| x.parse::<u32>().unwrap_or(0), unparse = |x: u32| x.to_string())),
+		validate(inline(|x: &u32| *x > 1)),
+	))]
+	pub permissions: Vec<u32>,
+}
+
+fn parse(value: String, _field: &str) -> ParseResult<u16> {
+	(value.parse::<u16>().unwrap_or(0), None)
+}
+
+fn to_text(value: u16) -> String {
+	value.to_string()
+}
+
+#[test]
+fn should_reverse_parsed_values() {
+	let wrapper = TestWrapper {
+		name: Some("  john  ".to_string()),
+		port: Some("8080".to_string()),
+		id: Some("F47AC10B-58CC-4372-A567-0E02B2C3D479".to_string()),
+		ip: Some("127.0.0.1".to_string()),
+		size: Some("abc".to_string()),
+		tag: None,
+		nested: Some(NestedTestWrapper {
+			permissions: Some(vec!["2".to_string(), "3".to_string()]),
+		}),
+	};
+
+	let test = Test::validate_and_parse(wrapper).expect("should be valid");
+	let reversed = TestWrapper::from(test);
+
+	assert_eq!(
+		reversed,
+		TestWrapper {
+			name: Some("john".to_string()),
+			port: Some("8080".to_string()),
+			id: Some("f47ac10b-58cc-4372-a567-0e02b2c3d479".to_string()),
+			ip: Some("127.0.0.1".to_string()),
+			size: Some("aaa".to_string()),
+			tag: None,
+			nested: Some(NestedTestWrapper {
+				permissions: Some(vec!["2".to_string(), "3".to_string()]),
+			}),
+		}
+	);
+
+	let test = Test::validate_and_parse(reversed.clone()).expect("should be valid");
+	assert_eq!(TestWrapper::from(test), reversed);
+}
@@ -0,0 +1,72 @@
+use serde_json::json;
+use validy::core::{Validate, ValidateAndParse};
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, deny_unknown_fields, partial)]
+#[wrapper_derive(Debug)]
+struct Test {
+	#[validate(email)]
+	pub email: String,
+	pub name: Option<String>,
+	#[special(from_type(NestedTestWrapper))]
+	#[special(nested(NestedTest, NestedTestWrapper))]
+	pub nested: NestedTest,
+}
+
+#[derive(Debug, Default, Validate, PartialEq)]
+#[validate(payload, deny_unknown_fields)]
+#[wrapper_derive(Debug)]
+struct NestedTest {
+	pub code: Option<u32>,
+}
+
+#[test]
+fn should_accept_known_fields() {
+	let wrapper: TestWrapper = serde_json::from_value(json!({
+		"email": "test@gmail.com",
+		"nested": { "code": 1 },
+	}))
+	.unwrap();
+
+	let test = Test::validate_and_parse(wrapper).expect("should be valid");
+	assert_eq!(test.nested, NestedTest { code: Some(1) });
+}
+
+#[test]
+fn should_report_unknown_fields() {
+	let wrapper: TestWrapper = serde_json::from_value(json!({
+		"emial": "test@gmail.com",
+		"nmae": "test",
+		"nested": { "cdoe": 1 },
+	}))
+	.unwrap();
+
+	let errors = Test::validate_and_parse(wrapper).expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+
+	assert_eq!(
+		errors["emial"],
+		json!([{ "code": "unknown_field", "message": "is unknown" }])
+	);
+	assert_eq!(
+		errors["nmae"],
+		json!([{ "code": "unknown_field", "message": "is unknown" }])
+	);
+	assert_eq!(errors["email"][0]["code"], json!("required"));
+	assert_eq!(errors["nested"][0]["errors"]["cdoe"][0]["code"], json!("unknown_field"));
+}
+
+#[test]
+fn should_report_unknown_fields_on_patches() {
+	let mut test = Test {
+		email: "test@gmail.com".to_string(),
+		name: None,
+		nested: NestedTest::default(),
+	};
+
+	let patch: TestPatch = serde_json::from_value(json!({ "nmae": "test" })).unwrap();
+	let errors = patch.apply_to(&mut test).expect_err("should be invalid");
+
+	assert!(errors.contains_key("nmae"));
+	assert_eq!(test.name, None);
+}
@@ -0,0 +1,46 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(alpha)]
+	pub a: String,
+	#[validate(alpha("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_alpha_strings() {
+	let cases = [
+		("abc", true),
+		("José", true),
+		("日本語", true),
+		("", true),
+		("abc1", false),
+		("a b", false),
+		("abc!", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("alpha", "must contain only letters"),
+			});
+		}
+	}
+
+	test.a = "abc".to_string();
+	test.b = Some("abc1".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
@@ -0,0 +1,45 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(alphanumeric)]
+	pub a: String,
+	#[validate(alphanumeric("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_alphanumeric_strings() {
+	let cases = [
+		("abc123", true),
+		("Ünïcödé9", true),
+		("", true),
+		("abc 123", false),
+		("abc_123", false),
+		("abc-", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("alphanumeric", "must contain only letters and digits"),
+			});
+		}
+	}
+
+	test.a = "abc123".to_string();
+	test.b = Some("abc_123".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
@@ -0,0 +1,45 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(ascii)]
+	pub a: String,
+	#[validate(ascii("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_ascii_strings() {
+	let cases = [
+		("hello_world-1", true),
+		("tab\tand ~symbols!", true),
+		("", true),
+		("héllo", false),
+		("日本", false),
+		("emoji 🙂", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("ascii", "must contain only ascii characters"),
+			});
+		}
+	}
+
+	test.a = "plain".to_string();
+	test.b = Some("naïve".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
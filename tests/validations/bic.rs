@@ -0,0 +1,49 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(bic)]
+	pub a: String,
+	#[validate(bic("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_bics() {
+	let cases = [
+		("DEUTDEFF", true),
+		("DEUTDEFF500", true),
+		("NEDSZAJJXXX", true),
+		("BOFAUS3N", true),
+		("deutdeff", false),
+		("DEUT1EFF", false),
+		("DEUTDEF", false),
+		("DEUTDEFF50", false),
+		("DEUTDEFF 500", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("bic", "invalid bic"),
+			});
+		}
+	}
+
+	test.a = "DEUTDEFF".to_string();
+	test.b = Some("DEUTDE".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
@@ -0,0 +1,51 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(charset(allowed = "a-z0-9_-"))]
+	pub a: String,
+	#[validate(charset("+\\-0-9", "custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_charsets() {
+	let cases = [
+		("user_name-1", None),
+		("-_-", None),
+		("", None),
+		("User", Some("U")),
+		("user name", Some(" ")),
+		("usér", Some("é")),
+		("user.name", Some(".")),
+	];
+
+	let mut test = Test::default();
+	for (case, char) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		match char {
+			None => assert_validation!(result, test),
+			Some(char) => {
+				assert_errors!(result, test, {
+					"a" => ("charset", ("has characters outside the allowed set", [("char", *char)])),
+				});
+			}
+		}
+	}
+
+	test.a = "user".to_string();
+	test.b = Some("+55-11-9876".to_string());
+	let result = test.validate();
+	assert_validation!(result, test);
+
+	test.b = Some("+55 11".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", ("custom message", [("char", " ")])),
+	});
+}
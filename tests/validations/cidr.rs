@@ -0,0 +1,78 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(cidr)]
+	pub a: String,
+	#[validate(cidr(min_prefix = 16, max_prefix = 24, strict = true))]
+	pub b: Option<String>,
+	#[validate(cidr("custom message", "custom_code"))]
+	pub c: Option<String>,
+}
+
+#[test]
+fn should_validate_cidrs() {
+	let cases = [
+		("10.0.0.0/8", true),
+		("192.168.1.0/24", true),
+		("192.168.1.1/32", true),
+		("0.0.0.0/0", true),
+		("2001:db8::/32", true),
+		("::1/128", true),
+		("10.0.0.1/8", true),
+		("10.0.0.0", false),
+		("10.0.0.0/33", false),
+		("2001:db8::/129", false),
+		("10.0.0.0/-1", false),
+		("example.com/24", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("cidr", "invalid cidr format"),
+			});
+		}
+	}
+
+	test.a = "10.0.0.0/8".to_string();
+	let cases = [
+		("10.0.0.0/16", true),
+		("10.1.2.0/24", true),
+		("10.0.0.0/8", false),
+		("10.0.0.0/25", false),
+		("10.1.2.3/24", false),
+		("2001:d00::/24", true),
+		("2001:db8::/32", false),
+	];
+
+	for (case, is_valid) in cases.iter() {
+		test.b = Some(case.to_string());
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"b" => ("cidr", "invalid cidr format"),
+			});
+		}
+	}
+
+	test.b = None;
+	test.c = Some("10.0.0.0".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"c" => ("custom_code", "custom message"),
+	});
+}
@@ -0,0 +1,52 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(confusable_safe)]
+	pub a: String,
+	#[validate(confusable_safe("custom message", "custom_code", level = "ascii"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_confusable_safe_strings() {
+	let cases = [
+		("john_doe", true),
+		("josé", true),
+		("Привет", true),
+		("日本語abc", true),
+		("", true),
+		("раураl", false),
+		("раура", false),
+		("john doe", false),
+		("admin\u{200B}", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("confusable_safe", "has confusable characters"),
+			});
+		}
+	}
+
+	test.a = "john_doe".to_string();
+	test.b = Some("john_doe".to_string());
+	let result = test.validate();
+	assert_validation!(result, test);
+
+	test.b = Some("josé".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
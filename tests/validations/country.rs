@@ -0,0 +1,56 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(country)]
+	pub a: String,
+	#[validate(country("custom message", "custom_code", format = "alpha3"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_countries() {
+	let cases = [
+		("BR", true),
+		("US", true),
+		("DE", true),
+		("BRA", true),
+		("USA", true),
+		("DEU", true),
+		("br", false),
+		("bra", false),
+		("XX", false),
+		("XXX", false),
+		("BRAZ", false),
+		("B", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("country", "invalid country code"),
+			});
+		}
+	}
+
+	test.a = "BR".to_string();
+	test.b = Some("BRA".to_string());
+	let result = test.validate();
+	assert_validation!(result, test);
+
+	test.b = Some("BR".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
@@ -0,0 +1,75 @@
+use serde::Deserialize;
+use validy::core::{Validate, ValidationError};
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(credit_card)]
+	pub a: String,
+	#[validate(credit_card("custom message", "custom_code", brands = ["visa", "mastercard"]))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_credit_cards() {
+	let cases = [
+		("4111111111111111", true),
+		("5555555555554444", true),
+		("378282246310005", true),
+		("6011111111111117", true),
+		("4111111111111112", false),
+		("411111111111111", false),
+		("4111 1111 1111 1111", false),
+		("9111111111111111", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else if case.starts_with('4') && !case.contains(' ') {
+			assert_errors!(result, test, {
+				"a" => ("credit_card", ("invalid credit card number", [("brand", "visa")])),
+			});
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("credit_card", "invalid credit card number"),
+			});
+		}
+	}
+
+	test.a = "4111111111111111".to_string();
+	test.b = Some("5555555555554444".to_string());
+	let result = test.validate();
+	assert_validation!(result, test);
+
+	test.b = Some("378282246310005".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", ("custom message", [("brand", "amex")])),
+	});
+}
+
+#[test]
+fn should_serialize_detected_brands() {
+	let test = Test {
+		a: "378282246310004".to_string(),
+		b: None,
+	};
+
+	let errors = test.validate().expect_err("should be invalid");
+	let ValidationError::Leaf(error) = &errors["a"][0] else {
+		panic!("should be a simple error");
+	};
+
+	assert_eq!(error.params.get("brand").map(|brand| brand.as_ref()), Some("amex"));
+	assert_eq!(
+		serde_json::to_value(error).expect("should serialize"),
+		serde_json::json!({ "code": "credit_card", "message": "invalid credit card number", "params": { "brand": "amex" } })
+	);
+}
@@ -0,0 +1,53 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(currency)]
+	pub a: String,
+	#[validate(currency("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_currencies() {
+	let cases = [
+		("BRL", true),
+		("USD", true),
+		("EUR", true),
+		("JPY", true),
+		("XAU", true),
+		("usd", false),
+		("ABC", false),
+		("US", false),
+		("USDT", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("currency", "invalid currency code"),
+			});
+		}
+	}
+
+	test.a = "BRL".to_string();
+	test.b = Some("EUR".to_string());
+	let result = test.validate();
+	assert_validation!(result, test);
+
+	test.b = Some("EURO".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
@@ -0,0 +1,97 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(domain)]
+	pub a: String,
+	#[validate(domain("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct PolicyTest {
+	#[validate(domain(require_tld = false))]
+	pub a: String,
+	#[validate(domain(allow_wildcard = true, suffix_of = "example.com"))]
+	pub b: String,
+}
+
+#[test]
+fn should_validate_domains() {
+	let cases = [
+		("example.com", true),
+		("sub.example.co.uk", true),
+		("Example.COM", true),
+		("example.com.", true),
+		("bücher.de", true),
+		("xn--bcher-kva.de", true),
+		("localhost", false),
+		("1.2.3.4", false),
+		("*.example.com", false),
+		("-example.com", false),
+		("ex_ample.com", false),
+		("example..com", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("domain", "invalid domain"),
+			});
+		}
+	}
+
+	test.a = "example.com".to_string();
+	test.b = Some("localhost".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
+
+#[test]
+fn should_apply_domain_policies() {
+	let cases = [
+		("localhost", "app.example.com", true),
+		("intranet", "*.example.com", true),
+		("example.com", "*.app.example.com", true),
+		("example.com", "a.b.example.com", true),
+		("example.com", "example.com", false),
+		("example.com", "badexample.com", false),
+		("example.com", "example.org", false),
+		("example.com", "*.example.org", false),
+		("example.com", "app.*.example.com", false),
+	];
+
+	let mut test = PolicyTest::default();
+	for (a, b, is_valid) in cases.iter() {
+		test.a = a.to_string();
+		test.b = b.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"b" => ("domain", "invalid domain"),
+			});
+		}
+	}
+
+	test.a = "local_host".to_string();
+	test.b = "app.example.com".to_string();
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"a" => ("domain", "invalid domain"),
+	});
+}
@@ -1,5 +1,6 @@
 use serde::Deserialize;
-use validy::core::Validate;
+use validy::functions::validation::email::{EmailPolicy, validate_email, validate_email_with_policy};
+use validy::{core::Validate, settings::ValidationSettings};
 
 use validy::{assert_errors, assert_validation};
 
@@ -17,6 +18,16 @@ struct Test {
 	pub d: Option<String>,
 }
 
+#[derive(Debug, Validate, PartialEq)]
+struct PolicyTest {
+	#[validate(email(allow_display_name = true, require_tld = false, allow_ip_domain = true))]
+	pub a: String,
+	#[validate(email(allowed_domains = ["example.com", "*.example.org"], blocked_domains = ["internal.example.org"]))]
+	pub b: String,
+	#[validate(email(allow_disposable = true))]
+	pub c: String,
+}
+
 #[test]
 fn should_validate_emails() {
 	let cases = [
@@ -89,3 +100,106 @@ fn should_validate_emails() {
 		}
 	}
 }
+
+#[test]
+fn should_validate_email_policies() {
+	let cases = [
+		("user@example.com", true, true),
+		("User <user@example.com>", false, true),
+		("user@localhost", false, true),
+		("user@[127.0.0.1]", false, true),
+		("user@127.0.0.1", false, true),
+		("user@bücher.de", true, true),
+		("user@xn--bcher-kva.de", true, true),
+		("user@-example.com", false, false),
+	];
+
+	for (case, is_valid_by_default, is_valid) in cases.iter() {
+		let test = Test {
+			a: case.to_string(),
+			..Test::default()
+		};
+
+		assert_eq!(test.validate().is_ok(), *is_valid_by_default, "{case}");
+
+		let test = PolicyTest {
+			a: case.to_string(),
+			b: "user@example.com".to_string(),
+			c: "user@example.com".to_string(),
+		};
+
+		assert_eq!(test.validate().is_ok(), *is_valid, "{case}");
+	}
+
+	let cases = [
+		("user@example.com", true),
+		("user@EXAMPLE.com", true),
+		("user@mail.example.org", true),
+		("user@example.org", false),
+		("user@internal.example.org", false),
+		("user@example.net", false),
+		("user@evil-example.com", false),
+	];
+
+	for (case, is_valid) in cases.iter() {
+		let test = PolicyTest {
+			a: "user@example.com".to_string(),
+			b: case.to_string(),
+			c: "user@example.com".to_string(),
+		};
+
+		let result = test.validate();
+		assert_eq!(result.is_ok(), *is_valid, "{case}");
+		if !*is_valid {
+			assert_errors!(result, test, {
+				"b" => ("email", "invalid email format"),
+			});
+		}
+	}
+}
+
+#[test]
+fn should_block_disposable_domains() {
+	ValidationSettings::set_disposable_domains(["# disposable domains", "throwaway.test", "Temp-Mail.test."]);
+
+	let cases = [
+		("user@throwaway.test", false),
+		("user@inbox.throwaway.test", false),
+		("user@temp-mail.test", false),
+		("user@not-throwaway.test", true),
+	];
+
+	for (case, is_valid) in cases.iter() {
+		let test = Test {
+			a: case.to_string(),
+			..Test::default()
+		};
+
+		assert_eq!(test.validate().is_ok(), *is_valid, "{case}");
+
+		let test = PolicyTest {
+			a: "user@example.com".to_string(),
+			b: "user@example.com".to_string(),
+			c: case.to_string(),
+		};
+
+		assert!(test.validate().is_ok(), "{case}");
+	}
+}
+
+#[test]
+fn should_validate_emails_with_functions() {
+	assert!(validate_email("user@example.com", "a", "email", "invalid email format").is_ok());
+	assert!(validate_email("user", "a", "email", "invalid email format").is_err());
+
+	let policy = EmailPolicy {
+		blocked_domains: &["example.com"],
+		..Default::default()
+	};
+
+	let result = validate_email_with_policy("user@example.com", &policy, "a", "email", "invalid email format");
+	assert!(result.is_err());
+
+	let result = validate_email_with_policy("user@example.org", &policy, "a", "email", "invalid email format");
+	assert!(result.is_ok());
+}
@@ -0,0 +1,132 @@
+use serde_json::json;
+use validy::assert_errors;
+use validy::core::{AsyncValidateAndParse, Validate, ValidateAndModificate, ValidateAndParse};
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(modificate)]
+struct Address {
+	#[modificate(trim)]
+	#[validate(length(1..=20))]
+	pub street: String,
+	#[validate(length(2..=2))]
+	pub country: String,
+}
+
+#[derive(Debug, Validate)]
+#[validate(modificate)]
+struct Customer {
+	#[validate(length(1..=10))]
+	pub name: String,
+	#[special(flatten(Address))]
+	pub address: Address,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone, PartialEq)]
+struct PayloadAddress {
+	#[modificate(trim)]
+	#[validate(length(1..=20))]
+	pub street: String,
+	#[validate(length(2..=2))]
+	pub country: String,
+}
+
+#[derive(Debug, Default, Validate, PartialEq)]
+#[validate(payload, reversible, deny_unknown_fields)]
+#[wrapper_derive(Debug, Clone, PartialEq)]
+struct PayloadCustomer {
+	#[validate(length(1..=10))]
+	pub name: String,
+	#[special(flatten)]
+	pub address: PayloadAddress,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(payload, asynchronous)]
+#[wrapper_derive(Debug, Clone)]
+struct AsyncAddress {
+	#[validate(length(1..=20))]
+	pub street: String,
+}
+
+#[derive(Debug, Default, Validate, PartialEq)]
+#[validate(payload, asynchronous)]
+#[wrapper_derive(Debug, Clone)]
+struct AsyncCustomer {
+	#[special(flatten(AsyncAddress))]
+	pub address: AsyncAddress,
+}
+
+fn address(street: &str, country: &str) -> Address {
+	Address {
+		street: street.to_string(),
+		country: country.to_string(),
+	}
+}
+
+#[test]
+fn should_merge_flattened_errors() {
+	let mut customer = Customer {
+		name: "john".to_string(),
+		address: address(" main street ", "US"),
+	};
+
+	assert!(customer.validate_and_modificate().is_ok());
+	assert_eq!(customer.address.street, "main street");
+
+	let mut customer = Customer {
+		name: "".to_string(),
+		address: address("", "USA"),
+	};
+
+	let result = customer.validate_and_modificate();
+	assert_errors!(result, customer, {
+		"name" => ("length", "length out of range"),
+		"street" => ("length", "length out of range"),
+		"country" => ("length", "length out of range")
+	});
+}
+
+#[test]
+fn should_parse_flattened_payloads() {
+	let wrapper: PayloadCustomerWrapper = serde_json::from_value(json!({
+		"name": "john",
+		"street": " main street ",
+		"country": "US"
+	}))
+	.unwrap();
+
+	let customer = PayloadCustomer::validate_and_parse(wrapper.clone()).expect("should be valid");
+	assert_eq!(customer.address.street, "main street");
+
+	let reversed = PayloadCustomerWrapper::from(customer)
+		.address
+		.expect("should have an address");
+	assert_eq!(reversed.street.as_deref(), Some("main street"));
+	assert_eq!(reversed.country.as_deref(), Some("US"));
+
+	let wrapper: PayloadCustomerWrapper = serde_json::from_value(json!({
+		"name": "john",
+		"country": "USA",
+		"zip": "12345"
+	}))
+	.unwrap();
+
+	let result = PayloadCustomer::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"street" => ("required", "is required"),
+		"country" => ("length", "length out of range"),
+		"zip" => ("unknown_field", "is unknown")
+	});
+}
+
+#[tokio::test]
+async fn should_parse_flattened_payloads_asynchronously() {
+	let wrapper: AsyncCustomerWrapper = serde_json::from_value(json!({ "street": "" })).unwrap();
+
+	let result = AsyncCustomer::async_validate_and_parse(wrapper.clone()).await;
+	assert_errors!(result, wrapper, {
+		"street" => ("length", "length out of range")
+	});
+}
@@ -0,0 +1,262 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::json;
+use validy::core::{Validate, ValidateAndModificate, ValidateAndParse};
+use validy::{assert_errors, validation_errors};
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(payload)]
+struct Metadata {
+	#[validate(length(1..=10))]
+	pub value: String,
+}
+
+#[derive(Debug, Validate)]
+struct Test {
+	#[special(for_each_key(validate(length(2..=2))))]
+	#[special(for_each_value(validate(length(1..=5))))]
+	pub translations: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Validate)]
+#[validate(modificate)]
+struct ModificateTest {
+	#[special(for_each_key(modificate(lowercase), validate(length(2..=2))))]
+	#[special(for_each_value(modificate(trim)))]
+	pub translations: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Validate)]
+#[validate(modificate)]
+struct RenameChainTest {
+	#[special(for_each_key(modificate(
+		inline(|x: &mut String| if x == "a" { *x = "b".to_string() }),
+		lowercase,
+	)))]
+	pub translations: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Validate)]
+#[validate(payload)]
+struct PayloadTest {
+	#[special(from_type(HashMap<String, MetadataWrapper>))]
+	#[special(for_each_key(
+		config(to_collection = HashMap<u32, MetadataWrapper>),
+		parse(inline(|x: String| x.parse::<u32>().unwrap_or(0))),
+		validate(range(1..=9)),
+	))]
+	#[special(for_each_value(
+		config(from_item = MetadataWrapper, to_collection = HashMap<u32, Metadata>),
+		nested(Metadata, MetadataWrapper),
+	))]
+	pub metadata: HashMap<u32, Metadata>,
+}
+
+#[derive(Debug, Validate)]
+#[validate(failure_mode = FailFast)]
+struct FailFastTest {
+	#[special(for_each_value(validate(length(1..=5))))]
+	pub translations: BTreeMap<String, String>,
+}
+
+#[test]
+fn should_report_errors_by_key() {
+	let test = Test {
+		translations: BTreeMap::from([
+			("en".to_string(), "hello".to_string()),
+			("pt-BR".to_string(), "olá".to_string()),
+		]),
+	};
+
+	let errors = test.validate().expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+
+	assert_eq!(errors["translations"][0]["code"], json!("nested"));
+	assert_eq!(errors["translations"][0]["errors"]["pt-BR"][0]["code"], json!("length"));
+	assert!(errors["translations"][0]["errors"].get("en").is_none());
+
+	let test = Test {
+		translations: BTreeMap::from([
+			("en".to_string(), "hello".to_string()),
+			("es".to_string(), "".to_string()),
+		]),
+	};
+
+	let errors = test.validate().expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+
+	assert_eq!(errors["translations"][0]["errors"]["es"][0]["code"], json!("length"));
+	assert!(errors["translations"][0]["errors"].get("en").is_none());
+}
+
+#[test]
+fn should_modificate_keys_and_values() {
+	let mut test = ModificateTest {
+		translations: BTreeMap::from([
+			("EN".to_string(), "  hello  ".to_string()),
+			("pt".to_string(), "olá ".to_string()),
+		]),
+	};
+
+	assert!(test.validate_and_modificate().is_ok());
+	assert_eq!(
+		test.translations,
+		BTreeMap::from([
+			("en".to_string(), "hello".to_string()),
+			("pt".to_string(), "olá".to_string()),
+		])
+	);
+}
+
+#[test]
+fn should_report_the_failed_entry_by_key_when_failing_fast() {
+	let test = FailFastTest {
+		translations: BTreeMap::from([
+			("en".to_string(), "hello".to_string()),
+			("es".to_string(), "".to_string()),
+			("pt".to_string(), "".to_string()),
+		]),
+	};
+
+	let errors = test.validate().expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+
+	assert_eq!(errors["translations"][0]["code"], json!("nested"));
+	assert_eq!(errors["translations"][0]["errors"]["es"][0]["code"], json!("length"));
+	assert!(errors["translations"][0]["errors"].get("pt").is_none());
+}
+
+#[test]
+fn should_report_modificated_keys_that_collide() {
+	let mut test = ModificateTest {
+		translations: BTreeMap::from([
+			("EN".to_string(), "hello".to_string()),
+			("en".to_string(), "olá".to_string()),
+		]),
+	};
+
+	let errors = test.validate_and_modificate().expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+
+	assert_eq!(
+		errors["translations"][0]["errors"]["EN"][0]["code"],
+		json!("duplicate_key")
+	);
+	assert_eq!(
+		test.translations,
+		BTreeMap::from([
+			("EN".to_string(), "hello".to_string()),
+			("en".to_string(), "olá".to_string()),
+		])
+	);
+}
+
+#[test]
+fn should_rename_keys_into_keys_that_are_renamed_away() {
+	let mut test = RenameChainTest {
+		translations: BTreeMap::from([
+			("A".to_string(), "first".to_string()),
+			("a".to_string(), "second".to_string()),
+		]),
+	};
+
+	assert!(test.validate_and_modificate().is_ok());
+	assert_eq!(
+		test.translations,
+		BTreeMap::from([
+			("a".to_string(), "first".to_string()),
+			("b".to_string(), "second".to_string()),
+		])
+	);
+
+	let mut test = RenameChainTest {
+		translations: BTreeMap::from([
+			("A".to_string(), "first".to_string()),
+			("a".to_string(), "second".to_string()),
+			("b".to_string(), "third".to_string()),
+		]),
+	};
+
+	let result = test.validate_and_modificate();
+	assert_errors!(result, test, {
+		"translations" => ("nested", validation_errors! {
+			"A" => ("duplicate_key", "collides with another key"),
+			"a" => ("duplicate_key", "collides with another key")
+		})
+	});
+	assert_eq!(
+		test.translations,
+		BTreeMap::from([
+			("A".to_string(), "first".to_string()),
+			("a".to_string(), "second".to_string()),
+			("b".to_string(), "third".to_string()),
+		])
+	);
+}
+
+#[test]
+fn should_parse_keys_and_nested_values() {
+	let wrapper = PayloadTestWrapper {
+		metadata: Some(HashMap::from([(
+			"1".to_string(),
+			MetadataWrapper {
+				value: Some("a".to_string()),
+			},
+		)])),
+	};
+
+	let test = PayloadTest::validate_and_parse(wrapper).expect("should be valid");
+	assert_eq!(test.metadata, HashMap::from([(1, Metadata { value: "a".to_string() })]));
+
+	let wrapper = PayloadTestWrapper {
+		metadata: Some(HashMap::from([(
+			"10".to_string(),
+			MetadataWrapper {
+				value: Some("a".to_string()),
+			},
+		)])),
+	};
+
+	let errors = PayloadTest::validate_and_parse(wrapper).expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+	assert_eq!(errors["metadata"][0]["errors"]["10"][0]["code"], json!("range"));
+
+	let wrapper = PayloadTestWrapper {
+		metadata: Some(HashMap::from([("2".to_string(), MetadataWrapper { value: None })])),
+	};
+
+	let errors = PayloadTest::validate_and_parse(wrapper).expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+	assert_eq!(
+		errors["metadata"][0]["errors"]["2"][0]["errors"]["value"][0]["code"],
+		json!("required")
+	);
+
+	let wrapper = PayloadTestWrapper {
+		metadata: Some(HashMap::from([
+			(
+				"1".to_string(),
+				MetadataWrapper {
+					value: Some("a".to_string()),
+				},
+			),
+			(
+				"01".to_string(),
+				MetadataWrapper {
+					value: Some("b".to_string()),
+				},
+			),
+		])),
+	};
+
+	let errors = PayloadTest::validate_and_parse(wrapper).expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+	let entries = errors["metadata"][0]["errors"].as_object().unwrap();
+
+	assert_eq!(entries.len(), 1);
+	assert!(
+		entries
+			.values()
+			.all(|errors| errors[0]["code"] == json!("duplicate_key"))
+	);
+}
@@ -0,0 +1,195 @@
+use serde_json::json;
+use validy::core::{Validate, ValidateAndModificate, ValidateAndParse};
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+struct LineItem {
+	#[validate(length(1..=10))]
+	pub name: String,
+	#[validate(range(1..=100))]
+	pub quantity: u32,
+}
+
+#[derive(Debug, Validate)]
+struct Order {
+	#[special(for_each(nested(LineItem)))]
+	pub items: Vec<LineItem>,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(modificate)]
+struct ModificateLineItem {
+	#[modificate(trim)]
+	#[validate(length(1..=10))]
+	pub name: String,
+}
+
+#[derive(Debug, Validate)]
+#[validate(modificate)]
+struct ModificateOrder {
+	#[special(for_each(nested(ModificateLineItem)))]
+	pub items: Vec<ModificateLineItem>,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(payload)]
+struct PayloadLineItem {
+	#[validate(range(1..=100))]
+	pub quantity: u32,
+}
+
+#[derive(Debug, Validate)]
+#[validate(payload)]
+struct PayloadOrder {
+	#[special(from_type(Vec<PayloadLineItemWrapper>))]
+	#[special(for_each(
+		config(from_item = PayloadLineItemWrapper, to_collection = Vec<PayloadLineItem>),
+		nested(PayloadLineItem, PayloadLineItemWrapper),
+	))]
+	pub items: Vec<PayloadLineItem>,
+}
+
+#[derive(Debug, Validate)]
+#[validate(failure_mode = FailFast)]
+struct FailFastOrder {
+	#[special(for_each(nested(LineItem)))]
+	pub items: Vec<LineItem>,
+}
+
+#[derive(Debug, Validate)]
+#[validate(failure_mode = LastFailPerField)]
+struct LastFailOrder {
+	#[validate(length(0..=2))]
+	#[special(for_each(nested(LineItem)))]
+	pub items: Vec<LineItem>,
+}
+
+fn item(name: &str, quantity: u32) -> LineItem {
+	LineItem {
+		name: name.to_string(),
+		quantity,
+	}
+}
+
+#[test]
+fn should_report_errors_by_index() {
+	let order = Order {
+		items: vec![item("a", 1), item("", 1), item("c", 0)],
+	};
+
+	let errors = order.validate().expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+
+	assert_eq!(errors["items"][0]["code"], json!("nested"));
+	assert!(errors["items"][0]["errors"].get("0").is_none());
+	assert_eq!(
+		errors["items"][0]["errors"]["1"][0]["errors"]["name"][0]["code"],
+		json!("length")
+	);
+	assert_eq!(
+		errors["items"][0]["errors"]["2"][0]["errors"]["quantity"][0]["code"],
+		json!("range")
+	);
+}
+
+#[test]
+fn should_accept_valid_items() {
+	let order = Order {
+		items: vec![item("a", 1), item("b", 2)],
+	};
+
+	assert!(order.validate().is_ok());
+}
+
+#[test]
+fn should_modificate_items_and_report_errors_by_index() {
+	let mut order = ModificateOrder {
+		items: vec![
+			ModificateLineItem {
+				name: "  a  ".to_string(),
+			},
+			ModificateLineItem { name: "  ".to_string() },
+		],
+	};
+
+	let errors = order.validate_and_modificate().expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+	assert!(errors["items"][0]["errors"].get("0").is_none());
+	assert_eq!(
+		errors["items"][0]["errors"]["1"][0]["errors"]["name"][0]["code"],
+		json!("length")
+	);
+}
+
+#[test]
+fn should_parse_items_and_report_errors_by_index() {
+	let wrapper = PayloadOrderWrapper {
+		items: Some(vec![
+			PayloadLineItemWrapper { quantity: Some(10) },
+			PayloadLineItemWrapper { quantity: Some(0) },
+		]),
+	};
+
+	let errors = PayloadOrder::validate_and_parse(wrapper).expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+
+	assert!(errors["items"][0]["errors"].get("0").is_none());
+	assert_eq!(
+		errors["items"][0]["errors"]["1"][0]["errors"]["quantity"][0]["code"],
+		json!("range")
+	);
+
+	let wrapper = PayloadOrderWrapper {
+		items: Some(vec![PayloadLineItemWrapper { quantity: Some(10) }]),
+	};
+
+	let order = PayloadOrder::validate_and_parse(wrapper).expect("should be valid");
+	assert_eq!(order.items, vec![PayloadLineItem { quantity: 10 }]);
+}
+
+#[test]
+fn should_report_the_failed_item_by_index_when_failing_fast() {
+	let order = FailFastOrder {
+		items: vec![item("a", 1), item("", 1), item("c", 0)],
+	};
+
+	let errors = order.validate().expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+
+	assert_eq!(errors["items"].as_array().unwrap().len(), 1);
+	assert_eq!(errors["items"][0]["code"], json!("nested"));
+	assert_eq!(
+		errors["items"][0]["errors"]["1"][0]["errors"]["name"][0]["code"],
+		json!("length")
+	);
+	assert!(errors["items"][0]["errors"].get("2").is_none());
+}
+
+#[test]
+fn should_keep_only_the_indexed_items_error_as_the_last_fail() {
+	let order = LastFailOrder {
+		items: vec![item("", 1), item("b", 1), item("c", 0)],
+	};
+
+	let errors = order.validate().expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+
+	assert_eq!(errors["items"].as_array().unwrap().len(), 1);
+	assert_eq!(errors["items"][0]["code"], json!("nested"));
+	assert_eq!(
+		errors["items"][0]["errors"]["0"][0]["errors"]["name"][0]["code"],
+		json!("length")
+	);
+	assert!(errors["items"][0]["errors"].get("1").is_none());
+	assert_eq!(
+		errors["items"][0]["errors"]["2"][0]["errors"]["quantity"][0]["code"],
+		json!("range")
+	);
+
+	let order = LastFailOrder {
+		items: vec![item("a", 1), item("b", 1), item("c", 1)],
+	};
+
+	let errors = order.validate().expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+	assert_eq!(errors["items"][0]["code"], json!("length"));
+}
@@ -0,0 +1,54 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(hostname)]
+	pub a: String,
+	#[validate(hostname("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_hostnames() {
+	let long_label = "a".repeat(64);
+	let long_hostname = ["a".repeat(63), "a".repeat(63), "a".repeat(63), "a".repeat(63)].join(".");
+	let cases = [
+		("localhost", true),
+		("smtp.example.com", true),
+		("my-host.local", true),
+		("example.com.", true),
+		("123.example.com", true),
+		("-example.com", false),
+		("example-.com", false),
+		("ex_ample.com", false),
+		("example..com", false),
+		("bücher.de", false),
+		(long_label.as_str(), false),
+		(long_hostname.as_str(), false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("hostname", "invalid hostname"),
+			});
+		}
+	}
+
+	test.a = "localhost".to_string();
+	test.b = Some("local host".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
@@ -0,0 +1,68 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(iban)]
+	pub a: String,
+	#[validate(iban("custom message", "custom_code", countries = ["DE", "FR"]))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_ibans() {
+	let cases = [
+		("DE89370400440532013000", true),
+		("GB82WEST12345698765432", true),
+		("GB82 WEST 1234 5698 7654 32", true),
+		("gb82west12345698765432", true),
+		("FR1420041010050500013M02606", true),
+		("NO9386011117947", true),
+		("DE89370400440532013001", false),
+		("DE8937040044053201300", false),
+		("GB82WEST1234569876543!", false),
+		("DE00370400440532013000", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("iban", ("invalid iban", [("country", &case[..2])])),
+			});
+		}
+	}
+
+	for case in ["XX82WEST12345698765432", "1234", ""] {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if case.starts_with("XX") {
+			assert_errors!(result, test, {
+				"a" => ("iban", ("invalid iban", [("country", "XX")])),
+			});
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("iban", "invalid iban"),
+			});
+		}
+	}
+
+	test.a = "DE89370400440532013000".to_string();
+	test.b = Some("FR1420041010050500013M02606".to_string());
+	let result = test.validate();
+	assert_validation!(result, test);
+
+	test.b = Some("gb82WEST12345698765432".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", ("custom message", [("country", "GB")])),
+	});
+}
@@ -1,5 +1,9 @@
 use serde::Deserialize;
 use validy::core::Validate;
+use validy::functions::validation::ip::{
+	IpClass, IpPolicy, validate_ip, validate_ip_with_policy, validate_ipv4, validate_ipv4_with_policy, validate_ipv6,
+	validate_ipv6_with_policy,
+};
 
 use validy::{assert_errors, assert_validation};
 
@@ -17,6 +21,29 @@ struct Test {
 	pub d: Option<String>,
 }
 
+#[derive(Debug, Validate, PartialEq)]
+struct PolicyTest {
+	#[validate(ip(in = ["10.0.0.0/8", "2001:db8::/32"], not_in = ["10.0.0.0/24"]))]
+	pub a: String,
+	#[validate(ipv4(class = "public"))]
+	pub b: String,
+	#[validate(ipv6(class = "global_unicast"))]
+	pub c: String,
+	#[validate(ip(class = "private"))]
+	pub d: String,
+}
+
+impl Default for PolicyTest {
+	fn default() -> Self {
+		PolicyTest {
+			a: "10.1.0.1".to_string(),
+			b: "8.8.8.8".to_string(),
+			c: "2606:4700::1111".to_string(),
+			d: "192.168.0.1".to_string(),
+		}
+	}
+}
+
 #[test]
 fn should_validate_ips() {
 	let cases = [
@@ -94,3 +121,136 @@ fn should_validate_ips() {
 		}
 	}
 }
+
+#[test]
+fn should_validate_ip_policies() {
+	assert_validation!(PolicyTest::default().validate(), PolicyTest::default());
+
+	let cases = [
+		("10.1.0.1", true),
+		("10.255.255.255", true),
+		("2001:db8::1", true),
+		("::ffff:10.1.0.1", true),
+		("10.0.0.1", false),
+		("11.0.0.1", false),
+		("2001:db9::1", false),
+		("not an ip", false),
+	];
+
+	for (case, is_valid) in cases.iter() {
+		let test = PolicyTest {
+			a: case.to_string(),
+			..PolicyTest::default()
+		};
+
+		let result = test.validate();
+		assert_eq!(result.is_ok(), *is_valid, "{case}");
+		if !*is_valid {
+			assert_errors!(result, test, {
+				"a" => ("ip", "invalid ip format"),
+			});
+		}
+	}
+
+	let cases = [
+		("8.8.8.8", true),
+		("1.1.1.1", true),
+		("10.0.0.1", false),
+		("127.0.0.1", false),
+		("169.254.169.254", false),
+		("100.64.0.1", false),
+		("192.0.2.1", false),
+		("224.0.0.1", false),
+		("255.255.255.255", false),
+		("::1", false),
+	];
+
+	for (case, is_valid) in cases.iter() {
+		let test = PolicyTest {
+			b: case.to_string(),
+			..PolicyTest::default()
+		};
+
+		assert_eq!(test.validate().is_ok(), *is_valid, "{case}");
+	}
+
+	let cases = [
+		("2606:4700::1111", true),
+		("2001:4860:4860::8888", true),
+		("2001:db8::1", false),
+		("fd00::1", false),
+		("fe80::1", false),
+		("ff02::1", false),
+		("::1", false),
+		("::ffff:8.8.8.8", true),
+	];
+
+	for (case, is_valid) in cases.iter() {
+		let test = PolicyTest {
+			c: case.to_string(),
+			..PolicyTest::default()
+		};
+
+		assert_eq!(test.validate().is_ok(), *is_valid, "{case}");
+	}
+
+	let cases = [
+		("10.0.0.1", true),
+		("172.16.0.1", true),
+		("100.64.0.1", true),
+		("fd00::1", true),
+		("::ffff:192.168.0.1", true),
+		("8.8.8.8", false),
+		("127.0.0.1", false),
+		("2606:4700::1111", false),
+	];
+
+	for (case, is_valid) in cases.iter() {
+		let test = PolicyTest {
+			d: case.to_string(),
+			..PolicyTest::default()
+		};
+
+		assert_eq!(test.validate().is_ok(), *is_valid, "{case}");
+	}
+}
+
+const BROKEN_NETWORK: &str = "10.0.0.0/33";
+
+#[derive(Debug, Validate, PartialEq)]
+struct BrokenPolicyTest {
+	#[validate(ip(in = [BROKEN_NETWORK]))]
+	pub a: String,
+	#[validate(ip(not_in = [BROKEN_NETWORK]))]
+	pub b: String,
+}
+
+#[test]
+fn should_fail_closed_on_unparseable_networks() {
+	let test = BrokenPolicyTest {
+		a: "10.0.0.1".to_string(),
+		b: "8.8.8.8".to_string(),
+	};
+
+	assert_errors!(test.validate(), test, {
+		"a" => ("ip", "invalid ip format"),
+		"b" => ("ip", "invalid ip format"),
+	});
+}
+
+#[test]
+fn should_validate_ips_with_functions() {
+	assert!(validate_ip("10.0.0.1", "a", "ip", "invalid ip format").is_ok());
+	assert!(validate_ipv4("10.0.0.1", "a", "ipv4", "invalid ipv4 format").is_ok());
+	assert!(validate_ipv6("::1", "a", "ipv6", "invalid ipv6 format").is_ok());
+	assert!(validate_ip("10.0.0", "a", "ip", "invalid ip format").is_err());
+
+	let policy = IpPolicy {
+		class: Some(IpClass::Public),
+		..Default::default()
+	};
+
+	assert!(validate_ip_with_policy("8.8.8.8", &policy, "a", "ip", "invalid ip format").is_ok());
+	assert!(validate_ipv4_with_policy("10.0.0.1", &policy, "a", "ipv4", "invalid ipv4 format").is_err());
+	assert!(validate_ipv6_with_policy("::1", &policy, "a", "ipv6", "invalid ipv6 format").is_err());
+}
@@ -0,0 +1,55 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(language_tag)]
+	pub a: String,
+	#[validate(language_tag("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_language_tags() {
+	let cases = [
+		("en", true),
+		("en-US", true),
+		("pt-BR", true),
+		("zh-Hant-TW", true),
+		("sr-Latn-RS", true),
+		("de-CH-1996", true),
+		("en-US-x-private", true),
+		("en_US", false),
+		("english", false),
+		("xx", false),
+		("en-", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("language_tag", "invalid language tag"),
+			});
+		}
+	}
+
+	test.a = "en".to_string();
+	test.b = Some("pt-BR".to_string());
+	let result = test.validate();
+	assert_validation!(result, test);
+
+	test.b = Some("pt_BR".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
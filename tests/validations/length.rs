@@ -1,7 +1,10 @@
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::Deref;
 
 use serde::Deserialize;
 use validy::core::Validate;
+use validy::settings::LengthUnit;
+use validy::utils::length::Length;
 
 use validy::{assert_errors, assert_validation};
 
@@ -189,3 +192,102 @@ fn should_validate_lengths() {
 		}
 	}
 }
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct UnitTest {
+	#[validate(length(..=4, unit = "chars"))]
+	pub a: String,
+	#[validate(length(..=4, unit = "graphemes"))]
+	pub b: String,
+	#[validate(length(..=4, unit = "utf16"))]
+	pub c: String,
+	#[validate(length(..=4, unit = "bytes"))]
+	pub d: String,
+}
+
+#[test]
+fn should_validate_lengths_by_unit() {
+	let cases = [
+		("abcd", [true, true, true, true]),
+		("山田太郎", [true, true, true, false]),
+		("😀😀😀", [true, true, false, false]),
+		("éééé", [true, true, true, false]),
+		("e\u{301}e\u{301}e\u{301}e\u{301}", [false, true, false, false]),
+		("abcde", [false, false, false, false]),
+	];
+
+	for (case, expected) in cases.iter() {
+		let test = UnitTest {
+			a: case.to_string(),
+			b: case.to_string(),
+			c: case.to_string(),
+			d: case.to_string(),
+		};
+
+		let errors = test.validate().err().unwrap_or_default();
+		for (field, is_valid) in ["a", "b", "c", "d"].iter().zip(expected) {
+			assert_eq!(!errors.contains_key(*field), *is_valid, "{field} with {case}");
+		}
+	}
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Tags(Vec<String>);
+
+impl Length for Tags {
+	fn measure(&self, _: LengthUnit) -> usize {
+		self.0.len()
+	}
+}
+
+type Name = String;
+
+#[derive(Debug, Default, PartialEq)]
+struct Nickname(String);
+
+impl Deref for Nickname {
+	type Target = String;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+#[derive(Debug, Default, Validate)]
+struct DefaultUnitTest {
+	#[validate(length(1..=2))]
+	pub tags: Tags,
+	#[validate(length(..=3))]
+	pub name: String,
+	#[validate(length(..=2, unit = "chars"))]
+	pub alias: Name,
+	#[validate(length(..=2, unit = "chars"))]
+	pub nickname: Nickname,
+}
+
+#[test]
+fn should_measure_aliases_newtypes_and_custom_types() {
+	let test = DefaultUnitTest {
+		tags: Tags(vec!["a".to_string()]),
+		name: "山".to_string(),
+		alias: "山田".to_string(),
+		nickname: Nickname("山田".to_string()),
+	};
+
+	assert_validation!(test.validate(), test);
+
+	let test = DefaultUnitTest {
+		tags: Tags(vec![]),
+		name: "山田".to_string(),
+		alias: "山田太".to_string(),
+		nickname: Nickname("山田太".to_string()),
+	};
+
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"tags" => ("length", "length out of range"),
+		"name" => ("length", "length out of range"),
+		"alias" => ("length", "length out of range"),
+		"nickname" => ("length", "length out of range"),
+	});
+}
@@ -0,0 +1,128 @@
+use std::borrow::Cow;
+
+use validy::core::{AsyncValidate, Validate, ValidateAndParse};
+
+use validy::assert_errors;
+
+#[derive(Debug, Validate)]
+struct Borrowed<'a> {
+	#[validate(length(1..=10))]
+	pub name: &'a str,
+	#[validate(contains("@"))]
+	pub contact: Cow<'a, str>,
+	#[validate(length(0..=3))]
+	pub tags: Vec<&'a str>,
+}
+
+#[derive(Debug, Validate)]
+#[validate(asynchronous)]
+struct AsyncBorrowed<'a> {
+	#[validate(length(1..=10))]
+	pub name: &'a str,
+}
+
+#[derive(Debug, Validate)]
+#[validate(payload)]
+#[wrapper_derive(Debug, Clone)]
+struct BorrowedPayload<'a> {
+	#[validate(length(1..=10))]
+	pub slug: &'a str,
+	#[modificate(trim)]
+	#[modificate(lowercase)]
+	#[validate(length(1..=10))]
+	pub name: Cow<'a, str>,
+	#[modificate(snake_case)]
+	pub label: Option<Cow<'a, str>>,
+}
+
+#[test]
+fn should_validate_borrowed_fields() {
+	let contact = String::from("alice@example.com");
+	let test = Borrowed {
+		name: "alice",
+		contact: Cow::Borrowed(&contact),
+		tags: vec!["a", "b"],
+	};
+
+	assert!(test.validate().is_ok());
+
+	let test = Borrowed {
+		name: "",
+		contact: Cow::Owned(String::from("alice")),
+		tags: vec!["a", "b", "c", "d"],
+	};
+
+	assert_errors!(test.validate(), test, {
+		"name" => ("length", "length out of range"),
+		"contact" => ("slice", "invalid format"),
+		"tags" => ("length", "length out of range"),
+	});
+}
+
+#[tokio::test]
+async fn should_async_validate_borrowed_fields() {
+	assert!(AsyncBorrowed { name: "alice" }.async_validate().await.is_ok());
+	assert!(AsyncBorrowed { name: "" }.async_validate().await.is_err());
+}
+
+#[test]
+fn should_deserialize_borrowed_wrappers() {
+	let json = String::from(r#"{ "slug": "alice", "name": "  ALICE  ", "label": "Main Account" }"#);
+	let wrapper: BorrowedPayloadWrapper = serde_json::from_str(&json).unwrap();
+	let result = BorrowedPayload::validate_and_parse(wrapper).unwrap();
+
+	assert_eq!(result.slug, "alice");
+	assert_eq!(result.name, "alice");
+	assert_eq!(result.label.as_deref(), Some("main_account"));
+
+	let json = String::from(r#"{ "slug": "alice", "name": "alice", "label": "main_account" }"#);
+	let wrapper: BorrowedPayloadWrapper = serde_json::from_str(&json).unwrap();
+	assert!(matches!(wrapper.name, Some(Cow::Borrowed("alice"))));
+	assert!(matches!(wrapper.label, Some(Cow::Borrowed("main_account"))));
+
+	let result = BorrowedPayload::validate_and_parse(wrapper).unwrap();
+	assert!(matches!(result.name, Cow::Borrowed("alice")));
+	assert!(matches!(result.label, Some(Cow::Borrowed("main_account"))));
+
+	let json = String::from(r#"{ "slug": "alice", "name": "alice" }"#);
+	let wrapper: BorrowedPayloadWrapper = serde_json::from_str(&json).unwrap();
+	assert!(wrapper.label.is_none());
+}
+
+#[test]
+fn should_keep_unchanged_cows_borrowed() {
+	let wrapper = BorrowedPayloadWrapper {
+		slug: Some("alice"),
+		name: Some(Cow::Borrowed("  alice ")),
+		label: Some(Cow::Borrowed("main_account")),
+	};
+
+	let result = BorrowedPayload::validate_and_parse(wrapper).unwrap();
+	assert!(matches!(result.name, Cow::Borrowed("alice")));
+	assert!(matches!(result.label, Some(Cow::Borrowed("main_account"))));
+
+	let wrapper = BorrowedPayloadWrapper {
+		slug: Some("alice"),
+		name: Some(Cow::Borrowed(" Alice")),
+		label: Some(Cow::Borrowed("Main Account")),
+	};
+
+	let result = BorrowedPayload::validate_and_parse(wrapper).unwrap();
+	assert!(matches!(result.name, Cow::Owned(ref name) if name == "alice"));
+	assert!(matches!(result.label, Some(Cow::Owned(ref label)) if label == "main_account"));
+}
+
+#[test]
+fn should_fail_borrowed_payloads() {
+	let wrapper = BorrowedPayloadWrapper {
+		slug: Some(""),
+		name: Some(Cow::Borrowed("   ")),
+		label: None,
+	};
+
+	let result = BorrowedPayload::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"slug" => ("length", "length out of range"),
+		"name" => ("length", "length out of range"),
+	});
+}
@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(luhn)]
+	pub a: String,
+	#[validate(luhn("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_luhn_checksums() {
+	let cases = [
+		("79927398713", true),
+		("4111111111111111", true),
+		("18", true),
+		("00", true),
+		("79927398710", false),
+		("7992 7398 713", false),
+		("7992739871a", false),
+		("0", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("luhn", "invalid luhn checksum"),
+			});
+		}
+	}
+
+	test.a = "79927398713".to_string();
+	test.b = Some("79927398714".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
@@ -1,36 +1,69 @@
 pub mod after_now;
 pub mod after_today;
 pub mod allowlist;
+pub mod alpha;
+pub mod alphanumeric;
+pub mod ascii;
 pub mod async_custom;
 pub mod async_custom_with_context;
 pub mod before_now;
 pub mod before_today;
+pub mod bic;
 pub mod blocklist;
+pub mod charset;
+pub mod cidr;
+pub mod confusable_safe;
 pub mod contains;
+pub mod country;
+pub mod credit_card;
+pub mod currency;
 pub mod custom;
 pub mod custom_with_context;
+pub mod domain;
 pub mod email;
 pub mod fail_fast;
 pub mod fail_once_per_field;
 pub mod field_content_type;
 pub mod field_file_name;
 pub mod field_name;
+pub mod flatten;
+pub mod for_each_entry;
+pub mod for_each_nested;
 pub mod full_fail;
+pub mod hostname;
+pub mod iban;
 pub mod inline;
 pub mod ip;
 pub mod ipv4;
 pub mod ipv6;
+pub mod language_tag;
 pub mod last_fail_per_field;
 pub mod length;
+pub mod lifetimes;
+pub mod luhn;
 pub mod naive_date;
 pub mod naive_time;
+pub mod nested_inference;
+pub mod no_control_chars;
+pub mod no_mixed_script;
+pub mod no_zero_width;
 pub mod now;
+pub mod numeric;
 pub mod option;
+pub mod password;
 pub mod pattern;
+pub mod phone;
+pub mod port;
 pub mod prefix;
+pub mod printable;
 pub mod range;
+pub mod recursive;
+pub mod single_line;
+pub mod socket_addr;
 pub mod suffix;
 pub mod time;
+pub mod timezone;
 pub mod today;
+pub mod unicode_scripts;
 pub mod url;
 pub mod uuid;
@@ -0,0 +1,130 @@
+use serde_json::json;
+use validy::core::{AsyncValidateAndParse, Validate, ValidateAndParse};
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+struct Role {
+	#[validate(length(1..=10))]
+	pub name: String,
+}
+
+#[derive(Debug, Validate)]
+struct User {
+	#[special(nested)]
+	pub role: Option<Role>,
+	#[special(nested)]
+	pub roles: Vec<Role>,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Clone, PartialEq)]
+struct PayloadRole {
+	#[modificate(trim)]
+	#[validate(length(1..=10))]
+	pub name: String,
+}
+
+#[derive(Debug, Default, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Clone, PartialEq)]
+struct PayloadUser {
+	#[special(nested)]
+	pub role: PayloadRole,
+	#[special(nested)]
+	pub backup: Option<Box<PayloadRole>>,
+	#[special(nested)]
+	pub roles: Vec<PayloadRole>,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(payload, asynchronous)]
+#[wrapper_derive(Clone)]
+struct AsyncCategory {
+	#[validate(length(1..=10))]
+	pub name: String,
+	#[special(nested)]
+	pub parent: Option<Box<AsyncCategory>>,
+}
+
+fn role(name: &str) -> Role {
+	Role { name: name.to_string() }
+}
+
+#[test]
+fn should_infer_nested_types() {
+	let user = User {
+		role: Some(role("admin")),
+		roles: vec![role("user")],
+	};
+
+	assert!(user.validate().is_ok());
+
+	let user = User {
+		role: Some(role("")),
+		roles: vec![role("user"), role("")],
+	};
+
+	let errors = user.validate().expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+
+	assert_eq!(errors["role"][0]["errors"]["name"][0]["code"], json!("length"));
+	assert_eq!(
+		errors["roles"][0]["errors"]["1"][0]["errors"]["name"][0]["code"],
+		json!("length")
+	);
+}
+
+#[test]
+fn should_infer_nested_wrapper_types() {
+	let wrapper: PayloadUserWrapper = serde_json::from_value(json!({
+		"role": { "name": " admin " },
+		"backup": { "name": " user " },
+		"roles": [{ "name": " guest " }]
+	}))
+	.unwrap();
+
+	let user = PayloadUser::validate_and_parse(wrapper.clone()).expect("should be valid");
+
+	assert_eq!(user.role.name, "admin");
+	assert_eq!(user.backup.as_ref().map(|backup| backup.name.as_str()), Some("user"));
+	assert_eq!(
+		user.roles,
+		vec![PayloadRole {
+			name: "guest".to_string()
+		}]
+	);
+
+	let reversed = PayloadUserWrapper::from(user);
+	assert_eq!(reversed.role.and_then(|role| role.name), Some("admin".to_string()));
+
+	let wrapper: PayloadUserWrapper = serde_json::from_value(json!({
+		"role": { "name": "" },
+		"roles": [{ "name": "guest" }, { "name": "" }]
+	}))
+	.unwrap();
+
+	let errors = PayloadUser::validate_and_parse(wrapper).expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+
+	assert_eq!(errors["role"][0]["errors"]["name"][0]["code"], json!("length"));
+	assert_eq!(
+		errors["roles"][0]["errors"]["1"][0]["errors"]["name"][0]["code"],
+		json!("length")
+	);
+}
+
+#[tokio::test]
+async fn should_infer_recursive_wrapper_types() {
+	let wrapper: AsyncCategoryWrapper = serde_json::from_value(json!({
+		"name": "books",
+		"parent": { "name": "" }
+	}))
+	.unwrap();
+
+	let errors = AsyncCategory::async_validate_and_parse(wrapper)
+		.await
+		.expect_err("should be invalid");
+	let errors = serde_json::to_value(errors).unwrap();
+
+	assert_eq!(errors["parent"][0]["errors"]["name"][0]["code"], json!("length"));
+}
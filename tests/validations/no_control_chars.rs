@@ -0,0 +1,46 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(no_control_chars)]
+	pub a: String,
+	#[validate(no_control_chars("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_strings_without_control_chars() {
+	let cases = [
+		("plain", true),
+		("multi\nline\ttext\r\n", true),
+		("", true),
+		("bell\u{7}", false),
+		("null\u{0}", false),
+		("esc\u{1B}[31m", false),
+		("delete\u{7F}", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("no_control_chars", "has control characters"),
+			});
+		}
+	}
+
+	test.a = "plain".to_string();
+	test.b = Some("null\u{0}".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
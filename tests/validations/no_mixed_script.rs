@@ -0,0 +1,46 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(no_mixed_script)]
+	pub a: String,
+	#[validate(no_mixed_script("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_strings_without_mixed_scripts() {
+	let cases = [
+		("paypal", true),
+		("Привет", true),
+		("日本語ひらがなカタカナ", true),
+		("user 123", true),
+		("", true),
+		("раураl", false),
+		("ΑBC", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("no_mixed_script", "mixes characters from different scripts"),
+			});
+		}
+	}
+
+	test.a = "paypal".to_string();
+	test.b = Some("раураl".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
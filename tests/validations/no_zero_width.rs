@@ -0,0 +1,47 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(no_zero_width)]
+	pub a: String,
+	#[validate(no_zero_width("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_strings_without_zero_width_chars() {
+	let cases = [
+		("admin", true),
+		("Ünïcödé", true),
+		("", true),
+		("ad\u{200B}min", false),
+		("\u{FEFF}admin", false),
+		("ad\u{AD}min", false),
+		("rtl\u{202E}txt", false),
+		("👨\u{200D}👩", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("no_zero_width", "has invisible characters"),
+			});
+		}
+	}
+
+	test.a = "admin".to_string();
+	test.b = Some("ad\u{200D}min".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
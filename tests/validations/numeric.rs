@@ -0,0 +1,46 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(numeric)]
+	pub a: String,
+	#[validate(numeric("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_numeric_strings() {
+	let cases = [
+		("0123", true),
+		("٣٤٥", true),
+		("", true),
+		("12.5", false),
+		("-1", false),
+		("1a", false),
+		("1 000", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("numeric", "must contain only digits"),
+			});
+		}
+	}
+
+	test.a = "123".to_string();
+	test.b = Some("12.5".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
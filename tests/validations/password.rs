@@ -0,0 +1,203 @@
+use serde::Deserialize;
+use validy::core::{Validate, ValidateAndParse};
+use validy::utils::password::{estimate_password, estimate_password_at};
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	pub email: String,
+	#[validate(password)]
+	pub a: String,
+	#[validate(password(min_score = 2, require = ["upper", "digit", "symbol"], forbid_fields = ["email", "name"], max_repeats = 3))]
+	pub b: Option<String>,
+	#[validate(password("custom message", "custom_code", min_entropy = 30.0))]
+	pub c: Option<String>,
+	pub name: Option<String>,
+}
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload, partial)]
+#[wrapper_derive(Debug, Clone)]
+struct PayloadTest {
+	pub email: String,
+	#[validate(password(forbid_fields = ["email", "name"]))]
+	pub password: String,
+	pub name: Option<String>,
+}
+
+#[derive(Debug, Validate, PartialEq)]
+#[validate(payload)]
+#[wrapper_derive(Debug, Clone)]
+struct RawSiblingTest {
+	pub r#type: String,
+	#[validate(password(forbid_fields = ["r#type"], reference_year = 2025))]
+	pub password: String,
+}
+
+#[test]
+fn should_validate_passwords() {
+	let cases = [
+		("correcthorsebatterystaple", true),
+		("xK9#mQ2$vL7p", true),
+		("Blue-Monkey-Battery-42", true),
+		("password", false),
+		("P@ssw0rd", false),
+		("qwertyuiop", false),
+		("abcdef123", false),
+		("aaaaaaaa", false),
+		("Summer2024", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			let errors = result.expect_err("should be invalid");
+			let json = serde_json::to_string(&errors).expect("should serialize");
+			assert!(json.contains(r#""code":"password""#));
+			assert!(json.contains(r#""score":""#));
+		}
+	}
+
+	test.a = "password".to_string();
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"a" => ("password", ("weak password", [("score", "0"), ("suggestions", "avoid_common_passwords,add_more_words")])),
+	});
+}
+
+#[test]
+fn should_validate_password_policies() {
+	let mut test = Test {
+		email: "johnny.doe@example.com".to_string(),
+		a: "correcthorsebatterystaple".to_string(),
+		name: Some("Mary Jane".to_string()),
+		..Default::default()
+	};
+
+	test.b = Some("Correct-Horse-42".to_string());
+	let result = test.validate();
+	assert_validation!(result, test);
+
+	test.b = Some("correcthorsebatterystaple".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("password", ("weak password", [("score", "4"), ("suggestions", "add_upper,add_digit,add_symbol")])),
+	});
+
+	test.b = Some("Correct-Horse-4444".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("password", ("weak password", [("score", "4"), ("suggestions", "avoid_repeats")])),
+	});
+
+	test.b = Some("Johnny-Battery-42".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("password", ("weak password", [("score", "4"), ("suggestions", "avoid_personal_info")])),
+	});
+
+	test.b = Some("Staple-MARYJANE-7".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("password", ("weak password", [("score", "4"), ("suggestions", "avoid_personal_info")])),
+	});
+
+	test.b = None;
+	test.c = Some("kX8r2pLq".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"c" => ("custom_code", ("custom message", [("score", "2"), ("suggestions", "add_more_words")])),
+	});
+
+	test.c = Some("xK9#mQ2$vL7p".to_string());
+	let result = test.validate();
+	assert_validation!(result, test);
+}
+
+#[test]
+fn should_validate_passwords_against_wrapper_siblings() {
+	let mut wrapper = PayloadTestWrapper {
+		email: Some("johnny.doe@example.com".to_string()),
+		password: Some("Correct-Horse-42".to_string()),
+		name: Some("Mary Jane".to_string()),
+	};
+
+	let result = PayloadTest::validate_and_parse(wrapper.clone());
+	assert!(result.is_ok());
+
+	for password in ["Johnny-Battery-42", "Battery-Maryjane-42"] {
+		wrapper.password = Some(password.to_string());
+		let result = PayloadTest::validate_and_parse(wrapper.clone());
+		assert_errors!(result, wrapper, {
+			"password" => ("password", ("weak password", [("score", "4"), ("suggestions", "avoid_personal_info")])),
+		});
+	}
+
+	wrapper.name = None;
+	let result = PayloadTest::validate_and_parse(wrapper.clone());
+	assert!(result.is_ok());
+}
+
+#[test]
+fn should_validate_password_patches_against_target_siblings() {
+	let mut target = PayloadTest {
+		email: "johnny.doe@example.com".to_string(),
+		password: "Correct-Horse-42".to_string(),
+		name: None,
+	};
+
+	let patch = PayloadTestPatch {
+		password: Some(Some("Johnny-Battery-42".to_string())),
+		..Default::default()
+	};
+
+	let errors = patch.apply_to(&mut target).expect_err("should be invalid");
+	assert!(errors.contains_key("password"));
+
+	let patch = PayloadTestPatch {
+		email: Some(Some("mary.jane@example.com".to_string())),
+		password: Some(Some("Johnny-Battery-42".to_string())),
+		..Default::default()
+	};
+
+	assert!(patch.apply_to(&mut target).is_ok());
+	assert_eq!(target.password, "Johnny-Battery-42");
+}
+
+#[test]
+fn should_validate_passwords_against_raw_siblings() {
+	let mut wrapper = RawSiblingTestWrapper {
+		r#type: Some("administrator".to_string()),
+		password: Some("Correct-Horse-42".to_string()),
+	};
+
+	let result = RawSiblingTest::validate_and_parse(wrapper.clone());
+	assert!(result.is_ok());
+
+	wrapper.password = Some("Administrator-Battery-42".to_string());
+	let result = RawSiblingTest::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"password" => ("password", ("weak password", [("score", "4"), ("suggestions", "avoid_personal_info")])),
+	});
+}
+
+#[test]
+fn should_score_years_against_the_reference_year() {
+	let recent = estimate_password_at("1999", &[], 2000);
+	let distant = estimate_password_at("1999", &[], 2050);
+
+	assert!(recent.guesses < distant.guesses);
+	assert_eq!(estimate_password_at("1999", &[], 2000), recent);
+	assert_eq!(
+		estimate_password_at("1999", &[], 2000).suggestions,
+		["avoid_dates", "add_more_words"]
+	);
+	assert!(estimate_password("1999", &[]).guesses <= distant.guesses);
+}
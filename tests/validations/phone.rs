@@ -0,0 +1,126 @@
+use serde::Deserialize;
+use validy::core::{Validate, ValidateWithContext};
+use validy::settings::ValidationSettings;
+use validy::utils::phone::PhoneRegion;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(phone)]
+	pub a: String,
+	#[validate(phone("custom message", "custom_code", regions = ["BR", "US"], types = ["mobile"]))]
+	pub b: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct RegionTest {
+	#[validate(phone(region = "BR"))]
+	pub a: String,
+	#[validate(phone(region = "br", types = ["fixed_line"]))]
+	pub b: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+#[validate(context = String)]
+struct ContextTest {
+	#[validate(phone(region = context))]
+	pub a: String,
+}
+
+#[test]
+fn should_validate_phones() {
+	let cases = [
+		("+5511987654321", true),
+		("+55 (11) 98765-4321", true),
+		("+1 201-555-0123", true),
+		("+44 20 7946 0000", true),
+		("tel:+1-201-555-0123", true),
+		("+447700900123", false),
+		("+55 11 1234", false),
+		("+1 000 000 0000", false),
+		("phone", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("phone", "invalid phone number"),
+			});
+		}
+	}
+
+	test.a = "+5511987654321".to_string();
+	for (case, is_valid) in [
+		("+5511987654321", true),
+		("+12015550123", true),
+		("+551133334444", false),
+		("+442079460000", false),
+	] {
+		test.b = Some(case.to_string());
+		let result = test.validate();
+
+		if is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"b" => ("custom_code", "custom message"),
+			});
+		}
+	}
+}
+
+#[test]
+fn should_validate_phones_with_regions() {
+	let mut test = RegionTest {
+		a: "(11) 98765-4321".to_string(),
+		b: Some("11 3333-4444".to_string()),
+	};
+
+	let result = test.validate();
+	assert_validation!(result, test);
+
+	test.a = "+1 201-555-0123".to_string();
+	test.b = Some("(11) 98765-4321".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("phone", "invalid phone number"),
+	});
+
+	let test = ContextTest {
+		a: "020 7946 0000".to_string(),
+	};
+
+	let result = test.validate_with_context(&"GB".to_string());
+	assert_validation!(result, test);
+
+	let result = test.validate_with_context(&"BR".to_string());
+	assert_errors!(result, test, {
+		"a" => ("phone", "invalid phone number"),
+	});
+}
+
+#[test]
+fn should_use_the_default_phone_region() {
+	let test = Test {
+		a: "(11) 98765-4321".to_string(),
+		b: None,
+	};
+
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"a" => ("phone", "invalid phone number"),
+	});
+
+	ValidationSettings::set_phone_region(Some(PhoneRegion::BR));
+	let result = test.validate();
+	ValidationSettings::set_phone_region(None);
+	assert_validation!(result, test);
+}
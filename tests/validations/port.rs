@@ -0,0 +1,69 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(port)]
+	pub a: String,
+	#[validate(port(1024..))]
+	pub b: Option<u16>,
+	#[validate(port(range = 1..=1023, message = "custom message", code = "custom_code"))]
+	pub c: Option<u32>,
+}
+
+#[test]
+fn should_validate_ports() {
+	let cases = [
+		("0", true),
+		("80", true),
+		("65535", true),
+		("65536", false),
+		("-1", false),
+		("http", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("port", "invalid port"),
+			});
+		}
+	}
+
+	test.a = "80".to_string();
+	for (case, is_valid) in [(1024, true), (8080, true), (65535, true), (80, false), (0, false)] {
+		test.b = Some(case);
+		let result = test.validate();
+
+		if is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"b" => ("port", "invalid port"),
+			});
+		}
+	}
+
+	test.b = None;
+	for (case, is_valid) in [(1, true), (1023, true), (0, false), (1024, false), (70000, false)] {
+		test.c = Some(case);
+		let result = test.validate();
+
+		if is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"c" => ("custom_code", "custom message"),
+			});
+		}
+	}
+}
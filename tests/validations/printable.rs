@@ -0,0 +1,47 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(printable)]
+	pub a: String,
+	#[validate(printable("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_printable_strings() {
+	let cases = [
+		("Hello, World!", true),
+		("café ☕", true),
+		("", true),
+		("line\nbreak", false),
+		("tab\t", false),
+		("zero\u{200B}width", false),
+		("private\u{E000}", false),
+		("separator\u{2028}", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("printable", "has non-printable characters"),
+			});
+		}
+	}
+
+	test.a = "text".to_string();
+	test.b = Some("bell\u{7}".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
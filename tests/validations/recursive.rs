@@ -0,0 +1,216 @@
+use serde_json::json;
+use std::sync::Arc;
+use validy::core::{AsyncValidateAndParse, Validate, ValidateAndModificate, ValidateAndParse, ValidationErrors};
+use validy::settings::ValidationSettings;
+use validy::utils::assertions::assert_validation_errors;
+
+use validy::{assert_errors, validation_errors};
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+struct Category {
+	#[validate(length(1..=10))]
+	pub name: String,
+	#[special(nested(Category))]
+	pub parent: Option<Box<Category>>,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+struct SharedCategory {
+	#[validate(length(1..=10))]
+	pub name: String,
+	#[special(nested(Arc<SharedCategory>))]
+	pub parent: Option<Arc<SharedCategory>>,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(modificate)]
+struct ModificateCategory {
+	#[modificate(trim)]
+	#[validate(length(1..=10))]
+	pub name: String,
+	#[special(nested(ModificateCategory))]
+	pub parent: Option<Box<ModificateCategory>>,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(payload, reversible)]
+#[wrapper_derive(Debug, Clone)]
+struct Comment {
+	#[modificate(trim)]
+	#[validate(length(1..=20))]
+	pub text: String,
+	#[special(from_type(Box<CommentWrapper>))]
+	#[special(nested(Comment, CommentWrapper))]
+	pub reply: Option<Box<Comment>>,
+}
+
+#[derive(Debug, Clone, Default, Validate, PartialEq)]
+#[validate(payload, asynchronous)]
+#[wrapper_derive(Debug, Clone)]
+struct AsyncComment {
+	#[validate(length(1..=20))]
+	pub text: String,
+	#[special(from_type(Box<AsyncCommentWrapper>))]
+	#[special(nested(AsyncComment, AsyncCommentWrapper))]
+	pub reply: Option<Box<AsyncComment>>,
+}
+
+fn category(name: &str, parent: Option<Category>) -> Category {
+	Category {
+		name: name.to_string(),
+		parent: parent.map(Box::new),
+	}
+}
+
+fn chain(depth: usize) -> Category {
+	(0..depth).fold(category("leaf", None), |parent, _| category("node", Some(parent)))
+}
+
+fn reply_chain(depth: usize) -> AsyncCommentWrapper {
+	let json = (0..depth).fold(
+		json!({ "text": "leaf" }),
+		|reply, _| json!({ "text": "node", "reply": reply }),
+	);
+
+	serde_json::from_value(json).unwrap()
+}
+
+fn too_deep(field: &'static str, depth: usize) -> ValidationErrors {
+	let errors = validation_errors! {
+		field => ("max_depth", "exceeds the maximum nesting depth")
+	};
+
+	(0..depth).fold(errors, |errors, _| validation_errors! { field => ("nested", errors) })
+}
+
+#[test]
+fn should_validate_boxed_recursive_fields() {
+	let valid = category("root", Some(category("child", Some(category("leaf", None)))));
+	assert!(valid.validate().is_ok());
+
+	let invalid = category("root", Some(category("child", Some(category("", None)))));
+	let result = invalid.validate();
+	assert_errors!(result, invalid, {
+		"parent" => ("nested", validation_errors! {
+			"parent" => ("nested", validation_errors! {
+				"name" => ("length", "length out of range")
+			})
+		})
+	});
+}
+
+#[test]
+fn should_validate_shared_recursive_fields() {
+	let invalid = SharedCategory {
+		name: "root".to_string(),
+		parent: Some(Arc::new(SharedCategory::default())),
+	};
+
+	let result = invalid.validate();
+	assert_errors!(result, invalid, {
+		"parent" => ("nested", validation_errors! {
+			"name" => ("length", "length out of range")
+		})
+	});
+}
+
+#[test]
+fn should_modificate_boxed_recursive_fields() {
+	let mut category = ModificateCategory {
+		name: " root ".to_string(),
+		parent: Some(Box::new(ModificateCategory {
+			name: " child ".to_string(),
+			parent: None,
+		})),
+	};
+
+	assert!(category.validate_and_modificate().is_ok());
+	assert_eq!(
+		category.parent.as_ref().map(|parent| parent.name.as_str()),
+		Some("child")
+	);
+}
+
+#[test]
+fn should_parse_boxed_recursive_payloads() {
+	let wrapper: CommentWrapper = serde_json::from_value(json!({
+		"text": " first ",
+		"reply": { "text": " second ", "reply": { "text": " third " } }
+	}))
+	.unwrap();
+
+	let comment = Comment::validate_and_parse(wrapper).expect("should be valid");
+	let reply = comment.reply.expect("should have a reply");
+
+	assert_eq!(comment.text, "first");
+	assert_eq!(reply.text, "second");
+	assert_eq!(reply.reply.map(|reply| reply.text), Some("third".to_string()));
+
+	let reversed = CommentWrapper::from(Comment {
+		text: "first".to_string(),
+		reply: Some(Box::new(Comment {
+			text: "second".to_string(),
+			reply: None,
+		})),
+	});
+	let reply = reversed.reply.expect("should have a reply");
+	assert_eq!(reply.text.as_deref(), Some("second"));
+
+	let wrapper: CommentWrapper = serde_json::from_value(json!({
+		"text": "first",
+		"reply": { "text": "   " }
+	}))
+	.unwrap();
+
+	let result = Comment::validate_and_parse(wrapper.clone());
+	assert_errors!(result, wrapper, {
+		"reply" => ("nested", validation_errors! {
+			"text" => ("length", "length out of range")
+		})
+	});
+}
+
+#[tokio::test]
+async fn should_parse_boxed_recursive_payloads_asynchronously() {
+	let wrapper: AsyncCommentWrapper = serde_json::from_value(json!({
+		"text": "first",
+		"reply": { "text": "second", "reply": { "text": "" } }
+	}))
+	.unwrap();
+
+	let result = AsyncComment::async_validate_and_parse(wrapper.clone()).await;
+	assert_errors!(result, wrapper, {
+		"reply" => ("nested", validation_errors! {
+			"reply" => ("nested", validation_errors! {
+				"text" => ("length", "length out of range")
+			})
+		})
+	});
+}
+
+#[tokio::test]
+async fn should_report_max_depth() {
+	assert!(chain(30).validate().is_ok());
+
+	let invalid = chain(40);
+	let result = invalid.validate();
+	assert_validation_errors(&result, &invalid, &too_deep("parent", 32));
+
+	let wrapper = reply_chain(40);
+	let result = AsyncComment::async_validate_and_parse(wrapper.clone()).await;
+	assert_validation_errors(&result, &wrapper, &too_deep("reply", 32));
+
+	ValidationSettings::set_max_depth(5);
+
+	let valid = chain(4).validate();
+	let invalid = chain(6);
+	let result = invalid.validate();
+	let wrapper = reply_chain(6);
+	let async_result = AsyncComment::async_validate_and_parse(wrapper.clone()).await;
+
+	ValidationSettings::set_max_depth(32);
+
+	assert!(valid.is_ok());
+	assert_validation_errors(&result, &invalid, &too_deep("parent", 5));
+	assert_validation_errors(&async_result, &wrapper, &too_deep("reply", 5));
+}
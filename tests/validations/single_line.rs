@@ -0,0 +1,46 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(single_line)]
+	pub a: String,
+	#[validate(single_line("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_single_line_strings() {
+	let cases = [
+		("one line", true),
+		("tab\tseparated", true),
+		("", true),
+		("a\nb", false),
+		("a\rb", false),
+		("a\u{2028}b", false),
+		("a\u{85}b", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("single_line", "must be a single line"),
+			});
+		}
+	}
+
+	test.a = "one line".to_string();
+	test.b = Some("a\nb".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
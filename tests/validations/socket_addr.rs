@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(socket_addr)]
+	pub a: String,
+	#[validate(socket_addr("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_socket_addrs() {
+	let cases = [
+		("127.0.0.1:8080", true),
+		("0.0.0.0:0", true),
+		("[::1]:443", true),
+		("[2001:db8::1]:65535", true),
+		("127.0.0.1", false),
+		("127.0.0.1:65536", false),
+		("::1:443", false),
+		("localhost:8080", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("socket_addr", "invalid socket address format"),
+			});
+		}
+	}
+
+	test.a = "127.0.0.1:8080".to_string();
+	test.b = Some("127.0.0.1".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
@@ -0,0 +1,52 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(timezone)]
+	pub a: String,
+	#[validate(timezone("custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_timezones() {
+	let cases = [
+		("America/Sao_Paulo", true),
+		("Europe/London", true),
+		("Asia/Kolkata", true),
+		("UTC", true),
+		("Etc/GMT+3", true),
+		("america/sao_paulo", false),
+		("Mars/Olympus_Mons", false),
+		("Sao Paulo", false),
+		("", false),
+	];
+
+	let mut test = Test::default();
+	for (case, is_valid) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		if *is_valid {
+			assert_validation!(result, test);
+		} else {
+			assert_errors!(result, test, {
+				"a" => ("timezone", "invalid timezone"),
+			});
+		}
+	}
+
+	test.a = "UTC".to_string();
+	test.b = Some("Europe/London".to_string());
+	let result = test.validate();
+	assert_validation!(result, test);
+
+	test.b = Some("Europe/Atlantis".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", "custom message"),
+	});
+}
@@ -0,0 +1,68 @@
+use serde::Deserialize;
+use validy::core::Validate;
+
+use validy::{assert_errors, assert_validation};
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct Test {
+	#[validate(unicode_scripts(allowed = ["Latin"]))]
+	pub a: String,
+	#[validate(unicode_scripts(["Latin", "Cyrl"], "custom message", "custom_code"))]
+	pub b: Option<String>,
+}
+
+#[test]
+fn should_validate_unicode_scripts() {
+	let cases = [
+		("José", None),
+		("user_123 !", None),
+		("cafe\u{301}", None),
+		("", None),
+		("Пётр", Some("Cyrillic")),
+		("Tokyo 東京", Some("Han")),
+		("Ελένη", Some("Greek")),
+	];
+
+	let mut test = Test::default();
+	for (case, script) in cases.iter() {
+		test.a = case.to_string();
+		let result = test.validate();
+
+		match script {
+			None => assert_validation!(result, test),
+			Some(script) => {
+				assert_errors!(result, test, {
+					"a" => ("unicode_scripts", ("has characters from disallowed scripts", [("script", *script)])),
+				});
+			}
+		}
+	}
+
+	test.a = "José".to_string();
+	test.b = Some("Пётр Smith".to_string());
+	let result = test.validate();
+	assert_validation!(result, test);
+
+	test.b = Some("Ελένη".to_string());
+	let result = test.validate();
+	assert_errors!(result, test, {
+		"b" => ("custom_code", ("custom message", [("script", "Greek")])),
+	});
+}
+
+const UNKNOWN_SCRIPT: &str = "Klingon";
+
+#[derive(Debug, Validate, PartialEq)]
+struct UnknownScriptTest {
+	#[validate(unicode_scripts(allowed = ["Latin", UNKNOWN_SCRIPT]))]
+	pub a: String,
+}
+
+#[test]
+fn should_reject_unknown_scripts() {
+	let test = UnknownScriptTest { a: "José".to_string() };
+
+	assert_errors!(test.validate(), test, {
+		"a" => ("bad-script", ("can't find a script by provided name", [("script", "Klingon")])),
+	});
+}
@@ -1,5 +1,6 @@
 use serde::Deserialize;
 use validy::core::Validate;
+use validy::functions::validation::url::{UrlPolicy, validate_url, validate_url_with_policy};
 
 use validy::{assert_errors, assert_validation};
 
@@ -21,18 +22,17 @@ struct Test {
 fn should_validate_urls() {
 	let cases = [
 		("http://site-com-hifen.com", true),
-		("www.teste.org", true),
-		("google.com", true),
-		("sub.dominio.net", true),
-		("site.com/caminho/para/rota", true),
-		("site.com?q=busca&filtros=ok", true),
-		("site.com.br", true),
+		("https://www.teste.org", true),
+		("https://sub.dominio.net/caminho/para/rota", true),
+		("https://site.com?q=busca&filtros=ok", true),
+		("ftp://site.com.br", true),
+		("mailto:user@site.com", true),
+		("google.com", false),
+		("www.teste.org", false),
 		("google", false),
 		("http://", false),
-		("site.c", false),
-		("site.abcdefg", false),
-		("http://.com", false),
-		("site.123", false),
+		("http://exa mple.com", false),
+		("https://[::1", false),
 		("https://www.google.com", true),
 	];
 
@@ -91,3 +91,125 @@ fn should_validate_urls() {
 		}
 	}
 }
+
+#[derive(Debug, Default, Deserialize, Validate, PartialEq)]
+struct PolicyTest {
+	#[validate(url(schemes = ["https", "mailto"], require_host = true, max_length = 40))]
+	pub a: String,
+	#[validate(url(allowed_hosts = ["example.com", "*.example.com"], blocked_hosts = ["internal.example.com"]))]
+	pub b: String,
+	#[validate(url(deny_private_ips = true))]
+	pub c: String,
+}
+
+#[test]
+fn should_validate_url_policies() {
+	let cases = [
+		("https://example.com", true),
+		("HTTPS://example.com/path", true),
+		("http://example.com", false),
+		("mailto:user@example.com", false),
+		("https://example.com/a-very-long-path-that-goes-on", false),
+	];
+
+	for (case, is_valid) in cases.iter() {
+		let test = PolicyTest {
+			a: case.to_string(),
+			b: "https://example.com".to_string(),
+			c: "https://example.com".to_string(),
+		};
+
+		assert_eq!(test.validate().is_ok(), *is_valid, "{case}");
+	}
+
+	let cases = [
+		("https://example.com", true),
+		("https://api.example.com", true),
+		("https://a.b.example.com.", true),
+		("https://internal.example.com", false),
+		("https://evil-example.com", false),
+		("https://example.com.evil.org", false),
+		("mailto:user@example.com", false),
+	];
+
+	for (case, is_valid) in cases.iter() {
+		let test = PolicyTest {
+			a: "https://example.com".to_string(),
+			b: case.to_string(),
+			c: "https://example.com".to_string(),
+		};
+
+		assert_eq!(test.validate().is_ok(), *is_valid, "{case}");
+	}
+
+	let cases = [
+		("https://example.com", true),
+		("https://8.8.8.8", true),
+		("https://[2001:db8::1]", true),
+		("http://127.0.0.1:8080", false),
+		("http://2130706433", false),
+		("http://0x7f.1", false),
+		("http://10.0.0.1", false),
+		("http://172.16.5.4", false),
+		("http://192.168.1.1", false),
+		("http://169.254.169.254/latest/meta-data", false),
+		("http://100.100.100.200", false),
+		("http://0.0.0.0", false),
+		("http://[::1]", false),
+		("http://[::ffff:127.0.0.1]", false),
+		("http://[fd00:ec2::254]", false),
+		("http://[fe80::1]", false),
+		("http://localhost:3000", false),
+		("http://api.localhost", false),
+		("http://metadata.google.internal", false),
+	];
+
+	for (case, is_valid) in cases.iter() {
+		let test = PolicyTest {
+			a: "https://example.com".to_string(),
+			b: "https://example.com".to_string(),
+			c: case.to_string(),
+		};
+
+		let result = test.validate();
+		assert_eq!(result.is_ok(), *is_valid, "{case}");
+		if !*is_valid {
+			assert_errors!(result, test, {
+				"c" => ("url", "invalid url format"),
+			});
+		}
+	}
+}
+
+#[test]
+fn should_validate_urls_with_functions() {
+	assert!(validate_url("https://example.com", "a", "url", "invalid url format").is_ok());
+	assert!(validate_url("example", "a", "url", "invalid url format").is_err());
+
+	let policy = UrlPolicy {
+		schemes: &["https"],
+		..Default::default()
+	};
+
+	assert!(validate_url_with_policy("https://example.com", &policy, "a", "url", "invalid url format").is_ok());
+	assert!(validate_url_with_policy("http://example.com", &policy, "a", "url", "invalid url format").is_err());
+
+	let policy = UrlPolicy {
+		allowed_hosts: &["::1", "[2001:db8::1]", "127.0.0.1"],
+		blocked_hosts: &["0:0:0:0:0:0:0:1"],
+		..Default::default()
+	};
+
+	let cases = [
+		("http://[2001:db8::1]:8080", true),
+		("http://[2001:DB8:0::1]", true),
+		("http://127.0.0.1", true),
+		("http://[::1]", false),
+		("http://[2001:db8::2]", false),
+	];
+
+	for (case, is_valid) in cases.iter() {
+		let result = validate_url_with_policy(case, &policy, "a", "url", "invalid url format");
+		assert_eq!(result.is_ok(), *is_valid, "{case}");
+	}
+}
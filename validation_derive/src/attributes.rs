@@ -10,6 +10,10 @@ pub struct ValidationAttributes {
 	pub context: Option<Type>,
 	pub axum: bool,
 	pub multipart: bool,
+	pub builder: bool,
+	pub reversible: bool,
+	pub partial: bool,
+	pub deny_unknown_fields: bool,
 	pub failure_mode: Option<Expr>,
 }
 
@@ -22,6 +26,10 @@ impl ArgParser for ValidationAttributes {
 		"axum",
 		"multipart",
 		"failure_mode",
+		"builder",
+		"reversible",
+		"partial",
+		"deny_unknown_fields",
 	];
 
 	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
@@ -52,6 +60,22 @@ impl ArgParser for ValidationAttributes {
 				let bool_lit: LitBool = input.parse()?;
 				self.multipart = bool_lit.value();
 			}
+			"builder" => {
+				let bool_lit: LitBool = input.parse()?;
+				self.builder = bool_lit.value();
+			}
+			"reversible" => {
+				let bool_lit: LitBool = input.parse()?;
+				self.reversible = bool_lit.value();
+			}
+			"partial" => {
+				let bool_lit: LitBool = input.parse()?;
+				self.partial = bool_lit.value();
+			}
+			"deny_unknown_fields" => {
+				let bool_lit: LitBool = input.parse()?;
+				self.deny_unknown_fields = bool_lit.value();
+			}
 			_ => return Err(Error::new(input.span(), "unknown arg")),
 		}
 
@@ -67,6 +91,10 @@ impl ArgParser for ValidationAttributes {
 				"payload" => self.payload = true,
 				"axum" => self.axum = true,
 				"multipart" => self.multipart = true,
+				"builder" => self.builder = true,
+				"reversible" => self.reversible = true,
+				"partial" => self.partial = true,
+				"deny_unknown_fields" => self.deny_unknown_fields = true,
 				_ => return Err(Error::new(input.span(), "unknown arg")),
 			}
 
@@ -3,7 +3,7 @@ use std::cell::RefCell;
 use proc_macro_error::emit_error;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Field, Fields, Index, meta::ParseNestedMeta, spanned::Spanned};
+use syn::{Attribute, Data, DeriveInput, Field, Fields, Index, meta::ParseNestedMeta, spanned::Spanned};
 
 use crate::{
 	ImportsSet,
@@ -11,6 +11,11 @@ use crate::{
 	factories::core::AbstractValidationFactory,
 	fields::FieldAttributes,
 	primitives::{
+		chars::{
+			alpha::create_alpha, alphanumeric::create_alphanumeric, ascii::create_ascii, charset::create_charset,
+			no_control_chars::create_no_control_chars, no_zero_width::create_no_zero_width, numeric::create_numeric,
+			printable::create_printable, single_line::create_single_line,
+		},
 		collections::{allowlist::create_allowlist, blocklist::create_blocklist},
 		customs::{
 			modification::{
@@ -28,38 +33,64 @@ use crate::{
 				custom::create_custom, custom_with_context::create_custom_with_context,
 			},
 		},
+		domains::{domain::create_domain, hostname::create_hostname, parse_domain::create_domain_parse},
 		field_datas::{
 			field_content_type::create_field_content_type, field_file_name::create_field_file_name,
 			field_name::create_field_name,
 		},
 		format::{
-			camel_case::create_camel_case, capitalize::create_capitalize, kebab_case::create_kebab_case,
-			lower_camel_case::create_lower_camel_case, lowercase::create_lowercase,
+			camel_case::create_camel_case, capitalize::create_capitalize, digits_only::create_digits_only,
+			e164::create_e164, kebab_case::create_kebab_case, lower_camel_case::create_lower_camel_case,
+			lowercase::create_lowercase, normalize_email::create_normalize_email, punycode::create_punycode,
 			shouty_kebab_case::create_shouty_kebab_case, shouty_snake_case::create_shouty_snake_case,
 			snake_case::create_snake_case, train_case::create_train_case, trim::create_trim, trim_end::create_trim_end,
-			trim_start::create_trim_start, uppercase::create_uppercase,
+			trim_start::create_trim_start, truncate::create_truncate, uppercase::create_uppercase,
 		},
 		inlines::{
 			inline_modification::create_inline_modification, inline_parse::create_inline_parse,
 			inline_validation::create_inline_validation,
 		},
 		ips::{
-			ip::create_ip, ipv4::create_ipv4, ipv6::create_ipv6, parse_ip::create_ip_parse,
-			parse_ipv4::create_ipv4_parse, parse_ipv6::create_ipv6_parse,
+			cidr::create_cidr, ip::create_ip, ipv4::create_ipv4, ipv6::create_ipv6, parse_cidr::create_cidr_parse,
+			parse_ip::create_ip_parse, parse_ipv4::create_ipv4_parse, parse_ipv6::create_ipv6_parse,
+			parse_socket_addr::create_socket_addr_parse, port::create_port, socket_addr::create_socket_addr,
+		},
+		isos::{
+			country::create_country, currency::create_currency, language_tag::create_language_tag,
+			parse_country::create_country_parse, parse_currency::create_currency_parse,
+			parse_language_tag::create_language_tag_parse, parse_timezone::create_timezone_parse,
+			timezone::create_timezone,
 		},
 		option::required::create_required,
+		passwords::password::create_password,
 		patterns::{
-			contains::create_contains, email::create_email, pattern::create_pattern, prefix::create_prefix,
-			suffix::create_suffix, url::create_url,
+			contains::create_contains, email::create_email, parse_email::create_email_parse,
+			parse_url::create_url_parse, pattern::create_pattern, prefix::create_prefix, suffix::create_suffix,
+			url::create_url,
 		},
+		payments::{bic::create_bic, credit_card::create_credit_card, iban::create_iban, luhn::create_luhn},
+		phones::{parse_phone::create_phone_parse, phone::create_phone},
 		ranges::{length::create_length, range::create_range},
-		specials::{for_each::create_for_each, from_type::create_from_type, ignore::create_ignore},
+		specials::{
+			default::create_default,
+			flatten::create_flatten,
+			for_each::create_for_each,
+			for_each_entry::{create_for_each_key, create_for_each_value},
+			from_type::create_from_type,
+			ignore::create_ignore,
+			nested::create_nested,
+			newtype::create_newtype,
+		},
 		time::{
 			after_now::create_after_now, after_today::create_after_today, before_now::create_before_now,
 			before_today::create_before_today, default_time::create_time, naive_date::create_naive_date,
 			naive_time::create_naive_time, now::create_now, parse_naive_date::create_naive_date_parse,
 			parse_naive_time::create_naive_time_parse, parse_time::create_time_parse, today::create_today,
 		},
+		unicodes::{
+			confusable_safe::create_confusable_safe, no_mixed_script::create_no_mixed_script,
+			unicode_scripts::create_unicode_scripts,
+		},
 		uuids::{parse_uuid::create_uuid_parse, uuid::create_uuid},
 	},
 };
@@ -96,40 +127,7 @@ pub fn get_fields_attributes(
 			}
 		};
 
-		for attr in &field.attrs {
-			if attr.path().is_ident("validate")
-				&& let Err(error) = attr.parse_nested_meta(|meta| {
-					let validation =
-						get_validate_by_attr_macro(factory, meta, &mut field_attributes, attributes, imports);
-					field_attributes.add_operation(validation.clone());
-					Ok(())
-				}) {
-				emit_error!(error.span(), error.to_string());
-			} else if attr.path().is_ident("modificate")
-				&& let Err(error) = attr.parse_nested_meta(|meta| {
-					let operation =
-						get_modificate_by_attr_macro(factory, meta, &mut field_attributes, attributes, imports);
-					field_attributes.add_operation(operation.clone());
-					Ok(())
-				}) {
-				emit_error!(error.span(), error.to_string());
-			} else if attr.path().is_ident("parse")
-				&& let Err(error) = attr.parse_nested_meta(|meta| {
-					let operation = get_parse_by_attr_macro(factory, meta, &mut field_attributes, attributes, imports);
-					field_attributes.add_operation(operation.clone());
-					Ok(())
-				}) {
-				emit_error!(error.span(), error.to_string());
-			} else if attr.path().is_ident("special")
-				&& let Err(error) = attr.parse_nested_meta(|meta| {
-					let operation =
-						get_special_by_attr_macro(factory, meta, &mut field_attributes, attributes, imports);
-					field_attributes.add_operation(operation.clone());
-					Ok(())
-				}) {
-				emit_error!(error.span(), error.to_string());
-			};
-		}
+		apply_attributes(&field.attrs, factory, &mut field_attributes, attributes, imports);
 
 		fields_attributes.push(field_attributes);
 	});
@@ -137,6 +135,46 @@ pub fn get_fields_attributes(
 	fields_attributes
 }
 
+pub fn apply_attributes(
+	attrs: &[Attribute],
+	factory: &dyn AbstractValidationFactory,
+	field_attributes: &mut FieldAttributes,
+	attributes: &ValidationAttributes,
+	imports: &RefCell<ImportsSet>,
+) {
+	for attr in attrs {
+		if attr.path().is_ident("validate")
+			&& let Err(error) = attr.parse_nested_meta(|meta| {
+				let validation = get_validate_by_attr_macro(factory, meta, field_attributes, attributes, imports);
+				field_attributes.add_operation(validation.clone());
+				Ok(())
+			}) {
+			emit_error!(error.span(), error.to_string());
+		} else if attr.path().is_ident("modificate")
+			&& let Err(error) = attr.parse_nested_meta(|meta| {
+				let operation = get_modificate_by_attr_macro(factory, meta, field_attributes, attributes, imports);
+				field_attributes.add_operation(operation.clone());
+				Ok(())
+			}) {
+			emit_error!(error.span(), error.to_string());
+		} else if attr.path().is_ident("parse")
+			&& let Err(error) = attr.parse_nested_meta(|meta| {
+				let operation = get_parse_by_attr_macro(factory, meta, field_attributes, attributes, imports);
+				field_attributes.add_operation(operation.clone());
+				Ok(())
+			}) {
+			emit_error!(error.span(), error.to_string());
+		} else if attr.path().is_ident("special")
+			&& let Err(error) = attr.parse_nested_meta(|meta| {
+				let operation = get_special_by_attr_macro(factory, meta, field_attributes, attributes, imports);
+				field_attributes.add_operation(operation.clone());
+				Ok(())
+			}) {
+			emit_error!(error.span(), error.to_string());
+		};
+	}
+}
+
 pub fn get_validate_by_attr_macro(
 	_factory: &dyn AbstractValidationFactory,
 	meta: ParseNestedMeta<'_>,
@@ -156,10 +194,37 @@ pub fn get_validate_by_attr_macro(
 		m if m.path.is_ident("ip") => create_ip(m.input, field, imports),
 		m if m.path.is_ident("ipv4") => create_ipv4(m.input, field, imports),
 		m if m.path.is_ident("ipv6") => create_ipv6(m.input, field, imports),
+		m if m.path.is_ident("cidr") => create_cidr(m.input, field, imports),
+		m if m.path.is_ident("socket_addr") => create_socket_addr(m.input, field, imports),
+		m if m.path.is_ident("port") => create_port(m.input, field, imports),
 		m if m.path.is_ident("pattern") => create_pattern(m.input, field, imports),
 		m if m.path.is_ident("uuid") => create_uuid(m.input, field, imports),
 		m if m.path.is_ident("url") => create_url(m.input, field, imports),
 		m if m.path.is_ident("email") => create_email(m.input, field, imports),
+		m if m.path.is_ident("hostname") => create_hostname(m.input, field, imports),
+		m if m.path.is_ident("domain") => create_domain(m.input, field, imports),
+		m if m.path.is_ident("credit_card") => create_credit_card(m.input, field, imports),
+		m if m.path.is_ident("luhn") => create_luhn(m.input, field, imports),
+		m if m.path.is_ident("iban") => create_iban(m.input, field, imports),
+		m if m.path.is_ident("bic") => create_bic(m.input, field, imports),
+		m if m.path.is_ident("phone") => create_phone(m.input, field, imports),
+		m if m.path.is_ident("password") => create_password(m.input, field, imports),
+		m if m.path.is_ident("country") => create_country(m.input, field, imports),
+		m if m.path.is_ident("currency") => create_currency(m.input, field, imports),
+		m if m.path.is_ident("language_tag") => create_language_tag(m.input, field, imports),
+		m if m.path.is_ident("timezone") => create_timezone(m.input, field, imports),
+		m if m.path.is_ident("ascii") => create_ascii(m.input, field, imports),
+		m if m.path.is_ident("alphanumeric") => create_alphanumeric(m.input, field, imports),
+		m if m.path.is_ident("alpha") => create_alpha(m.input, field, imports),
+		m if m.path.is_ident("numeric") => create_numeric(m.input, field, imports),
+		m if m.path.is_ident("printable") => create_printable(m.input, field, imports),
+		m if m.path.is_ident("no_control_chars") => create_no_control_chars(m.input, field, imports),
+		m if m.path.is_ident("no_zero_width") => create_no_zero_width(m.input, field, imports),
+		m if m.path.is_ident("single_line") => create_single_line(m.input, field, imports),
+		m if m.path.is_ident("charset") => create_charset(m.input, field, imports),
+		m if m.path.is_ident("unicode_scripts") => create_unicode_scripts(m.input, field, imports),
+		m if m.path.is_ident("no_mixed_script") => create_no_mixed_script(m.input, field, imports),
+		m if m.path.is_ident("confusable_safe") => create_confusable_safe(m.input, field, imports),
 		m if m.path.is_ident("prefix") => create_prefix(m.input, field, imports),
 		m if m.path.is_ident("suffix") => create_suffix(m.input, field, imports),
 		m if m.path.is_ident("range") => create_range(m.input, field, imports),
@@ -207,11 +272,16 @@ pub fn get_modificate_by_attr_macro(
 		m if m.path.is_ident("async_custom_with_context") => {
 			create_async_custom_with_context_modification(m.input, field, attributes)
 		}
-		m if m.path.is_ident("trim") => create_trim(field),
-		m if m.path.is_ident("trim_end") => create_trim_end(field),
-		m if m.path.is_ident("trim_start") => create_trim_start(field),
-		m if m.path.is_ident("uppercase") => create_uppercase(field),
-		m if m.path.is_ident("lowercase") => create_lowercase(field),
+		m if m.path.is_ident("trim") => create_trim(field, imports),
+		m if m.path.is_ident("trim_end") => create_trim_end(field, imports),
+		m if m.path.is_ident("trim_start") => create_trim_start(field, imports),
+		m if m.path.is_ident("truncate") => create_truncate(m.input, field, imports),
+		m if m.path.is_ident("uppercase") => create_uppercase(field, imports),
+		m if m.path.is_ident("lowercase") => create_lowercase(field, imports),
+		m if m.path.is_ident("normalize_email") => create_normalize_email(m.input, field, imports),
+		m if m.path.is_ident("punycode") => create_punycode(field, imports),
+		m if m.path.is_ident("digits_only") => create_digits_only(field, imports),
+		m if m.path.is_ident("e164") => create_e164(m.input, field, imports),
 		m if m.path.is_ident("capitalize") => create_capitalize(field, imports),
 		m if m.path.is_ident("camel_case") => create_camel_case(field, imports),
 		m if m.path.is_ident("lower_camel_case") => create_lower_camel_case(field, imports),
@@ -250,7 +320,17 @@ pub fn get_parse_by_attr_macro(
 		m if m.path.is_ident("ip") => create_ip_parse(m.input, field, imports),
 		m if m.path.is_ident("ipv4") => create_ipv4_parse(m.input, field, imports),
 		m if m.path.is_ident("ipv6") => create_ipv6_parse(m.input, field, imports),
+		m if m.path.is_ident("cidr") => create_cidr_parse(m.input, field, imports),
+		m if m.path.is_ident("socket_addr") => create_socket_addr_parse(m.input, field, imports),
 		m if m.path.is_ident("uuid") => create_uuid_parse(m.input, field, imports),
+		m if m.path.is_ident("url") => create_url_parse(m.input, field, imports),
+		m if m.path.is_ident("email") => create_email_parse(m.input, field, imports),
+		m if m.path.is_ident("domain") => create_domain_parse(m.input, field, imports),
+		m if m.path.is_ident("phone") => create_phone_parse(m.input, field, imports),
+		m if m.path.is_ident("country") => create_country_parse(m.input, field, imports),
+		m if m.path.is_ident("currency") => create_currency_parse(m.input, field, imports),
+		m if m.path.is_ident("language_tag") => create_language_tag_parse(m.input, field, imports),
+		m if m.path.is_ident("timezone") => create_timezone_parse(m.input, field, imports),
 		m if m.path.is_ident("time") => create_time_parse(m.input, field, imports),
 		m if m.path.is_ident("naive_time") => create_naive_time_parse(m.input, field, imports),
 		m if m.path.is_ident("naive_date") => create_naive_date_parse(m.input, field, imports),
@@ -270,10 +350,15 @@ pub fn get_special_by_attr_macro(
 	imports: &RefCell<ImportsSet>,
 ) -> TokenStream {
 	match meta {
-		m if m.path.is_ident("nested") => factory.create_nested(m.input, field),
+		m if m.path.is_ident("nested") => create_nested(factory, m, field, attributes, imports),
+		m if m.path.is_ident("flatten") => create_flatten(factory, m.input, field, attributes),
 		m if m.path.is_ident("from_type") => create_from_type(m.input, field, attributes),
+		m if m.path.is_ident("newtype") => create_newtype(m.input, field, attributes),
 		m if m.path.is_ident("for_each") => create_for_each(factory, m, field, attributes, imports),
+		m if m.path.is_ident("for_each_key") => create_for_each_key(factory, m, field, attributes, imports),
+		m if m.path.is_ident("for_each_value") => create_for_each_value(factory, m, field, attributes, imports),
 		m if m.path.is_ident("ignore") => create_ignore(m.input, field, attributes),
+		m if m.path.is_ident("default") => create_default(m.input, field, attributes),
 		_ => {
 			emit_error!(meta.input.span(), "unknown value");
 			quote! {}
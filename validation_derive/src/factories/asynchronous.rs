@@ -4,7 +4,10 @@ use crate::{
 	ImportsSet, Output,
 	attributes::ValidationAttributes,
 	factories::{
-		boilerplates::{defaults::get_async_default_factory_boilerplates, failure_mode::get_failure_mode_boilerplate},
+		boilerplates::{
+			defaults::get_async_default_factory_boilerplates, failure_mode::get_failure_mode_boilerplate,
+			nested_errors::get_nested_errors_boilerplate,
+		},
 		core::AbstractValidationFactory,
 		extensions::defaults::get_async_default_extensions,
 		others::defaults::DefaultsCodeFactory,
@@ -12,17 +15,18 @@ use crate::{
 	fields::FieldAttributes,
 	imports::Import,
 	primitives::specials::nested::get_nested,
+	types::StructName,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, parse::ParseStream};
+use syn::parse::ParseStream;
 
 pub struct AsyncValidationFactory<'a> {
-	struct_name: &'a Ident,
+	struct_name: &'a StructName,
 }
 
 impl<'a> AsyncValidationFactory<'a> {
-	pub fn new(struct_name: &'a Ident) -> Self {
+	pub fn new(struct_name: &'a StructName) -> Self {
 		Self { struct_name }
 	}
 }
@@ -34,6 +38,7 @@ impl<'a> AbstractValidationFactory for AsyncValidationFactory<'a> {
 		attributes: &ValidationAttributes,
 		imports: &RefCell<ImportsSet>,
 	) -> Output {
+		let impl_generics = self.struct_name.impl_generics();
 		imports.borrow_mut().add(Import::ValidyCore);
 		imports.borrow_mut().add(Import::ValidySettings);
 		imports.borrow_mut().add(Import::ValidyHelpers);
@@ -56,7 +61,7 @@ impl<'a> AbstractValidationFactory for AsyncValidationFactory<'a> {
 				#imports
 
   			#[async_trait]
-  		  impl AsyncValidate for #struct_name {
+  		  impl #impl_generics AsyncValidate for #struct_name {
   			  async fn async_validate(&self) -> Result<(), ValidationErrors> {
   					let mut errors = ValidationErrors::new();
             let failure_mode = #failure_mode;
@@ -83,23 +88,15 @@ impl<'a> AbstractValidationFactory for AsyncValidationFactory<'a> {
 	fn create_nested(&self, input: ParseStream, field: &mut FieldAttributes) -> TokenStream {
 		let reference = field.get_reference();
 		let field_name = field.get_name();
-		let (field_type, _, nested_code) = get_nested(input);
+		let (field_type, _, nested_code) = get_nested(input, field);
+		let nested_errors = get_nested_errors_boilerplate(field, &nested_code);
 
 		if field.is_ref() {
 			field.set_is_ref(true);
 			#[rustfmt::skip]
 			let result = quote! {
-			  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = <#field_type as AsyncValidate>::async_validate(#reference).await {
-					let error = NestedValidationError::from_with_code(
-						e,
-						#field_name,
-						#nested_code
-					);
-
-				  append_error(&mut errors, error.into(), failure_mode, #field_name);
-          if should_fail_fast(&errors, failure_mode, #field_name) {
-       			return Err(errors);
-       	  }
+			  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = async_nested_scope(<#field_type as AsyncValidate>::async_validate(#reference)).await {
+					#nested_errors
 			  }
 			};
 
@@ -109,17 +106,8 @@ impl<'a> AbstractValidationFactory for AsyncValidationFactory<'a> {
 			#[rustfmt::skip]
 			let result = quote! {
 			  let _ref = &#reference;
-			  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = <#field_type as AsyncValidate>::async_validate(_ref).await {
-					let error = NestedValidationError::from_with_code(
-						e,
-						#field_name,
-						#nested_code
-					);
-
-				  append_error(&mut errors, error.into(), failure_mode, #field_name);
-          if should_fail_fast(&errors, failure_mode, #field_name) {
-       			return Err(errors);
-       	  }
+			  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = async_nested_scope(<#field_type as AsyncValidate>::async_validate(_ref)).await {
+					#nested_errors
 			  }
 			};
 
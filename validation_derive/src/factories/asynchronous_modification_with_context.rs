@@ -7,6 +7,7 @@ use crate::{
 		boilerplates::{
 			failure_mode::get_failure_mode_boilerplate,
 			modifications::get_async_modification_with_context_factory_boilerplates,
+			nested_errors::get_nested_errors_boilerplate,
 		},
 		core::AbstractValidationFactory,
 		extensions::modifications::get_async_modification_with_context_extensions,
@@ -15,18 +16,19 @@ use crate::{
 	fields::FieldAttributes,
 	imports::Import,
 	primitives::specials::nested::get_nested,
+	types::StructName,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Type, parse::ParseStream};
+use syn::{Type, parse::ParseStream};
 
 pub struct AsyncModificationWithContextFactory<'a> {
-	struct_name: &'a Ident,
+	struct_name: &'a StructName,
 	context_type: &'a Type,
 }
 
 impl<'a> AsyncModificationWithContextFactory<'a> {
-	pub fn new(struct_name: &'a Ident, context_type: &'a Type) -> Self {
+	pub fn new(struct_name: &'a StructName, context_type: &'a Type) -> Self {
 		Self {
 			struct_name,
 			context_type,
@@ -41,6 +43,7 @@ impl<'a> AbstractValidationFactory for AsyncModificationWithContextFactory<'a> {
 		attributes: &ValidationAttributes,
 		imports: &RefCell<ImportsSet>,
 	) -> Output {
+		let impl_generics = self.struct_name.impl_generics();
 		imports.borrow_mut().add(Import::ValidyCore);
 		imports.borrow_mut().add(Import::ValidySettings);
 		imports.borrow_mut().add(Import::ValidyHelpers);
@@ -65,7 +68,7 @@ impl<'a> AbstractValidationFactory for AsyncModificationWithContextFactory<'a> {
 				#imports
 
   			#[async_trait]
-  		  impl AsyncValidateAndModificateWithContext<#context_type> for #struct_name {
+  		  impl #impl_generics AsyncValidateAndModificateWithContext<#context_type> for #struct_name {
   			  async fn async_validate_and_modificate_with_context(&mut self, context: &#context_type) -> Result<(), ValidationErrors> {
      				let mut errors = ValidationErrors::new();
             let failure_mode = #failure_mode;
@@ -81,7 +84,7 @@ impl<'a> AbstractValidationFactory for AsyncModificationWithContextFactory<'a> {
   		  }
 
    			#[async_trait]
-   		  impl SpecificAsyncValidateAndModificateWithContext for #struct_name {
+   		  impl #impl_generics SpecificAsyncValidateAndModificateWithContext for #struct_name {
           type Context = #context_type;
    			  async fn specific_async_validate_and_modificate_with_context(&mut self, context: &#context_type) -> Result<(), ValidationErrors> {
             <#struct_name as AsyncValidateAndModificateWithContext<#context_type>>::async_validate_and_modificate_with_context(self, context).await
@@ -100,24 +103,16 @@ impl<'a> AbstractValidationFactory for AsyncModificationWithContextFactory<'a> {
 	fn create_nested(&self, input: ParseStream, field: &mut FieldAttributes) -> TokenStream {
 		let reference = field.get_reference();
 		let field_name = field.get_name();
-		let (field_type, _, nested_code) = get_nested(input);
+		let (field_type, _, nested_code) = get_nested(input, field);
+		let nested_errors = get_nested_errors_boilerplate(field, &nested_code);
 		let context_type = self.context_type;
 
 		if field.is_ref() {
 			field.set_is_ref(true);
 			#[rustfmt::skip]
   		let result = quote! {
-  		  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = <#field_type as AsyncValidateAndModificateWithContext<#context_type>>::async_validate_and_modificate_with_context(#reference, context).await {
-  				let error = NestedValidationError::from_with_code(
-  					e,
-  					#field_name,
-            #nested_code
-  				);
-
-  			  append_error(&mut errors, error.into(), failure_mode, #field_name);
-          if should_fail_fast(&errors, failure_mode, #field_name) {
-       			return Err(errors);
-       	  }
+  		  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = async_nested_scope(<#field_type as AsyncValidateAndModificateWithContext<#context_type>>::async_validate_and_modificate_with_context(#reference, context)).await {
+  				#nested_errors
   		  }
   		};
 
@@ -127,17 +122,8 @@ impl<'a> AbstractValidationFactory for AsyncModificationWithContextFactory<'a> {
 			#[rustfmt::skip]
   		let result = quote! {
   		  let _ref = &mut #reference;
-  		  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = <#field_type as AsyncValidateAndModificateWithContext<#context_type>>::async_validate_and_modificate_with_context(_ref, context).await {
-  				let error = NestedValidationError::from_with_code(
-  					e,
-  					#field_name,
-            #nested_code
-  				);
-
-  			  append_error(&mut errors, error.into(), failure_mode, #field_name);
-          if should_fail_fast(&errors, failure_mode, #field_name) {
-       			return Err(errors);
-       	  }
+  		  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = async_nested_scope(<#field_type as AsyncValidateAndModificateWithContext<#context_type>>::async_validate_and_modificate_with_context(_ref, context)).await {
+  				#nested_errors
   		  }
   		};
 
@@ -4,28 +4,34 @@ use crate::{
 	ImportsSet, Output,
 	attributes::ValidationAttributes,
 	factories::{
-		boilerplates::failure_mode::get_failure_mode_boilerplate,
+		boilerplates::{
+			failure_mode::get_failure_mode_boilerplate, nested_errors::get_nested_errors_boilerplate,
+			unknown_fields::get_unknown_fields_boilerplate,
+		},
 		core::AbstractValidationFactory,
 		extensions::payloads::get_async_payload_with_context_extensions,
-		others::{payloads::PayloadsCodeFactory, wrappers::WrapperFactory},
+		others::{
+			builders::BuilderFactory, patches::PatchFactory, payloads::PayloadsCodeFactory, reverses::ReverseFactory,
+			wrappers::WrapperFactory,
+		},
 	},
 	fields::FieldAttributes,
-	imports::Import,
+	imports::{Import, import_validy_core},
 	primitives::specials::nested::get_nested,
+	types::StructName,
 };
-use proc_macro_error::emit_error;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{DeriveInput, Ident, Type, parse::ParseStream};
+use syn::{DeriveInput, Type, parse::ParseStream, parse_quote};
 
 pub struct AsyncPayloadWithContextFactory<'a> {
-	struct_name: &'a Ident,
+	struct_name: &'a StructName,
 	context_type: &'a Type,
 	wrapper_factory: WrapperFactory,
 }
 
 impl<'a> AsyncPayloadWithContextFactory<'a> {
-	pub fn new(struct_name: &'a Ident, context_type: &'a Type) -> Self {
+	pub fn new(struct_name: &'a StructName, context_type: &'a Type) -> Self {
 		Self {
 			struct_name,
 			context_type,
@@ -45,6 +51,7 @@ impl<'a> AbstractValidationFactory for AsyncPayloadWithContextFactory<'a> {
 		attributes: &ValidationAttributes,
 		imports: &RefCell<ImportsSet>,
 	) -> Output {
+		let impl_generics = self.struct_name.impl_generics();
 		imports.borrow_mut().add(Import::ValidyCore);
 		imports.borrow_mut().add(Import::ValidySettings);
 		imports.borrow_mut().add(Import::ValidyHelpers);
@@ -54,6 +61,11 @@ impl<'a> AbstractValidationFactory for AsyncPayloadWithContextFactory<'a> {
 		let context_type = self.context_type;
 
 		let (wrapper_struct, wrapper_ident) = self.wrapper_factory.create(struct_name, &fields);
+		let (builder_struct, builder_impls) =
+			BuilderFactory::new(struct_name, &wrapper_ident).create(&fields, attributes);
+		let reverse = ReverseFactory::new(struct_name, &wrapper_ident).create(&fields, attributes);
+		let (patch_struct, patch_ident) = self.wrapper_factory.create_patch(struct_name, &fields, attributes);
+		let patch = PatchFactory::new(struct_name, &patch_ident).create(fields.clone(), attributes);
 		let mut code_factory = PayloadsCodeFactory(&mut fields);
 
 		let extensions = get_async_payload_with_context_extensions(
@@ -64,25 +76,33 @@ impl<'a> AbstractValidationFactory for AsyncPayloadWithContextFactory<'a> {
 			imports,
 		);
 
+		let siblings = code_factory.siblings();
 		let operations = code_factory.operations();
 		let commit = code_factory.commit();
 		let imports = imports.borrow().create();
 
 		let failure_mode = get_failure_mode_boilerplate(attributes);
+		let unknown_fields = get_unknown_fields_boilerplate(attributes, quote! { wrapper.unknown_fields });
 
 		#[rustfmt::skip]
 		let result = quote! {
 		  #wrapper_struct
+		  #builder_struct
+		  #patch_struct
 
 		  const _: () = {
 				#imports
 
   			#[async_trait]
-  			impl AsyncValidateAndParseWithContext<#wrapper_ident, #context_type> for #struct_name {
+  			impl #impl_generics AsyncValidateAndParseWithContext<#wrapper_ident, #context_type> for #struct_name {
          	async fn async_validate_and_parse_with_context(mut wrapper: #wrapper_ident, context: &#context_type) -> Result<Self, ValidationErrors> {
     				let mut errors = ValidationErrors::new();
             let failure_mode = #failure_mode;
 
+            #unknown_fields
+
+            #siblings
+
             #(#operations)*
 
             if errors.is_empty() {
@@ -94,7 +114,7 @@ impl<'a> AbstractValidationFactory for AsyncPayloadWithContextFactory<'a> {
    	    }
 
         #[async_trait]
-   			impl SpecificAsyncValidateAndParseWithContext for #struct_name {
+   			impl #impl_generics SpecificAsyncValidateAndParseWithContext for #struct_name {
           type Wrapper = #wrapper_ident;
           type Context = #context_type;
          	async fn specific_async_validate_and_parse_with_context(mut wrapper: #wrapper_ident, context: &#context_type) -> Result<Self, ValidationErrors> {
@@ -103,6 +123,12 @@ impl<'a> AbstractValidationFactory for AsyncPayloadWithContextFactory<'a> {
    	    }
 
         #extensions
+
+        #builder_impls
+
+        #reverse
+
+        #patch
 			};
 		};
 
@@ -114,37 +140,30 @@ impl<'a> AbstractValidationFactory for AsyncPayloadWithContextFactory<'a> {
 		field.increment_modifications();
 		let new_reference = field.get_reference();
 		let field_name = field.get_name();
-		let (field_type, wrapper_type, nested_code) = get_nested(input);
+		let (field_type, wrapper_type, nested_code) = get_nested(input, field);
+		let nested_errors = get_nested_errors_boilerplate(field, &nested_code);
 		let context_type = self.context_type;
 
-		if wrapper_type.is_none() {
-			emit_error!(input.span(), "needs the wrapper type");
-		}
+		let specific_trait = import_validy_core("SpecificAsyncValidateAndParseWithContext");
+		let wrapper_type = wrapper_type.unwrap_or_else(|| parse_quote! { <#field_type as #specific_trait>::Wrapper });
+		field.infer_initial_type(&field_type, &wrapper_type);
+		field.add_unparse(Some(quote! { unparse_nested::<_, #field_type, #wrapper_type>(value) }));
 
 		if field.is_ref() {
 			field.set_is_ref(false);
 			#[rustfmt::skip]
   		let result = quote! {
-  		  let mut #new_reference = #field_type::default();
+  		  let mut #new_reference = Default::default();
   			let result = if can_continue(&errors, failure_mode, #field_name) {
-          <#field_type as AsyncValidateAndParseWithContext<#wrapper_type, #context_type>>::async_validate_and_parse_with_context(*#reference, context).await
+          async_parse_nested::<_, #wrapper_type, _, _>(*#reference, |wrapper| <#field_type as AsyncValidateAndParseWithContext<#wrapper_type, #context_type>>::async_validate_and_parse_with_context(wrapper, context)).await
   			} else {
-          Ok(#field_type::default())
+          Ok(Default::default())
   			};
 
   			match result {
   			  Ok(value) => #new_reference = value,
   				Err(e) =>  {
-    				let error = NestedValidationError::from_with_code(
-     					e,
-     					#field_name,
-              #nested_code
-    				);
-
-  			    append_error(&mut errors, error.into(), failure_mode, #field_name);
-            if should_fail_fast(&errors, failure_mode, #field_name) {
-         			return Err(errors);
-         	  }
+    				#nested_errors
   			  },
   			}
   		};
@@ -154,26 +173,17 @@ impl<'a> AbstractValidationFactory for AsyncPayloadWithContextFactory<'a> {
 			field.set_is_ref(false);
 			#[rustfmt::skip]
   		let result = quote! {
-  		  let mut #new_reference = #field_type::default();
+  		  let mut #new_reference = Default::default();
   			let result = if can_continue(&errors, failure_mode, #field_name) {
-          <#field_type as AsyncValidateAndParseWithContext<#wrapper_type, #context_type>>::async_validate_and_parse_with_context(#reference, context).await
+          async_parse_nested::<_, #wrapper_type, _, _>(#reference, |wrapper| <#field_type as AsyncValidateAndParseWithContext<#wrapper_type, #context_type>>::async_validate_and_parse_with_context(wrapper, context)).await
   			} else {
-          Ok(#field_type::default())
+          Ok(Default::default())
   			};
 
   			match result {
   			  Ok(value) => #new_reference = value,
   				Err(e) =>  {
-    				let error = NestedValidationError::from_with_code(
-     					e,
-     					#field_name,
-              #nested_code
-    				);
-
-  			    append_error(&mut errors, error.into(), failure_mode, #field_name);
-            if should_fail_fast(&errors, failure_mode, #field_name) {
-         			return Err(errors);
-         	  }
+    				#nested_errors
   			  },
   			}
   		};
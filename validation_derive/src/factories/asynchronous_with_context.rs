@@ -6,6 +6,7 @@ use crate::{
 	factories::{
 		boilerplates::{
 			defaults::get_async_default_factory_with_context_boilerplates, failure_mode::get_failure_mode_boilerplate,
+			nested_errors::get_nested_errors_boilerplate,
 		},
 		core::AbstractValidationFactory,
 		extensions::defaults::get_async_default_with_context_extensions,
@@ -14,18 +15,19 @@ use crate::{
 	fields::FieldAttributes,
 	imports::Import,
 	primitives::specials::nested::get_nested,
+	types::StructName,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Type, parse::ParseStream};
+use syn::{Type, parse::ParseStream};
 
 pub struct AsyncValidationWithContextFactory<'a> {
-	struct_name: &'a Ident,
+	struct_name: &'a StructName,
 	context_type: &'a Type,
 }
 
 impl<'a> AsyncValidationWithContextFactory<'a> {
-	pub fn new(struct_name: &'a Ident, context_type: &'a Type) -> Self {
+	pub fn new(struct_name: &'a StructName, context_type: &'a Type) -> Self {
 		Self {
 			struct_name,
 			context_type,
@@ -40,6 +42,7 @@ impl<'a> AbstractValidationFactory for AsyncValidationWithContextFactory<'a> {
 		attributes: &ValidationAttributes,
 		imports: &RefCell<ImportsSet>,
 	) -> Output {
+		let impl_generics = self.struct_name.impl_generics();
 		imports.borrow_mut().add(Import::ValidyCore);
 		imports.borrow_mut().add(Import::ValidySettings);
 		imports.borrow_mut().add(Import::ValidyHelpers);
@@ -64,7 +67,7 @@ impl<'a> AbstractValidationFactory for AsyncValidationWithContextFactory<'a> {
 				#imports
 
   			#[async_trait]
-  		  impl AsyncValidateWithContext<#context_type> for #struct_name {
+  		  impl #impl_generics AsyncValidateWithContext<#context_type> for #struct_name {
   			  async fn async_validate_with_context(&self, context: &#context_type) -> Result<(), ValidationErrors> {
      				let mut errors = ValidationErrors::new();
             let failure_mode = #failure_mode;
@@ -80,7 +83,7 @@ impl<'a> AbstractValidationFactory for AsyncValidationWithContextFactory<'a> {
   		  }
 
    			#[async_trait]
-   		  impl SpecificAsyncValidateWithContext for #struct_name {
+   		  impl #impl_generics SpecificAsyncValidateWithContext for #struct_name {
           type Context = #context_type;
    			  async fn specific_async_validate_with_context(&self, context: &#context_type) -> Result<(), ValidationErrors> {
             <#struct_name as AsyncValidateWithContext<#context_type>>::async_validate_with_context(self, context).await
@@ -99,24 +102,16 @@ impl<'a> AbstractValidationFactory for AsyncValidationWithContextFactory<'a> {
 	fn create_nested(&self, input: ParseStream, field: &mut FieldAttributes) -> TokenStream {
 		let reference = field.get_reference();
 		let field_name = field.get_name();
-		let (field_type, _, nested_code) = get_nested(input);
+		let (field_type, _, nested_code) = get_nested(input, field);
+		let nested_errors = get_nested_errors_boilerplate(field, &nested_code);
 		let context_type = self.context_type;
 
 		if field.is_ref() {
 			field.set_is_ref(true);
 			#[rustfmt::skip]
 			let result = quote! {
-			  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = <#field_type as AsyncValidateWithContext<#context_type>>::async_validate_with_context(#reference, &context).await {
-					let error = NestedValidationError::from_with_code(
-						e,
-						#field_name,
-						#nested_code
-					);
-
-				  append_error(&mut errors, error.into(), failure_mode, #field_name);
-          if should_fail_fast(&errors, failure_mode, #field_name) {
-       			return Err(errors);
-       	  }
+			  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = async_nested_scope(<#field_type as AsyncValidateWithContext<#context_type>>::async_validate_with_context(#reference, &context)).await {
+					#nested_errors
 				}
 			};
 
@@ -126,17 +121,8 @@ impl<'a> AbstractValidationFactory for AsyncValidationWithContextFactory<'a> {
 			#[rustfmt::skip]
 			let result = quote! {
 			  let _ref = &#reference;
-			  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = <#field_type as AsyncValidateWithContext<#context_type>>::async_validate_with_context(_ref, &context).await {
-					let error = NestedValidationError::from_with_code(
-						e,
-						#field_name,
-						#nested_code
-					);
-
-				  append_error(&mut errors, error.into(), failure_mode, #field_name);
-          if should_fail_fast(&errors, failure_mode, #field_name) {
-       			return Err(errors);
-       	  }
+			  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = async_nested_scope(<#field_type as AsyncValidateWithContext<#context_type>>::async_validate_with_context(_ref, &context)).await {
+					#nested_errors
 				}
 			};
 
@@ -1,6 +1,6 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Type};
+use syn::Type;
 
 use crate::factories::boilerplates::{
 	modifications::{
@@ -12,8 +12,9 @@ use crate::factories::boilerplates::{
 		get_payload_with_context_boilerplate,
 	},
 };
+use crate::types::StructName;
 
-pub fn get_default_factory_boilerplates(struct_name: &Ident) -> TokenStream {
+pub fn get_default_factory_boilerplates(struct_name: &StructName) -> TokenStream {
 	let method = quote! { self.validate() };
 	let payload_method = quote! {
 	  wrapper.validate()?;
@@ -42,7 +43,7 @@ pub fn get_default_factory_boilerplates(struct_name: &Ident) -> TokenStream {
 	result
 }
 
-pub fn get_default_with_context_factory_boilerplates(struct_name: &Ident, context_type: &Type) -> TokenStream {
+pub fn get_default_with_context_factory_boilerplates(struct_name: &StructName, context_type: &Type) -> TokenStream {
 	let method = quote! { self.validate_with_context(context) };
 	let payload_method = quote! {
 	  wrapper.validate_with_context(context)?;
@@ -65,7 +66,7 @@ pub fn get_default_with_context_factory_boilerplates(struct_name: &Ident, contex
 	result
 }
 
-pub fn get_async_default_factory_boilerplates(struct_name: &Ident) -> TokenStream {
+pub fn get_async_default_factory_boilerplates(struct_name: &StructName) -> TokenStream {
 	let method = quote! { self.async_validate().await };
 	let payload_method = quote! {
 	  wrapper.async_validate().await?;
@@ -88,7 +89,10 @@ pub fn get_async_default_factory_boilerplates(struct_name: &Ident) -> TokenStrea
 	result
 }
 
-pub fn get_async_default_factory_with_context_boilerplates(struct_name: &Ident, context_type: &Type) -> TokenStream {
+pub fn get_async_default_factory_with_context_boilerplates(
+	struct_name: &StructName,
+	context_type: &Type,
+) -> TokenStream {
 	let method = quote! { self.async_validate_with_context(context).await };
 	let payload_method = quote! {
 	  wrapper.async_validate_with_context(context).await?;
@@ -109,20 +113,22 @@ pub fn get_async_default_factory_with_context_boilerplates(struct_name: &Ident,
 }
 
 pub fn get_default_with_context_boilerplate(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	context_type: Option<&Type>,
 	method: &TokenStream,
 ) -> TokenStream {
+	let impl_generics = struct_name.impl_generics();
+	let generic_params = struct_name.generic_params();
 	#[rustfmt::skip]
 	let result = match context_type {
     Some(context_type) => quote! {
-		  impl ValidateWithContext<#context_type> for #struct_name {
+		  impl #impl_generics ValidateWithContext<#context_type> for #struct_name {
 			  fn validate_with_context(&self, context: &#context_type) -> Result<(), ValidationErrors> {
 			    #method
 			  }
 		  }
 
-		  impl SpecificValidateWithContext for #struct_name {
+		  impl #impl_generics SpecificValidateWithContext for #struct_name {
 				type Context = #context_type;
 			  fn specific_validate_with_context(&self, context: &#context_type) -> Result<(), ValidationErrors> {
 			    #method
@@ -130,13 +136,13 @@ pub fn get_default_with_context_boilerplate(
 		  }
     },
     None => quote! {
-  		impl<C> ValidateWithContext<C> for #struct_name {
+  		impl<#generic_params C> ValidateWithContext<C> for #struct_name {
 			  fn validate_with_context(&self, _: &C) -> Result<(), ValidationErrors> {
 			    #method
 			  }
 		  }
 
-		  impl SpecificValidateWithContext for #struct_name {
+		  impl #impl_generics SpecificValidateWithContext for #struct_name {
 				type Context = NoContext;
 			  fn specific_validate_with_context(&self, context: &NoContext) -> Result<(), ValidationErrors> {
 			    #method
@@ -148,11 +154,12 @@ pub fn get_default_with_context_boilerplate(
 	result
 }
 
-pub fn get_async_default_boilerplate(struct_name: &Ident, method: &TokenStream) -> TokenStream {
+pub fn get_async_default_boilerplate(struct_name: &StructName, method: &TokenStream) -> TokenStream {
+	let impl_generics = struct_name.impl_generics();
 	#[rustfmt::skip]
 	let result = quote! {
 		#[async_trait]
-	  impl AsyncValidate for #struct_name {
+	  impl #impl_generics AsyncValidate for #struct_name {
 		  async fn async_validate(&self) -> Result<(), ValidationErrors> {
   		  #method
 		  }
@@ -163,22 +170,24 @@ pub fn get_async_default_boilerplate(struct_name: &Ident, method: &TokenStream)
 }
 
 pub fn get_async_default_with_context_boilerplate(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	context_type: Option<&Type>,
 	method: &TokenStream,
 ) -> TokenStream {
+	let impl_generics = struct_name.impl_generics();
+	let generic_params = struct_name.generic_params();
 	#[rustfmt::skip]
 	let result = match context_type {
     Some(context_type) => quote! {
 			#[async_trait]
-		  impl AsyncValidateWithContext<#context_type> for #struct_name {
+		  impl #impl_generics AsyncValidateWithContext<#context_type> for #struct_name {
 			  async fn async_validate_with_context(&self, context: &#context_type) -> Result<(), ValidationErrors> {
 			    #method
 			  }
 		  }
 
 			#[async_trait]
-		  impl SpecificAsyncValidateWithContext for #struct_name {
+		  impl #impl_generics SpecificAsyncValidateWithContext for #struct_name {
 				type Context = #context_type;
 			  async fn specific_async_validate_with_context(&self, context: &#context_type) -> Result<(), ValidationErrors> {
 			    #method
@@ -187,14 +196,14 @@ pub fn get_async_default_with_context_boilerplate(
     },
     None => quote! {
       #[async_trait]
-		  impl<C> AsyncValidateWithContext<C> for #struct_name {
+		  impl<#generic_params C> AsyncValidateWithContext<C> for #struct_name {
 			  async fn async_validate_with_context(&self, _: &C) -> Result<(), ValidationErrors> {
 			    #method
 			  }
 		  }
 
 			#[async_trait]
-		  impl SpecificAsyncValidateWithContext for #struct_name {
+		  impl #impl_generics SpecificAsyncValidateWithContext for #struct_name {
 				type Context = NoContext;
 			  async fn specific_async_validate_with_context(&self, context: &NoContext) -> Result<(), ValidationErrors> {
 			    #method
@@ -1,4 +1,6 @@
 pub mod defaults;
 pub mod failure_mode;
 pub mod modifications;
+pub mod nested_errors;
 pub mod payloads;
+pub mod unknown_fields;
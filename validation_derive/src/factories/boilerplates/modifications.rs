@@ -1,13 +1,14 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Type};
+use syn::Type;
 
 use crate::factories::boilerplates::payloads::{
 	get_async_payload_boilerplate, get_async_payload_with_context_boilerplate, get_payload_boilerplate,
 	get_payload_with_context_boilerplate,
 };
+use crate::types::StructName;
 
-pub fn get_modification_factory_boilerplates(struct_name: &Ident) -> TokenStream {
+pub fn get_modification_factory_boilerplates(struct_name: &StructName) -> TokenStream {
 	let method = quote! { self.validate_and_modificate() };
 	let payload_method = quote! {
 	  wrapper.validate_and_modificate()?;
@@ -32,7 +33,10 @@ pub fn get_modification_factory_boilerplates(struct_name: &Ident) -> TokenStream
 	result
 }
 
-pub fn get_modification_with_context_factory_boilerplates(struct_name: &Ident, context_type: &Type) -> TokenStream {
+pub fn get_modification_with_context_factory_boilerplates(
+	struct_name: &StructName,
+	context_type: &Type,
+) -> TokenStream {
 	let method = quote! { self.validate_and_modificate_with_context(context) };
 	let payload_method = quote! {
 	  wrapper.validate_and_modificate_with_context(context)?;
@@ -53,7 +57,7 @@ pub fn get_modification_with_context_factory_boilerplates(struct_name: &Ident, c
 	result
 }
 
-pub fn get_async_modification_factory_boilerplates(struct_name: &Ident) -> TokenStream {
+pub fn get_async_modification_factory_boilerplates(struct_name: &StructName) -> TokenStream {
 	let method = quote! { self.async_validate_and_modificate().await };
 	let payload_method = quote! {
 	  wrapper.async_validate_and_modificate().await?;
@@ -75,7 +79,7 @@ pub fn get_async_modification_factory_boilerplates(struct_name: &Ident) -> Token
 }
 
 pub fn get_async_modification_with_context_factory_boilerplates(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	context_type: &Type,
 ) -> TokenStream {
 	let payload_method = quote! {
@@ -99,20 +103,22 @@ pub fn get_async_modification_with_context_factory_boilerplates(
 }
 
 pub fn get_modification_with_context_boilerplate(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	context_type: Option<&Type>,
 	method: &TokenStream,
 ) -> TokenStream {
+	let impl_generics = struct_name.impl_generics();
+	let generic_params = struct_name.generic_params();
 	#[rustfmt::skip]
 	let result = match context_type {
     Some(context_type) => quote! {
-   	  impl ValidateAndModificateWithContext<#context_type> for #struct_name {
+   	  impl #impl_generics ValidateAndModificateWithContext<#context_type> for #struct_name {
         fn validate_and_modificate_with_context(&mut self, context: &#context_type) -> Result<(), ValidationErrors> {
 			    #method
 			  }
 		  }
 
-			impl SpecificValidateAndModificateWithContext for #struct_name {
+			impl #impl_generics SpecificValidateAndModificateWithContext for #struct_name {
 			  type Context = #context_type;
         fn specific_validate_and_modificate_with_context(&mut self, context: &#context_type) -> Result<(), ValidationErrors> {
           #method
@@ -120,13 +126,13 @@ pub fn get_modification_with_context_boilerplate(
       }
     },
     None => quote! {
-      impl<C> ValidateAndModificateWithContext<C> for #struct_name {
+      impl<#generic_params C> ValidateAndModificateWithContext<C> for #struct_name {
 			  fn validate_and_modificate_with_context(&mut self, _: &C) -> Result<(), ValidationErrors> {
 			    #method
 			  }
 		  }
 
-			impl SpecificValidateAndModificateWithContext for #struct_name {
+			impl #impl_generics SpecificValidateAndModificateWithContext for #struct_name {
 				type Context = NoContext;
 				fn specific_validate_and_modificate_with_context(&mut self, context: &NoContext) -> Result<(), ValidationErrors> {
           #method
@@ -138,10 +144,11 @@ pub fn get_modification_with_context_boilerplate(
 	result
 }
 
-pub fn get_modification_boilerplate(struct_name: &Ident, method: &TokenStream) -> TokenStream {
+pub fn get_modification_boilerplate(struct_name: &StructName, method: &TokenStream) -> TokenStream {
+	let impl_generics = struct_name.impl_generics();
 	#[rustfmt::skip]
 	let result = quote! {
-		impl ValidateAndModificate for #struct_name {
+		impl #impl_generics ValidateAndModificate for #struct_name {
 		  fn validate_and_modificate(&mut self) -> Result<(), ValidationErrors> {
 				#method
 			}
@@ -151,11 +158,12 @@ pub fn get_modification_boilerplate(struct_name: &Ident, method: &TokenStream) -
 	result
 }
 
-pub fn get_async_modification_boilerplate(struct_name: &Ident, method: &TokenStream) -> TokenStream {
+pub fn get_async_modification_boilerplate(struct_name: &StructName, method: &TokenStream) -> TokenStream {
+	let impl_generics = struct_name.impl_generics();
 	#[rustfmt::skip]
 	let result = quote! {
 		#[async_trait]
-	  impl AsyncValidateAndModificate for #struct_name {
+	  impl #impl_generics AsyncValidateAndModificate for #struct_name {
 		  async fn async_validate_and_modificate(&mut self) -> Result<(), ValidationErrors> {
 		    #method
 		  }
@@ -166,22 +174,24 @@ pub fn get_async_modification_boilerplate(struct_name: &Ident, method: &TokenStr
 }
 
 pub fn get_async_modification_with_context_boilerplate(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	context_type: Option<&Type>,
 	method: &TokenStream,
 ) -> TokenStream {
+	let impl_generics = struct_name.impl_generics();
+	let generic_params = struct_name.generic_params();
 	#[rustfmt::skip]
 	let result = match context_type {
     Some(context_type) => quote! {
   		#[async_trait]
-		  impl AsyncValidateAndModificateWithContext<#context_type> for #struct_name {
+		  impl #impl_generics AsyncValidateAndModificateWithContext<#context_type> for #struct_name {
 		    async fn async_validate_and_modificate_with_context(&mut self, context: &#context_type) -> Result<(), ValidationErrors> {
 				  #method
 			  }
 		  }
 
   		#[async_trait]
-		  impl SpecificAsyncValidateAndModificateWithContext for #struct_name {
+		  impl #impl_generics SpecificAsyncValidateAndModificateWithContext for #struct_name {
 				type Context = #context_type;
 		    async fn specific_async_validate_and_modificate_with_context(&mut self, context: &#context_type) -> Result<(), ValidationErrors> {
 				  #method
@@ -190,14 +200,14 @@ pub fn get_async_modification_with_context_boilerplate(
     },
     None => quote! {
       #[async_trait]
-		  impl<C> AsyncValidateAndModificateWithContext<C> for #struct_name {
+		  impl<#generic_params C> AsyncValidateAndModificateWithContext<C> for #struct_name {
 			  async fn async_validate_and_modificate_with_context(&mut self, _: &C) -> Result<(), ValidationErrors> {
 			    #method
 			  }
 		  }
 
   		#[async_trait]
-		  impl SpecificAsyncValidateAndModificateWithContext for #struct_name {
+		  impl #impl_generics SpecificAsyncValidateAndModificateWithContext for #struct_name {
 				type Context = NoContext;
 		    async fn specific_async_validate_and_modificate_with_context(&mut self, context: &NoContext) -> Result<(), ValidationErrors> {
 				  #method
@@ -0,0 +1,36 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+use crate::fields::FieldAttributes;
+
+pub fn get_nested_errors_boilerplate(field: &FieldAttributes, nested_code: &LitStr) -> TokenStream {
+	let field_name = field.get_name();
+
+	if field.is_flatten() {
+		#[rustfmt::skip]
+		let result = quote! {
+		  if merge_errors(&mut errors, e, failure_mode, #field_name) {
+				return Err(errors);
+		  }
+		};
+
+		return result;
+	}
+
+	#[rustfmt::skip]
+	let result = quote! {
+	  let error = into_nested_error(
+			e,
+			#field_name,
+			#nested_code
+	  );
+
+	  append_error(&mut errors, error.into(), failure_mode, #field_name);
+	  if should_fail_fast(&errors, failure_mode, #field_name) {
+			return Err(errors);
+	  }
+	};
+
+	result
+}
@@ -1,8 +1,9 @@
+use crate::types::StructName;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Type};
+use syn::Type;
 
-pub fn get_payload_factory_boilerplates(struct_name: &Ident, wrapper_ident: &Ident) -> TokenStream {
+pub fn get_payload_factory_boilerplates(struct_name: &StructName, wrapper_ident: &StructName) -> TokenStream {
 	let method = quote! { <#struct_name as ValidateAndParse<#wrapper_ident>>::validate_and_parse(wrapper) };
 	let boilerplates = vec![
 		get_payload_with_context_boilerplate(struct_name, wrapper_ident, None, &method),
@@ -19,8 +20,8 @@ pub fn get_payload_factory_boilerplates(struct_name: &Ident, wrapper_ident: &Ide
 }
 
 pub fn get_payload_with_context_factory_boilerplates(
-	struct_name: &Ident,
-	wrapper_ident: &Ident,
+	struct_name: &StructName,
+	wrapper_ident: &StructName,
 	context_type: &Type,
 ) -> TokenStream {
 	let method = quote! { <#struct_name as ValidateAndParseWithContext<#wrapper_ident, #context_type>>::validate_and_parse_with_context(wrapper, context) };
@@ -39,7 +40,7 @@ pub fn get_payload_with_context_factory_boilerplates(
 	result
 }
 
-pub fn get_async_payload_factory_boilerplates(struct_name: &Ident, wrapper_ident: &Ident) -> TokenStream {
+pub fn get_async_payload_factory_boilerplates(struct_name: &StructName, wrapper_ident: &StructName) -> TokenStream {
 	let method =
 		quote! { <#struct_name as AsyncValidateAndParse<#wrapper_ident>>::async_validate_and_parse(wrapper).await };
 	let boilerplates = vec![get_async_payload_with_context_boilerplate(
@@ -58,21 +59,23 @@ pub fn get_async_payload_factory_boilerplates(struct_name: &Ident, wrapper_ident
 }
 
 pub fn get_payload_with_context_boilerplate(
-	struct_name: &Ident,
-	wrapper_ident: &Ident,
+	struct_name: &StructName,
+	wrapper_ident: &StructName,
 	context_type: Option<&Type>,
 	method: &TokenStream,
 ) -> TokenStream {
+	let impl_generics = struct_name.impl_generics();
+	let generic_params = struct_name.generic_params();
 	#[rustfmt::skip]
 	let result = match context_type {
     Some(context_type) => quote! {
-   	  impl ValidateAndParseWithContext<#wrapper_ident, #context_type> for #struct_name {
+   	  impl #impl_generics ValidateAndParseWithContext<#wrapper_ident, #context_type> for #struct_name {
        	fn validate_and_parse_with_context(mut wrapper: #wrapper_ident, context: &#context_type) -> Result<Self, ValidationErrors> {
   			  #method
   		  }
   	  }
 
-     impl SpecificValidateAndParseWithContext for #struct_name {
+     impl #impl_generics SpecificValidateAndParseWithContext for #struct_name {
         type Wrapper = #wrapper_ident;
         type Context = #context_type;
         fn specific_validate_and_parse_with_context(mut wrapper: #wrapper_ident, context: &#context_type) -> Result<Self, ValidationErrors> {
@@ -81,13 +84,13 @@ pub fn get_payload_with_context_boilerplate(
   	  }
     },
     None => quote! {
-  	  impl<C> ValidateAndParseWithContext<#wrapper_ident, C> for #struct_name {
+  	  impl<#generic_params C> ValidateAndParseWithContext<#wrapper_ident, C> for #struct_name {
   			fn validate_and_parse_with_context(mut wrapper: #wrapper_ident, _: &C) -> Result<Self, ValidationErrors> {
   			  #method
   		  }
   	  }
 
-      impl SpecificValidateAndParseWithContext for #struct_name {
+      impl #impl_generics SpecificValidateAndParseWithContext for #struct_name {
         type Wrapper = #wrapper_ident;
         type Context = NoContext;
         fn specific_validate_and_parse_with_context(mut wrapper: #wrapper_ident, context: &NoContext) -> Result<Self, ValidationErrors> {
@@ -100,16 +103,21 @@ pub fn get_payload_with_context_boilerplate(
 	result
 }
 
-pub fn get_payload_boilerplate(struct_name: &Ident, wrapper_ident: &Ident, method: &TokenStream) -> TokenStream {
+pub fn get_payload_boilerplate(
+	struct_name: &StructName,
+	wrapper_ident: &StructName,
+	method: &TokenStream,
+) -> TokenStream {
+	let impl_generics = struct_name.impl_generics();
 	#[rustfmt::skip]
 	let result = quote! {
-    impl ValidateAndParse<#wrapper_ident> for #struct_name {
+    impl #impl_generics ValidateAndParse<#wrapper_ident> for #struct_name {
       fn validate_and_parse(mut wrapper: #wrapper_ident) -> Result<Self, ValidationErrors> {
        	#method
       }
     }
 
-    impl SpecificValidateAndParse for #struct_name {
+    impl #impl_generics SpecificValidateAndParse for #struct_name {
       type Wrapper = #wrapper_ident;
       fn specific_validate_and_parse(mut wrapper: #wrapper_ident) -> Result<Self, ValidationErrors> {
        	#method
@@ -120,18 +128,23 @@ pub fn get_payload_boilerplate(struct_name: &Ident, wrapper_ident: &Ident, metho
 	result
 }
 
-pub fn get_async_payload_boilerplate(struct_name: &Ident, wrapper_ident: &Ident, method: &TokenStream) -> TokenStream {
+pub fn get_async_payload_boilerplate(
+	struct_name: &StructName,
+	wrapper_ident: &StructName,
+	method: &TokenStream,
+) -> TokenStream {
+	let impl_generics = struct_name.impl_generics();
 	#[rustfmt::skip]
 	let result = quote! {
     #[async_trait]
-    impl AsyncValidateAndParse<#wrapper_ident> for #struct_name {
+    impl #impl_generics AsyncValidateAndParse<#wrapper_ident> for #struct_name {
       async fn async_validate_and_parse(mut wrapper: #wrapper_ident) -> Result<Self, ValidationErrors> {
        	#method
       }
     }
 
     #[async_trait]
-    impl SpecificAsyncValidateAndParse for #struct_name {
+    impl #impl_generics SpecificAsyncValidateAndParse for #struct_name {
       type Wrapper = #wrapper_ident;
       async fn specific_async_validate_and_parse(mut wrapper: #wrapper_ident) -> Result<Self, ValidationErrors> {
        	#method
@@ -143,23 +156,25 @@ pub fn get_async_payload_boilerplate(struct_name: &Ident, wrapper_ident: &Ident,
 }
 
 pub fn get_async_payload_with_context_boilerplate(
-	struct_name: &Ident,
-	wrapper_ident: &Ident,
+	struct_name: &StructName,
+	wrapper_ident: &StructName,
 	context_type: Option<&Type>,
 	method: &TokenStream,
 ) -> TokenStream {
+	let impl_generics = struct_name.impl_generics();
+	let generic_params = struct_name.generic_params();
 	#[rustfmt::skip]
 	let result = match context_type {
     Some(context_type) => quote! {
       #[async_trait]
-     	impl AsyncValidateAndParseWithContext<#wrapper_ident, #context_type> for #struct_name {
+     	impl #impl_generics AsyncValidateAndParseWithContext<#wrapper_ident, #context_type> for #struct_name {
      	  async fn async_validate_and_parse_with_context(mut wrapper: #wrapper_ident, context: &#context_type) -> Result<Self, ValidationErrors> {
          	#method
      		}
       }
 
      	#[async_trait]
-     	impl SpecificAsyncValidateAndParseWithContext for #struct_name {
+     	impl #impl_generics SpecificAsyncValidateAndParseWithContext for #struct_name {
         type Wrapper = #wrapper_ident;
         type Context = #context_type;
      	  async fn specific_async_validate_and_parse_with_context(mut wrapper: #wrapper_ident, context: &#context_type) -> Result<Self, ValidationErrors> {
@@ -169,14 +184,14 @@ pub fn get_async_payload_with_context_boilerplate(
     },
     None => quote! {
      	#[async_trait]
-     	impl<C> AsyncValidateAndParseWithContext<#wrapper_ident, C> for #struct_name {
+     	impl<#generic_params C> AsyncValidateAndParseWithContext<#wrapper_ident, C> for #struct_name {
      	  async fn async_validate_and_parse_with_context(mut wrapper: #wrapper_ident, _: &C) -> Result<Self, ValidationErrors> {
          	#method
      		}
       }
 
       #[async_trait]
-     	impl SpecificAsyncValidateAndParseWithContext for #struct_name {
+     	impl #impl_generics SpecificAsyncValidateAndParseWithContext for #struct_name {
         type Wrapper = #wrapper_ident;
         type Context = NoContext;
      	  async fn specific_async_validate_and_parse_with_context(mut wrapper: #wrapper_ident, context: &NoContext) -> Result<Self, ValidationErrors> {
@@ -0,0 +1,38 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::attributes::ValidationAttributes;
+
+pub fn get_unknown_fields_boilerplate(attributes: &ValidationAttributes, source: TokenStream) -> TokenStream {
+	if !attributes.deny_unknown_fields {
+		return quote! {};
+	}
+
+	let stop = attributes.multipart.then(|| {
+		quote! {
+		  if !#source.0.is_empty() {
+				return Err(errors);
+		  }
+		}
+	});
+
+	#[rustfmt::skip]
+	let result = quote! {
+	  for unknown_field in #source.0.iter() {
+			let error = ValidationError::builder()
+			  .with_field(unknown_field.clone())
+			  .as_simple("unknown_field")
+			  .with_message("is unknown")
+			  .build();
+
+			append_error(&mut errors, error.into(), failure_mode, unknown_field.clone());
+			if should_fail_fast(&errors, failure_mode, unknown_field) {
+			  return Err(errors);
+			}
+	  }
+
+	  #stop
+	};
+
+	result
+}
@@ -13,9 +13,10 @@ use crate::{
 		with_context::ValidationWithContextFactory,
 	},
 	fields::FieldAttributes,
+	types::StructName,
 };
 use proc_macro2::TokenStream;
-use syn::{DeriveInput, Ident, parse::ParseStream};
+use syn::{DeriveInput, parse::ParseStream};
 
 pub trait AbstractValidationFactory {
 	fn init(&mut self, input: &DeriveInput, attributes: &ValidationAttributes) {
@@ -33,7 +34,7 @@ pub trait AbstractValidationFactory {
 }
 
 pub fn get_factory<'a>(
-	name: &'a Ident,
+	name: &'a StructName,
 	attributes: &'a ValidationAttributes,
 ) -> Box<dyn AbstractValidationFactory + 'a> {
 	match (
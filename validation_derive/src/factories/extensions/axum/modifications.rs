@@ -1,8 +1,8 @@
+use crate::types::StructName;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::Ident;
 
-pub fn get_async_modification_axum_extension(struct_name: &Ident) -> TokenStream {
+pub fn get_async_modification_axum_extension(struct_name: &StructName) -> TokenStream {
 	#[rustfmt::skip]
   let result = quote! {
  	  const _: () = {
@@ -34,7 +34,7 @@ pub fn get_async_modification_axum_extension(struct_name: &Ident) -> TokenStream
 	result
 }
 
-pub fn get_async_modification_with_context_axum_extension(struct_name: &Ident) -> TokenStream {
+pub fn get_async_modification_with_context_axum_extension(struct_name: &StructName) -> TokenStream {
 	#[rustfmt::skip]
   let result = quote! {
     const _: () = {
@@ -69,7 +69,7 @@ pub fn get_async_modification_with_context_axum_extension(struct_name: &Ident) -
 	result
 }
 
-pub fn get_async_modification_axum_multipart_extension(struct_name: &Ident) -> TokenStream {
+pub fn get_async_modification_axum_multipart_extension(struct_name: &StructName) -> TokenStream {
 	#[rustfmt::skip]
   let result = quote! {
  	  const _: () = {
@@ -108,7 +108,7 @@ pub fn get_async_modification_axum_multipart_extension(struct_name: &Ident) -> T
 	result
 }
 
-pub fn get_async_modification_with_context_axum_multipart_extension(struct_name: &Ident) -> TokenStream {
+pub fn get_async_modification_with_context_axum_multipart_extension(struct_name: &StructName) -> TokenStream {
 	#[rustfmt::skip]
   let result = quote! {
     const _: () = {
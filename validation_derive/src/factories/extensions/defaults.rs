@@ -2,7 +2,7 @@ use std::cell::RefCell;
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Type};
+use syn::Type;
 
 use crate::{
 	ImportsSet,
@@ -11,10 +11,11 @@ use crate::{
 		get_async_default_axum_extension, get_async_default_axum_multipart_extension,
 		get_async_default_with_context_axum_extension, get_async_default_with_context_axum_multipart_extension,
 	},
+	types::StructName,
 };
 
 pub fn get_default_extensions(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	attributes: &ValidationAttributes,
 	_: &RefCell<ImportsSet>,
 ) -> TokenStream {
@@ -35,7 +36,7 @@ pub fn get_default_extensions(
 }
 
 pub fn get_default_with_context_extensions(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	attributes: &ValidationAttributes,
 	_: &Type,
 	_: &RefCell<ImportsSet>,
@@ -59,7 +60,7 @@ pub fn get_default_with_context_extensions(
 }
 
 pub fn get_async_default_extensions(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	attributes: &ValidationAttributes,
 	_: &RefCell<ImportsSet>,
 ) -> TokenStream {
@@ -80,7 +81,7 @@ pub fn get_async_default_extensions(
 }
 
 pub fn get_async_default_with_context_extensions(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	attributes: &ValidationAttributes,
 	_: &Type,
 	_: &RefCell<ImportsSet>,
@@ -2,7 +2,7 @@ use std::cell::RefCell;
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Type};
+use syn::Type;
 
 use crate::{
 	ImportsSet,
@@ -12,10 +12,11 @@ use crate::{
 		get_async_modification_with_context_axum_extension,
 		get_async_modification_with_context_axum_multipart_extension,
 	},
+	types::StructName,
 };
 
 pub fn get_modification_extensions(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	attributes: &ValidationAttributes,
 	_: &RefCell<ImportsSet>,
 ) -> TokenStream {
@@ -36,7 +37,7 @@ pub fn get_modification_extensions(
 }
 
 pub fn get_modification_with_context_extensions(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	attributes: &ValidationAttributes,
 	_: &Type,
 	_: &RefCell<ImportsSet>,
@@ -60,7 +61,7 @@ pub fn get_modification_with_context_extensions(
 }
 
 pub fn get_async_modification_extensions(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	attributes: &ValidationAttributes,
 	_: &RefCell<ImportsSet>,
 ) -> TokenStream {
@@ -81,7 +82,7 @@ pub fn get_async_modification_extensions(
 }
 
 pub fn get_async_modification_with_context_extensions(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	attributes: &ValidationAttributes,
 	_: &Type,
 	_: &RefCell<ImportsSet>,
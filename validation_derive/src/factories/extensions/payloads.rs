@@ -2,7 +2,7 @@ use std::cell::RefCell;
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Type};
+use syn::Type;
 
 use crate::{
 	ImportsSet,
@@ -11,12 +11,13 @@ use crate::{
 		get_async_payload_axum_extension, get_async_payload_axum_multipart_extension,
 		get_async_payload_with_context_axum_extension, get_async_payload_with_context_axum_multipart_extension,
 	},
+	types::StructName,
 };
 
 pub fn get_payload_extensions(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	attributes: &ValidationAttributes,
-	_: &Ident,
+	_: &StructName,
 	_: &RefCell<ImportsSet>,
 ) -> TokenStream {
 	let mut extensions = vec![];
@@ -36,9 +37,9 @@ pub fn get_payload_extensions(
 }
 
 pub fn get_payload_with_context_extensions(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	attributes: &ValidationAttributes,
-	_: &Ident,
+	_: &StructName,
 	_: &Type,
 	_: &RefCell<ImportsSet>,
 ) -> TokenStream {
@@ -61,9 +62,9 @@ pub fn get_payload_with_context_extensions(
 }
 
 pub fn get_async_payload_extensions(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	attributes: &ValidationAttributes,
-	_: &Ident,
+	_: &StructName,
 	_: &RefCell<ImportsSet>,
 ) -> TokenStream {
 	let mut extensions = vec![];
@@ -83,9 +84,9 @@ pub fn get_async_payload_extensions(
 }
 
 pub fn get_async_payload_with_context_extensions(
-	struct_name: &Ident,
+	struct_name: &StructName,
 	attributes: &ValidationAttributes,
-	_: &Ident,
+	_: &StructName,
 	_: &Type,
 	_: &RefCell<ImportsSet>,
 ) -> TokenStream {
@@ -10,6 +10,7 @@ pub mod default;
 pub mod extensions;
 pub mod modification;
 pub mod modification_with_context;
+pub mod newtype;
 pub mod others;
 pub mod payload;
 pub mod payload_with_context;
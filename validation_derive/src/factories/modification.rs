@@ -6,6 +6,7 @@ use crate::{
 	factories::{
 		boilerplates::{
 			failure_mode::get_failure_mode_boilerplate, modifications::get_modification_factory_boilerplates,
+			nested_errors::get_nested_errors_boilerplate,
 		},
 		core::AbstractValidationFactory,
 		extensions::modifications::get_modification_extensions,
@@ -14,17 +15,18 @@ use crate::{
 	fields::FieldAttributes,
 	imports::Import,
 	primitives::specials::nested::get_nested,
+	types::StructName,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, parse::ParseStream};
+use syn::parse::ParseStream;
 
 pub struct ModificationFactory<'a> {
-	struct_name: &'a Ident,
+	struct_name: &'a StructName,
 }
 
 impl<'a> ModificationFactory<'a> {
-	pub fn new(struct_name: &'a Ident) -> Self {
+	pub fn new(struct_name: &'a StructName) -> Self {
 		Self { struct_name }
 	}
 }
@@ -36,6 +38,7 @@ impl<'a> AbstractValidationFactory for ModificationFactory<'a> {
 		attributes: &ValidationAttributes,
 		imports: &RefCell<ImportsSet>,
 	) -> Output {
+		let impl_generics = self.struct_name.impl_generics();
 		imports.borrow_mut().add(Import::ValidyCore);
 		imports.borrow_mut().add(Import::ValidySettings);
 		imports.borrow_mut().add(Import::ValidyHelpers);
@@ -57,7 +60,7 @@ impl<'a> AbstractValidationFactory for ModificationFactory<'a> {
 		  const _: () = {
 				#imports
 
-  		  impl ValidateAndModificate for #struct_name {
+  		  impl #impl_generics ValidateAndModificate for #struct_name {
   			  fn validate_and_modificate(&mut self) -> Result<(), ValidationErrors> {
      				let mut errors = ValidationErrors::new();
             let failure_mode = #failure_mode;
@@ -84,23 +87,15 @@ impl<'a> AbstractValidationFactory for ModificationFactory<'a> {
 	fn create_nested(&self, input: ParseStream, field: &mut FieldAttributes) -> TokenStream {
 		let reference = field.get_reference();
 		let field_name = field.get_name();
-		let (field_type, _, nested_code) = get_nested(input);
+		let (field_type, _, nested_code) = get_nested(input, field);
+		let nested_errors = get_nested_errors_boilerplate(field, &nested_code);
 
 		if field.is_ref() {
 			field.set_is_ref(true);
 			#[rustfmt::skip]
   		let result = quote! {
-  		  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = <#field_type as ValidateAndModificate>::validate_and_modificate(#reference) {
-  				let error = NestedValidationError::from_with_code(
-  					e,
-  					#field_name,
-            #nested_code
-  				);
-
-  			  append_error(&mut errors, error.into(), failure_mode, #field_name);
-          if should_fail_fast(&errors, failure_mode, #field_name) {
-       			return Err(errors);
-       	  }
+  		  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = nested_scope(|| <#field_type as ValidateAndModificate>::validate_and_modificate(#reference)) {
+  				#nested_errors
   		  }
   		};
 
@@ -110,17 +105,8 @@ impl<'a> AbstractValidationFactory for ModificationFactory<'a> {
 			#[rustfmt::skip]
   		let result = quote! {
         let _ref = &mut #reference;
-  		  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = <#field_type as ValidateAndModificate>::validate_and_modificate(_ref) {
-  				let error = NestedValidationError::from_with_code(
-  					e,
-  					#field_name,
-            #nested_code
-  				);
-
-  			  append_error(&mut errors, error.into(), failure_mode, #field_name);
-          if should_fail_fast(&errors, failure_mode, #field_name) {
-       			return Err(errors);
-       	  }
+  		  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = nested_scope(|| <#field_type as ValidateAndModificate>::validate_and_modificate(_ref)) {
+  				#nested_errors
   		  }
   		};
 
@@ -7,6 +7,7 @@ use crate::{
 		boilerplates::{
 			failure_mode::get_failure_mode_boilerplate,
 			modifications::get_modification_with_context_factory_boilerplates,
+			nested_errors::get_nested_errors_boilerplate,
 		},
 		core::AbstractValidationFactory,
 		extensions::modifications::get_modification_with_context_extensions,
@@ -15,18 +16,19 @@ use crate::{
 	fields::FieldAttributes,
 	imports::Import,
 	primitives::specials::nested::get_nested,
+	types::StructName,
 };
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Type, parse::ParseStream};
+use syn::{Type, parse::ParseStream};
 
 pub struct ModificationWithContextFactory<'a> {
-	struct_name: &'a Ident,
+	struct_name: &'a StructName,
 	context_type: &'a Type,
 }
 
 impl<'a> ModificationWithContextFactory<'a> {
-	pub fn new(struct_name: &'a Ident, context_type: &'a Type) -> Self {
+	pub fn new(struct_name: &'a StructName, context_type: &'a Type) -> Self {
 		Self {
 			struct_name,
 			context_type,
@@ -41,6 +43,7 @@ impl<'a> AbstractValidationFactory for ModificationWithContextFactory<'a> {
 		attributes: &ValidationAttributes,
 		imports: &RefCell<ImportsSet>,
 	) -> Output {
+		let impl_generics = self.struct_name.impl_generics();
 		imports.borrow_mut().add(Import::ValidyCore);
 		imports.borrow_mut().add(Import::ValidySettings);
 		imports.borrow_mut().add(Import::ValidyHelpers);
@@ -64,7 +67,7 @@ impl<'a> AbstractValidationFactory for ModificationWithContextFactory<'a> {
 		  const _: () = {
 				#imports
 
-  			impl ValidateAndModificateWithContext<#context_type> for #struct_name {
+  			impl #impl_generics ValidateAndModificateWithContext<#context_type> for #struct_name {
   			  fn validate_and_modificate_with_context(&mut self, context: &#context_type) -> Result<(), ValidationErrors> {
      				let mut errors = ValidationErrors::new();
             let failure_mode = #failure_mode;
@@ -79,7 +82,7 @@ impl<'a> AbstractValidationFactory for ModificationWithContextFactory<'a> {
   				}
   			}
 
-  			impl SpecificValidateAndModificateWithContext for #struct_name {
+  			impl #impl_generics SpecificValidateAndModificateWithContext for #struct_name {
           type Context = #context_type;
   			  fn specific_validate_and_modificate_with_context(&mut self, context: &#context_type) -> Result<(), ValidationErrors> {
             <#struct_name as ValidateAndModificateWithContext<#context_type>>::validate_and_modificate_with_context(self, context)
@@ -98,24 +101,16 @@ impl<'a> AbstractValidationFactory for ModificationWithContextFactory<'a> {
 	fn create_nested(&self, input: ParseStream, field: &mut FieldAttributes) -> TokenStream {
 		let reference = field.get_reference();
 		let field_name = field.get_name();
-		let (field_type, _, nested_code) = get_nested(input);
+		let (field_type, _, nested_code) = get_nested(input, field);
+		let nested_errors = get_nested_errors_boilerplate(field, &nested_code);
 		let context_type = self.context_type;
 
 		if field.is_ref() {
 			field.set_is_ref(true);
 			#[rustfmt::skip]
   		let result = quote! {
-  		  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = <#field_type as ValidateAndModificateWithContext<#context_type>>::validate_and_modificate_with_context(#reference, context) {
-  				let error = NestedValidationError::from_with_code(
-  					e,
-  					#field_name,
-            #nested_code
-  				);
-
-  			  append_error(&mut errors, error.into(), failure_mode, #field_name);
-          if should_fail_fast(&errors, failure_mode, #field_name) {
-       			return Err(errors);
-       	  }
+  		  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = nested_scope(|| <#field_type as ValidateAndModificateWithContext<#context_type>>::validate_and_modificate_with_context(#reference, context)) {
+  				#nested_errors
   		  }
   		};
 
@@ -125,17 +120,8 @@ impl<'a> AbstractValidationFactory for ModificationWithContextFactory<'a> {
 			#[rustfmt::skip]
   		let result = quote! {
         let _ref = &mut #reference;
-  		  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = <#field_type as ValidateAndModificateWithContext<#context_type>>::validate_and_modificate_with_context(_ref, context) {
-  				let error = NestedValidationError::from_with_code(
-  					e,
-  					#field_name,
-            #nested_code
-  				);
-
-  			  append_error(&mut errors, error.into(), failure_mode, #field_name);
-          if should_fail_fast(&errors, failure_mode, #field_name) {
-       			return Err(errors);
-       	  }
+  		  if can_continue(&errors, failure_mode, #field_name) && let Err(e) = nested_scope(|| <#field_type as ValidateAndModificateWithContext<#context_type>>::validate_and_modificate_with_context(_ref, context)) {
+  				#nested_errors
   		  }
   		};
 
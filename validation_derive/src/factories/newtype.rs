@@ -0,0 +1,245 @@
+use std::cell::RefCell;
+
+use crate::{
+	ImportsSet, Output,
+	attributes::ValidationAttributes,
+	factories::{
+		boilerplates::failure_mode::get_failure_mode_boilerplate, core::AbstractValidationFactory,
+		modification::ModificationFactory, others::modifications::ModificationsCodeFactory,
+	},
+	fields::FieldAttributes,
+	imports::{Import, import_validy_core},
+	types::StructName,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Generics, Type, WherePredicate, parse::ParseStream, parse_quote};
+
+#[derive(Default)]
+pub struct NewtypeOptions {
+	pub from_str: bool,
+	pub display: bool,
+}
+
+pub struct NewtypeFactory<'a> {
+	struct_name: &'a StructName,
+	generics: &'a Generics,
+	inner_type: &'a Type,
+	options: NewtypeOptions,
+}
+
+impl<'a> NewtypeFactory<'a> {
+	pub fn new(
+		struct_name: &'a StructName,
+		generics: &'a Generics,
+		inner_type: &'a Type,
+		options: NewtypeOptions,
+	) -> Self {
+		Self {
+			struct_name,
+			generics,
+			inner_type,
+			options,
+		}
+	}
+
+	fn is_generic_inner_type(&self) -> bool {
+		self.generics
+			.type_params()
+			.any(|param| matches!(self.inner_type, Type::Path(path) if path.path.is_ident(&param.ident)))
+	}
+
+	fn bounded_generics(&self, bound: WherePredicate) -> Generics {
+		let mut generics = self.generics.clone();
+		generics.make_where_clause().predicates.push(bound);
+		generics
+	}
+}
+
+impl<'a> AbstractValidationFactory for NewtypeFactory<'a> {
+	fn init(&mut self, _: &DeriveInput, _: &ValidationAttributes) {}
+
+	fn create(
+		&self,
+		mut fields: Vec<FieldAttributes>,
+		attributes: &ValidationAttributes,
+		imports: &RefCell<ImportsSet>,
+	) -> Output {
+		imports.borrow_mut().add(Import::ValidyCore);
+		imports.borrow_mut().add(Import::ValidySettings);
+		imports.borrow_mut().add(Import::ValidyHelpers);
+
+		let struct_name = self.struct_name;
+		let inner_type = self.inner_type;
+		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+		let generics = self.generics;
+		let valid_newtype = import_validy_core("ValidNewtype");
+
+		let mut code_factory = ModificationsCodeFactory(&mut fields);
+		let operations = code_factory.operations();
+		let imports = imports.borrow().create();
+
+		let failure_mode = get_failure_mode_boilerplate(attributes);
+
+		let from_str = if self.options.from_str {
+			let from_str_generics = self.bounded_generics(parse_quote! { #inner_type: ::std::str::FromStr });
+			let (from_str_impl, _, from_str_where) = from_str_generics.split_for_impl();
+
+			#[rustfmt::skip]
+			let result = quote! {
+				impl #from_str_impl ::std::str::FromStr for #struct_name #from_str_where {
+				  type Err = ValidationErrors;
+
+				  fn from_str(value: &str) -> Result<Self, Self::Err> {
+						match value.parse::<#inner_type>() {
+						  Ok(value) => <Self as #valid_newtype>::try_new(value),
+						  Err(_) => {
+								let error = ValidationError::builder()
+								  .with_field("value")
+								  .as_simple("parse")
+								  .with_message("invalid format")
+								  .build();
+
+								let mut errors = ValidationErrors::new();
+								errors.insert("value".into(), vec![error.into()]);
+								Err(errors)
+						  }
+						}
+				  }
+				}
+			};
+
+			result
+		} else {
+			quote! {}
+		};
+
+		let display = if self.options.display {
+			let display_generics = self.bounded_generics(parse_quote! { #inner_type: ::std::fmt::Display });
+			let (display_impl, _, display_where) = display_generics.split_for_impl();
+
+			#[rustfmt::skip]
+			let result = quote! {
+				impl #display_impl ::std::fmt::Display for #struct_name #display_where {
+				  fn fmt(&self, formatter: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+						::std::fmt::Display::fmt(&self.0, formatter)
+				  }
+				}
+			};
+
+			result
+		} else {
+			quote! {}
+		};
+
+		let serialize_generics = self.bounded_generics(parse_quote! { #inner_type: Serialize });
+		let (serialize_impl, _, serialize_where) = serialize_generics.split_for_impl();
+		let mut deserialize_generics = self.bounded_generics(parse_quote! { #inner_type: Deserialize<'de> });
+		deserialize_generics.params.insert(0, parse_quote! { 'de });
+		let (deserialize_impl, _, deserialize_where) = deserialize_generics.split_for_impl();
+
+		let try_from = if self.is_generic_inner_type() {
+			quote! {}
+		} else {
+			#[rustfmt::skip]
+			let result = quote! {
+				impl #impl_generics TryFrom<#inner_type> for #struct_name #where_clause {
+				  type Error = ValidationErrors;
+
+				  fn try_from(value: #inner_type) -> Result<Self, Self::Error> {
+						<Self as #valid_newtype>::try_new(value)
+				  }
+				}
+			};
+
+			result
+		};
+
+		#[rustfmt::skip]
+		let result = quote! {
+		  const _: () = {
+				#imports
+				use ::std::ops::Deref;
+				use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+				struct Newtype #generics #where_clause {
+					value: #inner_type,
+				}
+
+				impl #impl_generics Newtype #ty_generics #where_clause {
+				  fn validate_and_modificate(&mut self) -> Result<(), ValidationErrors> {
+     				let mut errors = ValidationErrors::new();
+            let failure_mode = #failure_mode;
+
+  				  #(#operations)*
+
+  				  if errors.is_empty() {
+  						Ok(())
+  				  } else {
+      				Err(errors)
+  				  }
+				  }
+				}
+
+				impl #impl_generics #struct_name #where_clause {
+				  pub fn into_inner(self) -> #inner_type {
+						self.0
+				  }
+				}
+
+				impl #impl_generics #valid_newtype for #struct_name #where_clause {
+				  type Inner = #inner_type;
+
+				  fn try_new(value: #inner_type) -> Result<Self, ValidationErrors> {
+						let mut newtype = Newtype { value };
+						newtype.validate_and_modificate()?;
+						Ok(Self(newtype.value))
+				  }
+
+				  fn into_inner(self) -> #inner_type {
+						self.0
+				  }
+				}
+
+				#try_from
+
+				#from_str
+
+				impl #deserialize_impl Deserialize<'de> for #struct_name #deserialize_where {
+				  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+						let value = <#inner_type as Deserialize<'de>>::deserialize(deserializer)?;
+						<Self as #valid_newtype>::try_new(value).map_err(|errors| D::Error::custom(describe_errors(&errors)))
+				  }
+				}
+
+				impl #serialize_impl Serialize for #struct_name #serialize_where {
+				  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+						self.0.serialize(serializer)
+				  }
+				}
+
+				impl #impl_generics Deref for #struct_name #where_clause {
+				  type Target = #inner_type;
+
+				  fn deref(&self) -> &Self::Target {
+						&self.0
+				  }
+				}
+
+				impl #impl_generics AsRef<#inner_type> for #struct_name #where_clause {
+				  fn as_ref(&self) -> &#inner_type {
+						&self.0
+				  }
+				}
+
+				#display
+			};
+		};
+
+		result.into()
+	}
+
+	fn create_nested(&self, input: ParseStream, field: &mut FieldAttributes) -> TokenStream {
+		ModificationFactory::new(self.struct_name).create_nested(input, field)
+	}
+}
@@ -0,0 +1,109 @@
+use proc_macro_error::emit_error;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::Type;
+
+use crate::types::StructName;
+use crate::{attributes::ValidationAttributes, fields::FieldAttributes, primitives::commons::extract_inner_type};
+
+pub struct BuilderFactory<'a> {
+	struct_name: &'a StructName,
+	wrapper_ident: &'a StructName,
+}
+
+impl<'a> BuilderFactory<'a> {
+	pub fn new(struct_name: &'a StructName, wrapper_ident: &'a StructName) -> Self {
+		Self {
+			struct_name,
+			wrapper_ident,
+		}
+	}
+
+	pub fn create(&self, fields: &[FieldAttributes], attributes: &ValidationAttributes) -> (TokenStream, TokenStream) {
+		if !attributes.builder {
+			return (quote! {}, quote! {});
+		}
+
+		let struct_name = self.struct_name;
+		let wrapper_ident = self.wrapper_ident;
+		let builder_ident = struct_name.with_suffix("Builder");
+		let impl_generics = struct_name.impl_generics();
+		let where_clause = struct_name.where_clause();
+
+		if fields.iter().any(|field| field.get_ident().is_none()) {
+			emit_error!(Span::call_site(), "builder only supports structs with named fields");
+			return (quote! {}, quote! {});
+		}
+
+		let setters: Vec<TokenStream> = fields
+			.iter()
+			.map(|field| {
+				let field_name = field.get_field_ident();
+				let wrapper_type = field.get_initial_type();
+				let setter_type = extract_inner_type(&wrapper_type).unwrap_or(wrapper_type);
+
+				quote! {
+				  pub fn #field_name(mut self, #field_name: impl Into<#setter_type>) -> Self {
+						self.wrapper.#field_name = Some(#field_name.into());
+						self
+				  }
+				}
+			})
+			.collect();
+
+		let build = self.get_build(attributes.asynchronous, attributes.context.as_ref());
+
+		#[rustfmt::skip]
+		let builder_struct = quote! {
+		  #[derive(Default)]
+		  pub struct #builder_ident #where_clause {
+				wrapper: #wrapper_ident,
+		  }
+		};
+
+		#[rustfmt::skip]
+		let builder_impls = quote! {
+		  impl #impl_generics #struct_name #where_clause {
+				pub fn builder() -> #builder_ident {
+				  Default::default()
+				}
+		  }
+
+		  impl #impl_generics #builder_ident #where_clause {
+				#(#setters)*
+
+				#build
+		  }
+		};
+
+		(builder_struct, builder_impls)
+	}
+
+	fn get_build(&self, asynchronous: bool, context: Option<&Type>) -> TokenStream {
+		let struct_name = self.struct_name;
+		let wrapper_ident = self.wrapper_ident;
+
+		match (asynchronous, context) {
+			(false, None) => quote! {
+			  pub fn build(self) -> Result<#struct_name, ValidationErrors> {
+					<#struct_name as ValidateAndParse<#wrapper_ident>>::validate_and_parse(self.wrapper)
+			  }
+			},
+			(false, Some(context_type)) => quote! {
+			  pub fn build(self, context: &#context_type) -> Result<#struct_name, ValidationErrors> {
+					<#struct_name as ValidateAndParseWithContext<#wrapper_ident, #context_type>>::validate_and_parse_with_context(self.wrapper, context)
+			  }
+			},
+			(true, None) => quote! {
+			  pub async fn build_async(self) -> Result<#struct_name, ValidationErrors> {
+					<#struct_name as AsyncValidateAndParse<#wrapper_ident>>::async_validate_and_parse(self.wrapper).await
+			  }
+			},
+			(true, Some(context_type)) => quote! {
+			  pub async fn build_async(self, context: &#context_type) -> Result<#struct_name, ValidationErrors> {
+					<#struct_name as AsyncValidateAndParseWithContext<#wrapper_ident, #context_type>>::async_validate_and_parse_with_context(self.wrapper, context).await
+			  }
+			},
+		}
+	}
+}
@@ -1,4 +1,8 @@
+pub mod builders;
 pub mod defaults;
 pub mod modifications;
+pub mod multiparts;
+pub mod patches;
 pub mod payloads;
+pub mod reverses;
 pub mod wrappers;
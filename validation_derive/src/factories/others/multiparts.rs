@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Expr, Token};
+
+use crate::fields::FieldAttributes;
+use crate::imports::import_validy_helper;
+use crate::types::StructName;
+
+pub struct MultipartFactory<'a> {
+	wrapper_ident: &'a StructName,
+	struct_attributes: &'a [Attribute],
+	fields_attributes: &'a HashMap<String, Vec<Attribute>>,
+}
+
+impl<'a> MultipartFactory<'a> {
+	pub fn new(
+		wrapper_ident: &'a StructName,
+		struct_attributes: &'a [Attribute],
+		fields_attributes: &'a HashMap<String, Vec<Attribute>>,
+	) -> Self {
+		Self {
+			wrapper_ident,
+			struct_attributes,
+			fields_attributes,
+		}
+	}
+
+	pub fn create(&self, fields: &[FieldAttributes]) -> TokenStream {
+		let generic_params = self.wrapper_ident.generic_params();
+		let wrapper_ident = self.wrapper_ident;
+		let form_ident = self.wrapper_ident.with_suffix("Form");
+		let unknown_fields = import_validy_helper("UnknownFields");
+		let strict = (!self.is_strict()).then(|| quote! { #[try_from_multipart(strict)] });
+		let struct_attributes: Vec<&Attribute> = self
+			.struct_attributes
+			.iter()
+			.filter(|attribute| is_multipart_attribute(attribute))
+			.collect();
+
+		let field_declarations: Vec<TokenStream> = fields
+			.iter()
+			.map(|field| {
+				let name = field.get_name();
+				let field_type = field.get_initial_type();
+				let field_name = field.get_field_ident();
+				let field_attributes: Vec<&Attribute> = self
+					.fields_attributes
+					.get(&name.value())
+					.into_iter()
+					.flatten()
+					.filter(|attribute| is_multipart_attribute(attribute))
+					.collect();
+
+				quote! {
+				  #(#field_attributes)*
+				  #field_name: #field_type,
+				}
+			})
+			.collect();
+
+		let field_names: Vec<_> = fields.iter().map(|field| field.get_field_ident()).collect();
+
+		#[rustfmt::skip]
+		let result = quote! {
+		  const _: () = {
+				use ::axum_typed_multipart::{TryFromMultipartWithState, TypedMultipartError};
+				use ::axum::extract::multipart::Multipart;
+
+				#[derive(::axum_typed_multipart::TryFromMultipart)]
+				#(#struct_attributes)*
+				#strict
+				struct #form_ident {
+				  #(#field_declarations)*
+				}
+
+				#[::axum_typed_multipart::async_trait]
+				impl<#generic_params S: Sync> TryFromMultipartWithState<S> for #wrapper_ident
+				where
+				  #form_ident: TryFromMultipartWithState<S>,
+				{
+				  async fn try_from_multipart_with_state(
+						multipart: &mut Multipart,
+						state: &S,
+				  ) -> Result<Self, TypedMultipartError> {
+						match <#form_ident as TryFromMultipartWithState<S>>::try_from_multipart_with_state(multipart, state).await {
+						  Ok(form) => Ok(Self {
+								#(#field_names: form.#field_names,)*
+								unknown_fields: Default::default(),
+						  }),
+						  Err(TypedMultipartError::UnknownField { field_name }) => Ok(Self {
+								unknown_fields: #unknown_fields(vec![field_name]),
+								..Default::default()
+						  }),
+						  Err(error) => Err(error),
+						}
+				  }
+				}
+		  };
+		};
+
+		result
+	}
+
+	fn is_strict(&self) -> bool {
+		let mut strict = false;
+
+		for attribute in self.struct_attributes {
+			if attribute.path().is_ident("try_from_multipart") {
+				let _ = attribute.parse_nested_meta(|meta| {
+					if meta.path.is_ident("strict") {
+						strict = true;
+					} else if meta.input.peek(Token![=]) {
+						meta.value()?.parse::<Expr>()?;
+					}
+
+					Ok(())
+				});
+			}
+		}
+
+		strict
+	}
+}
+
+pub fn is_multipart_attribute(attribute: &Attribute) -> bool {
+	attribute.path().is_ident("try_from_multipart") || attribute.path().is_ident("form_data")
+}
@@ -0,0 +1,151 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+	attributes::ValidationAttributes,
+	factories::{
+		boilerplates::{failure_mode::get_failure_mode_boilerplate, unknown_fields::get_unknown_fields_boilerplate},
+		others::payloads::{get_newtype_update, get_siblings_snapshot},
+	},
+	fields::FieldAttributes,
+	types::StructName,
+};
+
+pub struct PatchFactory<'a> {
+	struct_name: &'a StructName,
+	patch_ident: &'a StructName,
+}
+
+impl<'a> PatchFactory<'a> {
+	pub fn new(struct_name: &'a StructName, patch_ident: &'a StructName) -> Self {
+		Self {
+			struct_name,
+			patch_ident,
+		}
+	}
+
+	pub fn create(&self, mut fields: Vec<FieldAttributes>, attributes: &ValidationAttributes) -> TokenStream {
+		if !attributes.partial {
+			return quote! {};
+		}
+
+		let struct_name = self.struct_name;
+		let patch_ident = self.patch_ident;
+		let failure_mode = get_failure_mode_boilerplate(attributes);
+		let unknown_fields = get_unknown_fields_boilerplate(attributes, quote! { self.unknown_fields });
+		let siblings = get_siblings_snapshot(&fields, quote! { self }, Some(quote! { target }));
+
+		let mut operations = Vec::<TokenStream>::new();
+		let mut commits = Vec::<TokenStream>::new();
+
+		for field in fields.iter_mut() {
+			let name = field.get_name();
+			let field_name = field.get_field_ident();
+			let reference = field.get_reference();
+			field.increment_modifications();
+			let field_operations = field.get_operations();
+			let new_reference = field.get_reference();
+			let unwrapped = field.get_unwrapped_reference();
+			let required_args = field.get_required_args();
+
+			let value = if field.is_ref() {
+				quote! { *#reference }
+			} else {
+				quote! { #reference }
+			};
+
+			let some_update = get_newtype_update(
+				field,
+				value.clone(),
+				|value| quote! { #new_reference = Some(Some(#value)); },
+			);
+			let update = get_newtype_update(field, value, |value| quote! { #new_reference = Some(#value); });
+
+			if field.is_option() {
+				operations.push(quote! {
+					let mut #new_reference = None;
+					match self.#field_name.take() {
+						Some(Some(mut #unwrapped)) => {
+							#(#field_operations)*
+							#some_update
+						}
+						Some(None) => #new_reference = Some(None),
+						None => {}
+					}
+				});
+			} else {
+				let code = &required_args.code;
+				let message = &required_args.message;
+
+				operations.push(quote! {
+					let mut #new_reference = None;
+					match self.#field_name.take() {
+						Some(Some(mut #unwrapped)) => {
+							#(#field_operations)*
+							#update
+						}
+						Some(None) => {
+							let error = ValidationError::builder()
+								.with_field(#name)
+								.as_simple(#code)
+								.with_message(#message)
+								.build();
+
+							append_error(&mut errors, error.into(), failure_mode, #name);
+							if should_fail_fast(&errors, failure_mode, #name) {
+								return Err(errors);
+							}
+						}
+						None => {}
+					}
+				});
+			}
+
+			commits.push(quote! {
+				if let Some(value) = #new_reference {
+					target.#field_name = value;
+				}
+			});
+		}
+
+		let signature = match (attributes.asynchronous, attributes.context.as_ref()) {
+			(false, None) => quote! {
+			  pub fn apply_to(mut self, target: &mut #struct_name) -> Result<(), ValidationErrors>
+			},
+			(false, Some(context_type)) => quote! {
+			  pub fn apply_to(mut self, target: &mut #struct_name, context: &#context_type) -> Result<(), ValidationErrors>
+			},
+			(true, None) => quote! {
+			  pub async fn apply_to(mut self, target: &mut #struct_name) -> Result<(), ValidationErrors>
+			},
+			(true, Some(context_type)) => quote! {
+			  pub async fn apply_to(mut self, target: &mut #struct_name, context: &#context_type) -> Result<(), ValidationErrors>
+			},
+		};
+
+		#[rustfmt::skip]
+		let result = quote! {
+		  impl #patch_ident {
+				#signature {
+				  let mut errors = ValidationErrors::new();
+				  let failure_mode = #failure_mode;
+
+				  #unknown_fields
+
+				  #siblings
+
+				  #(#operations)*
+
+				  if errors.is_empty() {
+						#(#commits)*
+						Ok(())
+				  } else {
+						Err(errors)
+				  }
+				}
+		  }
+		};
+
+		result
+	}
+}
@@ -1,7 +1,7 @@
-use crate::fields::FieldAttributes;
+use crate::{fields::FieldAttributes, imports::import_validy_core};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::Ident;
+use syn::{Ident, ext::IdentExt};
 
 pub struct PayloadsCodeFactory<'a>(pub &'a mut Vec<FieldAttributes>);
 
@@ -20,13 +20,22 @@ impl<'a> PayloadsCodeFactory<'a> {
 				let unwrapped = field.get_unwrapped_reference();
 				let required_args = field.get_required_args();
 
-				let update = if field.is_ref() {
-					quote! { #new_reference = Some(*#reference); }
+				let value = if field.is_ref() {
+					quote! { *#reference }
 				} else {
-					quote! { #new_reference = Some(#reference); }
+					quote! { #reference }
 				};
 
-				if field.is_option() {
+				let update = get_newtype_update(field, value, |value| quote! { #new_reference = Some(#value); });
+
+				if let Some(default) = field.get_default() {
+					quote! {
+						let mut #new_reference: #wrapper_final_type = None;
+						let mut #unwrapped = #wrapper_reference.take().unwrap_or_else(|| #default);
+						#(#operations)*
+						#update
+					}
+				} else if field.is_option() {
 					quote! {
 						let mut #new_reference: #wrapper_final_type = None;
 						if let Some(mut #unwrapped) = #wrapper_reference.take() {
@@ -61,6 +70,10 @@ impl<'a> PayloadsCodeFactory<'a> {
 			.collect()
 	}
 
+	pub fn siblings(&self) -> TokenStream {
+		get_siblings_snapshot(self.0, quote! { wrapper }, None)
+	}
+
 	pub fn commit(&self) -> TokenStream {
 		let commits: Vec<TokenStream> = self
 			.0
@@ -69,7 +82,7 @@ impl<'a> PayloadsCodeFactory<'a> {
 			.map(|field| {
 				let reference = field.get_reference();
 				let name = field.get_name();
-				let field_name = Ident::new(&name.value(), Span::call_site());
+				let field_name = field.get_field_ident();
 
 				if field.is_option() {
 					quote! {
@@ -105,3 +118,64 @@ impl<'a> PayloadsCodeFactory<'a> {
 		commit
 	}
 }
+
+pub fn get_newtype_update(
+	field: &FieldAttributes,
+	value: TokenStream,
+	update: impl FnOnce(TokenStream) -> TokenStream,
+) -> TokenStream {
+	let Some(newtype) = field.get_newtype() else {
+		return update(value);
+	};
+
+	let field_name = field.get_name();
+	let valid_newtype = import_validy_core("ValidNewtype");
+	let update = update(quote! { value });
+
+	#[rustfmt::skip]
+	let result = quote! {
+		match <#newtype as #valid_newtype>::try_new(#value) {
+		  Ok(value) => {
+				#update
+		  }
+		  Err(e) => {
+				append_newtype_errors(&mut errors, e, failure_mode, #field_name);
+				if should_fail_fast(&errors, failure_mode, #field_name) {
+				  return Err(errors);
+				}
+		  }
+		}
+	};
+
+	result
+}
+
+pub fn get_siblings_snapshot(
+	fields: &[FieldAttributes],
+	source: TokenStream,
+	fallback: Option<TokenStream>,
+) -> TokenStream {
+	let mut siblings = Vec::<&Ident>::new();
+	for sibling in fields.iter().flat_map(|field| field.get_siblings()) {
+		if !siblings.contains(&sibling) {
+			siblings.push(sibling);
+		}
+	}
+
+	let snapshots = siblings.into_iter().map(|sibling| {
+		let ident = Ident::new(&format!("sibling_{}", sibling.unraw()), Span::call_site());
+		match &fallback {
+			Some(fallback) => quote! {
+				let #ident = #source.#sibling
+					.as_sibling()
+					.or_else(|| #fallback.#sibling.as_sibling())
+					.map(ToString::to_string);
+			},
+			None => quote! {
+				let #ident = #source.#sibling.as_sibling().map(ToString::to_string);
+			},
+		}
+	});
+
+	quote! { #(#snapshots)* }
+}
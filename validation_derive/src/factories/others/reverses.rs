@@ -0,0 +1,94 @@
+use proc_macro_error::emit_error;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+use crate::types::StructName;
+use crate::{attributes::ValidationAttributes, fields::FieldAttributes, imports::import_validy_core};
+
+pub struct ReverseFactory<'a> {
+	struct_name: &'a StructName,
+	wrapper_ident: &'a StructName,
+}
+
+impl<'a> ReverseFactory<'a> {
+	pub fn new(struct_name: &'a StructName, wrapper_ident: &'a StructName) -> Self {
+		Self {
+			struct_name,
+			wrapper_ident,
+		}
+	}
+
+	pub fn create(&self, fields: &[FieldAttributes], attributes: &ValidationAttributes) -> TokenStream {
+		let impl_generics = self.struct_name.impl_generics();
+		if !attributes.reversible {
+			return quote! {};
+		}
+
+		let struct_name = self.struct_name;
+		let wrapper_ident = self.wrapper_ident;
+
+		let conversions: Vec<TokenStream> = fields
+			.iter()
+			.map(|field| {
+				let name = field.get_name();
+				let field_name = field.get_field_ident();
+
+				let valid_newtype = import_validy_core("ValidNewtype");
+				let newtype = field
+					.get_newtype()
+					.map(|newtype| quote! { <#newtype as #valid_newtype>::into_inner(value) });
+				let unparses: Option<Vec<TokenStream>> = newtype
+					.into_iter()
+					.map(Some)
+					.chain(field.get_unparses().iter().rev().cloned())
+					.collect();
+				let Some(unparses) = unparses else {
+					emit_error!(
+						Span::call_site(),
+						"field '{}' has a parse rule without an unparse counterpart",
+						name.value()
+					);
+					return quote! {};
+				};
+
+				match (field.is_option(), unparses.is_empty()) {
+					(true, true) => quote! { #field_name: source.#field_name, },
+					(false, true) => quote! { #field_name: Some(source.#field_name), },
+					(true, false) => quote! {
+					  #field_name: source.#field_name.map(|value| {
+							#(let value = #unparses;)*
+							value
+					  }),
+					},
+					(false, false) => quote! {
+					  #field_name: Some({
+							let value = source.#field_name;
+							#(let value = #unparses;)*
+							value
+					  }),
+					},
+				}
+			})
+			.collect();
+
+		let unknown_fields = if attributes.deny_unknown_fields {
+			quote! { unknown_fields: Default::default(), }
+		} else {
+			quote! {}
+		};
+
+		#[rustfmt::skip]
+		let result = quote! {
+		  impl #impl_generics From<#struct_name> for #wrapper_ident {
+				fn from(source: #struct_name) -> Self {
+				  Self {
+						#(#conversions)*
+						#unknown_fields
+				  }
+				}
+		  }
+		};
+
+		result
+	}
+}
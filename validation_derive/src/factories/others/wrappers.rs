@@ -1,22 +1,34 @@
 use std::collections::HashMap;
 
 use proc_macro_error::emit_error;
-use proc_macro2::{Span, TokenStream};
-use quote::{format_ident, quote};
+use proc_macro2::TokenStream;
+use quote::quote;
 use syn::{
-	AttrStyle, Attribute, DeriveInput, Fields, Ident, Meta, Path, Token, parse_quote,
+	AttrStyle, Attribute, DeriveInput, Fields, Meta, Path, Token,
+	ext::IdentExt,
+	parse_quote,
 	punctuated::Punctuated,
 	spanned::Spanned,
 	token::{Bracket, Pound},
 };
 
-use crate::{attributes::ValidationAttributes, fields::FieldAttributes, get_fields};
+use crate::{
+	attributes::ValidationAttributes,
+	factories::others::multiparts::{MultipartFactory, is_multipart_attribute},
+	fields::FieldAttributes,
+	get_fields,
+	imports::import_validy_helper,
+	primitives::commons::{extract_inner_type, has_lifetime, is_optional_cow_str},
+	types::StructName,
+};
 
 #[derive(Default)]
 pub struct WrapperFactory {
 	struct_derives: Vec<Path>,
 	struct_attributes: Vec<Attribute>,
 	fields_attributes: HashMap<String, Vec<Attribute>>,
+	deny_unknown_fields: bool,
+	multipart: bool,
 }
 
 impl WrapperFactory {
@@ -50,7 +62,9 @@ impl WrapperFactory {
 			return WrapperFactory::default();
 		}
 
-		let native_derives = if attributes.multipart {
+		let native_derives = if attributes.multipart && attributes.deny_unknown_fields {
+			vec![parse_quote!(Default)]
+		} else if attributes.multipart {
 			vec![
 				parse_quote!(Default),
 				parse_quote!(::axum_typed_multipart::TryFromMultipart),
@@ -65,46 +79,148 @@ impl WrapperFactory {
 			struct_derives,
 			struct_attributes,
 			fields_attributes,
+			deny_unknown_fields: attributes.deny_unknown_fields,
+			multipart: attributes.multipart,
 		}
 	}
 
-	pub fn create<'a>(&self, name: &'a Ident, fields: &'a [FieldAttributes]) -> (TokenStream, Ident) {
+	pub fn create<'a>(&self, name: &'a StructName, fields: &'a [FieldAttributes]) -> (TokenStream, StructName) {
 		let struct_derives = &self.struct_derives;
-		let struct_attributes = &self.struct_attributes;
+		let custom_multipart = self.multipart && self.deny_unknown_fields;
+		let struct_attributes: Vec<&Attribute> = self
+			.struct_attributes
+			.iter()
+			.filter(|attribute| !custom_multipart || !is_multipart_attribute(attribute))
+			.collect();
 
-		let wrapper_ident = format_ident!("{}Wrapper", name);
+		let wrapper_ident = name.with_suffix("Wrapper");
+		let deserialize_borrowed_cow = import_validy_helper("deserialize_borrowed_cow").to_string();
 		let field_declarations: Vec<TokenStream> = fields
 			.iter()
 			.clone()
 			.map(|field| {
 				let name = field.get_name();
 				let field_type = field.get_initial_type();
-				let field_name = Ident::new(&name.value(), Span::call_site());
+				let field_name = field.get_field_ident();
 				let field_attributes: Vec<&Attribute> = self
 					.fields_attributes
 					.get(&name.value())
 					.into_iter()
 					.flatten()
+					.filter(|attribute| !custom_multipart || !is_multipart_attribute(attribute))
 					.collect();
+				let flatten = field.is_flatten().then(|| quote! { #[serde(flatten)] });
+				let borrow = match &field_type {
+					_ if self.multipart => None,
+					field_type if is_optional_cow_str(field_type) => {
+						Some(quote! { #[serde(default, borrow, deserialize_with = #deserialize_borrowed_cow)] })
+					}
+					field_type if has_lifetime(field_type) => Some(quote! { #[serde(borrow)] }),
+					_ => None,
+				};
 
 				quote! {
 				  #(#field_attributes)*
+				  #flatten
+				  #borrow
 				  pub #field_name: #field_type,
 				}
 			})
 			.collect();
 
+		let unknown_fields = self.get_unknown_fields_declaration();
+		let multipart = if custom_multipart {
+			MultipartFactory::new(&wrapper_ident, &self.struct_attributes, &self.fields_attributes).create(fields)
+		} else {
+			quote! {}
+		};
+
 		#[rustfmt::skip]
 		let wrapper_struct = quote! {
   		#[derive(#(#struct_derives),*)]
       #(#struct_attributes)*
   		pub struct #wrapper_ident {
   		  #(#field_declarations)*
+  		  #unknown_fields
   		}
+
+  		#multipart
 		};
 
 		(wrapper_struct, wrapper_ident)
 	}
+
+	fn get_unknown_fields_declaration(&self) -> TokenStream {
+		if !self.deny_unknown_fields {
+			return quote! {};
+		}
+
+		let unknown_fields = import_validy_helper("UnknownFields");
+
+		if self.multipart {
+			quote! {
+			  pub unknown_fields: #unknown_fields,
+			}
+		} else {
+			quote! {
+			  #[serde(flatten)]
+			  pub unknown_fields: #unknown_fields,
+			}
+		}
+	}
+
+	pub fn create_patch<'a>(
+		&self,
+		name: &'a StructName,
+		fields: &'a [FieldAttributes],
+		attributes: &ValidationAttributes,
+	) -> (TokenStream, StructName) {
+		let patch_ident = name.with_suffix("Patch");
+
+		if !attributes.partial {
+			return (quote! {}, patch_ident);
+		}
+
+		let struct_derives = &self.struct_derives;
+		let struct_attributes = &self.struct_attributes;
+		let deserialize_patch = import_validy_helper("deserialize_patch").to_string();
+
+		let field_declarations: Vec<TokenStream> = fields
+			.iter()
+			.map(|field| {
+				let name = field.get_name();
+				let wrapper_type = field.get_initial_type();
+				let field_type = extract_inner_type(&wrapper_type).unwrap_or(wrapper_type);
+				let field_name = field.get_field_ident();
+				let field_attributes: Vec<&Attribute> = self
+					.fields_attributes
+					.get(&name.value())
+					.into_iter()
+					.flatten()
+					.collect();
+
+				quote! {
+				  #(#field_attributes)*
+				  #[serde(default, deserialize_with = #deserialize_patch)]
+				  pub #field_name: Option<Option<#field_type>>,
+				}
+			})
+			.collect();
+
+		let unknown_fields = self.get_unknown_fields_declaration();
+
+		#[rustfmt::skip]
+		let patch_struct = quote! {
+  		#[derive(#(#struct_derives),*)]
+      #(#struct_attributes)*
+  		pub struct #patch_ident {
+  		  #(#field_declarations)*
+  		  #unknown_fields
+  		}
+		};
+
+		(patch_struct, patch_ident)
+	}
 }
 
 static NATIVE_FIELD_ATTRIBUTES: &[&str] = &["wrapper_attribute", "validate", "modificate", "parse", "special"];
@@ -140,7 +256,7 @@ fn get_attributes_by_fields(fields: &Fields) -> HashMap<String, Vec<Attribute>>
 		.enumerate()
 		.fold(HashMap::new(), |mut accumulator, (index, field)| {
 			let name: String = match &field.ident {
-				Some(ident) => ident.to_string(),
+				Some(ident) => ident.unraw().to_string(),
 				None => index.to_string(),
 			};
 
@@ -4,27 +4,33 @@ use crate::{
 	ImportsSet, Output,
 	attributes::ValidationAttributes,
 	factories::{
-		boilerplates::{failure_mode::get_failure_mode_boilerplate, payloads::get_payload_factory_boilerplates},
+		boilerplates::{
+			failure_mode::get_failure_mode_boilerplate, nested_errors::get_nested_errors_boilerplate,
+			payloads::get_payload_factory_boilerplates, unknown_fields::get_unknown_fields_boilerplate,
+		},
 		core::AbstractValidationFactory,
 		extensions::payloads::get_payload_extensions,
-		others::{payloads::PayloadsCodeFactory, wrappers::WrapperFactory},
+		others::{
+			builders::BuilderFactory, patches::PatchFactory, payloads::PayloadsCodeFactory, reverses::ReverseFactory,
+			wrappers::WrapperFactory,
+		},
 	},
 	fields::FieldAttributes,
-	imports::Import,
+	imports::{Import, import_validy_core},
 	primitives::specials::nested::get_nested,
+	types::StructName,
 };
-use proc_macro_error::emit_error;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{DeriveInput, Ident, parse::ParseStream};
+use syn::{DeriveInput, parse::ParseStream, parse_quote};
 
 pub struct PayloadFactory<'a> {
-	struct_name: &'a Ident,
+	struct_name: &'a StructName,
 	wrapper_factory: WrapperFactory,
 }
 
 impl<'a> PayloadFactory<'a> {
-	pub fn new(struct_name: &'a Ident) -> Self {
+	pub fn new(struct_name: &'a StructName) -> Self {
 		Self {
 			struct_name,
 			wrapper_factory: WrapperFactory::default(),
@@ -43,6 +49,7 @@ impl<'a> AbstractValidationFactory for PayloadFactory<'a> {
 		attributes: &ValidationAttributes,
 		imports: &RefCell<ImportsSet>,
 	) -> Output {
+		let impl_generics = self.struct_name.impl_generics();
 		imports.borrow_mut().add(Import::ValidyCore);
 		imports.borrow_mut().add(Import::ValidySettings);
 		imports.borrow_mut().add(Import::ValidyHelpers);
@@ -51,29 +58,42 @@ impl<'a> AbstractValidationFactory for PayloadFactory<'a> {
 		let struct_name = self.struct_name;
 
 		let (wrapper_struct, wrapper_ident) = self.wrapper_factory.create(struct_name, &fields);
+		let (builder_struct, builder_impls) =
+			BuilderFactory::new(struct_name, &wrapper_ident).create(&fields, attributes);
+		let reverse = ReverseFactory::new(struct_name, &wrapper_ident).create(&fields, attributes);
+		let (patch_struct, patch_ident) = self.wrapper_factory.create_patch(struct_name, &fields, attributes);
+		let patch = PatchFactory::new(struct_name, &patch_ident).create(fields.clone(), attributes);
 		let mut code_factory = PayloadsCodeFactory(&mut fields);
 
 		let extensions = get_payload_extensions(self.struct_name, attributes, &wrapper_ident, imports);
 
+		let siblings = code_factory.siblings();
 		let operations = code_factory.operations();
 		let commit = code_factory.commit();
 		let imports = imports.borrow().create();
 
 		let boilerplates = get_payload_factory_boilerplates(struct_name, &wrapper_ident);
 		let failure_mode = get_failure_mode_boilerplate(attributes);
+		let unknown_fields = get_unknown_fields_boilerplate(attributes, quote! { wrapper.unknown_fields });
 
 		#[rustfmt::skip]
 		let result = quote! {
 		  #wrapper_struct
+		  #builder_struct
+		  #patch_struct
 
 			const _: () = {
   		  #imports
 
-        impl ValidateAndParse<#wrapper_ident> for #struct_name {
+        impl #impl_generics ValidateAndParse<#wrapper_ident> for #struct_name {
           fn validate_and_parse(mut wrapper: #wrapper_ident) -> Result<Self, ValidationErrors> {
     				let mut errors = ValidationErrors::new();
             let failure_mode = #failure_mode;
 
+            #unknown_fields
+
+            #siblings
+
             #(#operations)*
 
             if errors.is_empty() {
@@ -84,7 +104,7 @@ impl<'a> AbstractValidationFactory for PayloadFactory<'a> {
           }
         }
 
-        impl SpecificValidateAndParse for #struct_name {
+        impl #impl_generics SpecificValidateAndParse for #struct_name {
           type Wrapper = #wrapper_ident;
           fn specific_validate_and_parse(mut wrapper: #wrapper_ident) -> Result<Self, ValidationErrors> {
             <#struct_name as ValidateAndParse<#wrapper_ident>>::validate_and_parse(wrapper)
@@ -94,6 +114,12 @@ impl<'a> AbstractValidationFactory for PayloadFactory<'a> {
         #boilerplates
 
         #extensions
+
+        #builder_impls
+
+        #reverse
+
+        #patch
 			};
 		};
 
@@ -105,36 +131,29 @@ impl<'a> AbstractValidationFactory for PayloadFactory<'a> {
 		field.increment_modifications();
 		let new_reference = field.get_reference();
 		let field_name = field.get_name();
-		let (field_type, wrapper_type, nested_code) = get_nested(input);
+		let (field_type, wrapper_type, nested_code) = get_nested(input, field);
+		let nested_errors = get_nested_errors_boilerplate(field, &nested_code);
 
-		if wrapper_type.is_none() {
-			emit_error!(input.span(), "needs the wrapper type");
-		}
+		let specific_trait = import_validy_core("SpecificValidateAndParse");
+		let wrapper_type = wrapper_type.unwrap_or_else(|| parse_quote! { <#field_type as #specific_trait>::Wrapper });
+		field.infer_initial_type(&field_type, &wrapper_type);
+		field.add_unparse(Some(quote! { unparse_nested::<_, #field_type, #wrapper_type>(value) }));
 
 		if field.is_ref() {
 			field.set_is_ref(false);
 			#[rustfmt::skip]
   		let result = quote! {
-  			let mut #new_reference = #field_type::default();
+  			let mut #new_reference = Default::default();
   			let result = if can_continue(&errors, failure_mode, #field_name) {
-          <#field_type as ValidateAndParse<#wrapper_type>>::validate_and_parse(*#reference)
+          parse_nested::<_, #wrapper_type, _>(*#reference, |wrapper| <#field_type as ValidateAndParse<#wrapper_type>>::validate_and_parse(wrapper))
   			} else {
-  			  Ok(#field_type::default())
+  			  Ok(Default::default())
   			};
 
   			match result {
   			  Ok(value) => #new_reference = value,
   				Err(e) =>  {
-    				let error = NestedValidationError::from_with_code(
-     					e,
-     					#field_name,
-              #nested_code
-    				);
-
-  			    append_error(&mut errors, error.into(), failure_mode, #field_name);
-            if should_fail_fast(&errors, failure_mode, #field_name) {
-         			return Err(errors);
-         	  }
+    				#nested_errors
   			  },
   			}
   		};
@@ -144,26 +163,17 @@ impl<'a> AbstractValidationFactory for PayloadFactory<'a> {
 			field.set_is_ref(false);
 			#[rustfmt::skip]
   		let result = quote! {
-  			let mut #new_reference = #field_type::default();
+  			let mut #new_reference = Default::default();
   			let result = if can_continue(&errors, failure_mode, #field_name) {
-          <#field_type as ValidateAndParse<#wrapper_type>>::validate_and_parse(#reference)
+          parse_nested::<_, #wrapper_type, _>(#reference, |wrapper| <#field_type as ValidateAndParse<#wrapper_type>>::validate_and_parse(wrapper))
   			} else {
-  			  Ok(#field_type::default())
+  			  Ok(Default::default())
   			};
 
   			match result {
   			  Ok(value) => #new_reference = value,
   				Err(e) =>  {
-    				let error = NestedValidationError::from_with_code(
-     					e,
-     					#field_name,
-              #nested_code
-    				);
-
-  			    append_error(&mut errors, error.into(), failure_mode, #field_name);
-            if should_fail_fast(&errors, failure_mode, #field_name) {
-         			return Err(errors);
-         	  }
+    				#nested_errors
   			  },
   			}
   		};
@@ -1,56 +1,81 @@
 use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, quote};
-use syn::{Ident, Index, LitStr, Type, parse_quote};
-
-use crate::{attributes::ValidationAttributes, primitives::option::required::RequiredArgs};
-
+use quote::{ToTokens, format_ident, quote};
+use syn::{Ident, Index, LitStr, Type, ext::IdentExt, parse_quote};
+
+use crate::{
+	attributes::ValidationAttributes,
+	primitives::{
+		commons::{extract_inner_type, replace_type},
+		option::required::RequiredArgs,
+	},
+};
+
+#[derive(Clone)]
 pub struct FieldAttributes {
 	ignore: bool,
+	flatten: bool,
+	declared_type: Type,
 	final_type: Type,
 	current_type: Type,
 	initial_type: Option<Type>,
+	newtype: Option<Type>,
 	required_args: RequiredArgs,
+	default: Option<TokenStream>,
 	payload: bool,
 	is_ref: bool,
 	operations: Vec<TokenStream>,
+	unparses: Vec<Option<TokenStream>>,
 	name: Option<Ident>,
 	index: Option<Index>,
 	scopes: usize,
 	modifications: usize,
+	siblings: Vec<Ident>,
 }
 
 impl FieldAttributes {
 	pub fn from_named(final_type: &Type, name: &Ident, attributes: &ValidationAttributes) -> Self {
 		FieldAttributes {
 			ignore: false,
+			flatten: false,
+			declared_type: final_type.clone(),
 			final_type: final_type.clone(),
 			current_type: final_type.clone(),
 			initial_type: None,
+			newtype: None,
 			required_args: RequiredArgs::default(),
+			default: None,
 			payload: attributes.payload,
 			is_ref: false,
 			operations: Vec::new(),
+			unparses: Vec::new(),
 			name: Some(name.clone()),
 			index: None,
 			scopes: 0,
 			modifications: 0,
+			siblings: Vec::new(),
 		}
 	}
 
 	pub fn from_unamed(final_type: &Type, index: &Index, attributes: &ValidationAttributes) -> Self {
 		FieldAttributes {
 			ignore: false,
+			flatten: false,
+			declared_type: final_type.clone(),
 			final_type: final_type.clone(),
 			current_type: final_type.clone(),
 			initial_type: None,
+			newtype: None,
 			required_args: RequiredArgs::default(),
+			default: None,
 			payload: attributes.payload,
 			is_ref: false,
 			operations: Vec::new(),
+			unparses: Vec::new(),
 			name: None,
 			index: Some(index.clone()),
 			scopes: 0,
 			modifications: 0,
+			siblings: Vec::new(),
 		}
 	}
 
@@ -58,6 +83,22 @@ impl FieldAttributes {
 		self.operations.push(operation);
 	}
 
+	pub fn add_unparse(&mut self, unparse: Option<TokenStream>) {
+		self.unparses.push(unparse);
+	}
+
+	pub fn get_unparses_count(&self) -> usize {
+		self.unparses.len()
+	}
+
+	pub fn take_unparses(&mut self, from: usize) -> Vec<Option<TokenStream>> {
+		self.unparses.split_off(from)
+	}
+
+	pub fn get_unparses(&self) -> &[Option<TokenStream>] {
+		&self.unparses
+	}
+
 	pub fn set_is_ref(&mut self, is_ref: bool) {
 		self.is_ref = is_ref;
 	}
@@ -83,6 +124,14 @@ impl FieldAttributes {
 		self.required_args = required_args;
 	}
 
+	pub fn get_default(&self) -> Option<&TokenStream> {
+		self.default.as_ref()
+	}
+
+	pub fn set_default(&mut self, default: TokenStream) {
+		self.default = Some(default);
+	}
+
 	pub fn is_payload(&self) -> bool {
 		self.payload
 	}
@@ -97,6 +146,14 @@ impl FieldAttributes {
 		false
 	}
 
+	pub fn set_flatten(&mut self, flatten: bool) {
+		self.flatten = flatten;
+	}
+
+	pub fn is_flatten(&self) -> bool {
+		self.flatten
+	}
+
 	pub fn set_ignore(&mut self, ignore: bool) {
 		self.ignore = ignore;
 	}
@@ -105,10 +162,34 @@ impl FieldAttributes {
 		&self.current_type
 	}
 
+	pub fn set_newtype(&mut self, newtype: &Type) {
+		self.newtype = Some(newtype.clone());
+	}
+
+	pub fn get_newtype(&self) -> Option<&Type> {
+		self.newtype.as_ref()
+	}
+
 	pub fn set_initial_type(&mut self, initial_type: &Type) {
 		self.initial_type = Some(initial_type.clone());
 	}
 
+	pub fn infer_initial_type(&mut self, from: &Type, to: &Type) {
+		if self.initial_type.is_some() {
+			return;
+		}
+
+		let declared_type = match self.is_option() {
+			true => extract_inner_type(&self.declared_type).unwrap_or(self.declared_type.clone()),
+			false => self.declared_type.clone(),
+		};
+
+		let initial_type = replace_type(&declared_type, from, to);
+		if initial_type.to_token_stream().to_string() != declared_type.to_token_stream().to_string() {
+			self.initial_type = Some(initial_type);
+		}
+	}
+
 	pub fn set_current_type(&mut self, current_type: &Type) {
 		self.current_type = current_type.clone();
 		self.final_type = current_type.clone();
@@ -152,9 +233,21 @@ impl FieldAttributes {
 		}
 	}
 
+	pub fn get_ident(&self) -> Option<&Ident> {
+		self.name.as_ref()
+	}
+
+	pub fn get_field_ident(&self) -> Ident {
+		match (&self.name, &self.index) {
+			(Some(name), _) => name.clone(),
+			(_, Some(index)) => format_ident!("_{}", index.index),
+			_ => panic!("needs a field name or index"),
+		}
+	}
+
 	pub fn get_name(&self) -> LitStr {
 		match (&self.name, &self.index) {
-			(Some(name), _) => LitStr::new(&name.to_string(), Span::call_site()),
+			(Some(name), _) => LitStr::new(&name.unraw().to_string(), Span::call_site()),
 			(_, Some(index)) => LitStr::new(&index.index.to_string(), Span::call_site()),
 			_ => panic!("needs a field name or index"),
 		}
@@ -172,6 +265,27 @@ impl FieldAttributes {
 		self.scopes -= 1;
 	}
 
+	pub fn get_scopes(&self) -> usize {
+		self.scopes
+	}
+
+	pub fn add_sibling(&mut self, sibling: &Ident) -> TokenStream {
+		if !self.siblings.contains(sibling) {
+			self.siblings.push(sibling.clone());
+		}
+
+		if self.payload {
+			let ident = Ident::new(&format!("sibling_{}", sibling.unraw()), Span::call_site());
+			quote! { #ident }
+		} else {
+			quote! { self.#sibling }
+		}
+	}
+
+	pub fn get_siblings(&self) -> &[Ident] {
+		&self.siblings
+	}
+
 	pub fn get_wrapper_reference(&self) -> TokenStream {
 		let suffix: &dyn ToTokens = match (&self.name, &self.index) {
 			(Some(name), _) => name,
@@ -194,7 +308,7 @@ impl FieldAttributes {
 
 	pub fn get_unwrapped_reference(&self) -> Ident {
 		let name = match (&self.name, &self.index) {
-			(Some(name), _) => name.to_string(),
+			(Some(name), _) => name.unraw().to_string(),
 			(_, Some(index)) => index.index.to_string(),
 			_ => panic!("needs a field name or index"),
 		};
@@ -214,7 +328,7 @@ impl FieldAttributes {
 			(false, 0, 0) => quote! { self.#suffix },
 			(true, 0, 0) => {
 				let name = match (&self.name, &self.index) {
-					(Some(name), _) => name.to_string(),
+					(Some(name), _) => name.unraw().to_string(),
 					(_, Some(index)) => index.index.to_string(),
 					_ => panic!("needs a field name or index"),
 				};
@@ -225,7 +339,7 @@ impl FieldAttributes {
 			}
 			(_, scopes, modifications) => {
 				let name = match (&self.name, &self.index) {
-					(Some(name), _) => name.to_string(),
+					(Some(name), _) => name.unraw().to_string(),
 					(_, Some(index)) => index.index.to_string(),
 					_ => panic!("needs a field name or index"),
 				};
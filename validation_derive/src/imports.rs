@@ -138,3 +138,29 @@ fn import_async_trait() -> TokenStream {
 		}
 	}
 }
+
+pub fn import_validy_helper(helper: &str) -> TokenStream {
+	let found_crate = crate_name("validy").expect("validy is present in `Cargo.toml`");
+	let helper_tokens: TokenStream = parse_str(helper).expect("invalid helper path");
+
+	match found_crate {
+		FoundCrate::Itself => quote!(::validy::utils::helpers::#helper_tokens),
+		FoundCrate::Name(name) => {
+			let ident = Ident::new(&name, Span::call_site());
+			quote!(#ident::utils::helpers::#helper_tokens)
+		}
+	}
+}
+
+pub fn import_validy_core(item: &str) -> TokenStream {
+	let found_crate = crate_name("validy").expect("validy is present in `Cargo.toml`");
+	let item_tokens: TokenStream = parse_str(item).expect("invalid core path");
+
+	match found_crate {
+		FoundCrate::Itself => quote!(::validy::core::#item_tokens),
+		FoundCrate::Name(name) => {
+			let ident = Ident::new(&name, Span::call_site());
+			quote!(#ident::core::#item_tokens)
+		}
+	}
+}
@@ -9,15 +9,20 @@ mod types;
 use std::cell::RefCell;
 
 use crate::{
-	attributes::get_attributes,
-	core::{get_fields, get_fields_attributes},
-	factories::core::get_factory,
+	attributes::{ValidationAttributes, get_attributes},
+	core::{apply_attributes, get_fields, get_fields_attributes},
+	factories::{
+		core::{AbstractValidationFactory, get_factory},
+		newtype::{NewtypeFactory, NewtypeOptions},
+	},
+	fields::FieldAttributes,
 	imports::ImportsSet,
-	types::{Input, Output},
+	types::{Input, Output, StructName},
 };
 
 use proc_macro_error::{emit_error, proc_macro_error};
-use syn::{DeriveInput, spanned::Spanned};
+use proc_macro2::Span;
+use syn::{DeriveInput, Fields, GenericParam, Ident, Visibility, spanned::Spanned};
 
 #[proc_macro_error]
 #[proc_macro_derive(
@@ -38,12 +43,105 @@ fn impl_validation_macro(ast: &DeriveInput) -> Output {
 		emit_error!(ast.span(), "payload implies modificate");
 	}
 
+	if attributes.builder && !attributes.payload {
+		emit_error!(ast.span(), "builder requires payload attribute");
+	}
+
+	if attributes.reversible && !attributes.payload {
+		emit_error!(ast.span(), "reversible requires payload attribute");
+	}
+
+	if attributes.partial && (!attributes.payload || attributes.multipart) {
+		emit_error!(
+			ast.span(),
+			"partial requires payload attribute and doesn't support multipart"
+		);
+	}
+
+	if attributes.deny_unknown_fields && !attributes.payload {
+		emit_error!(ast.span(), "deny_unknown_fields requires payload attribute");
+	}
+
+	for param in ast.generics.params.iter() {
+		if !matches!(param, GenericParam::Lifetime(_)) {
+			emit_error!(param.span(), "only lifetime parameters are supported");
+		}
+	}
+
+	if ast.generics.lifetimes().next().is_some()
+		&& ((attributes.modificate && !attributes.payload)
+			|| attributes.axum
+			|| attributes.multipart
+			|| attributes.partial)
+	{
+		emit_error!(
+			ast.generics.span(),
+			"lifetimes are only supported in validation-only and payload modes, without axum, multipart or partial"
+		);
+	}
+
 	attributes.modificate = attributes.modificate || attributes.payload;
 
-	let mut factory = get_factory(&ast.ident, &attributes);
+	let name = StructName::new(&ast.ident, &ast.generics);
+	let mut factory = get_factory(&name, &attributes);
 	factory.init(ast, &attributes);
 
 	let fields_attributes = get_fields_attributes(fields, factory.as_ref(), &attributes, &imports);
 
 	factory.create(fields_attributes, &attributes, &imports)
 }
+
+#[proc_macro_error]
+#[proc_macro_derive(ValidNewtype, attributes(validate, modificate, special, newtype))]
+pub fn valid_newtype_macro(input: Input) -> Output {
+	let ast = syn::parse(input).unwrap();
+	impl_valid_newtype_macro(&ast)
+}
+
+fn impl_valid_newtype_macro(ast: &DeriveInput) -> Output {
+	let field = match get_fields(ast) {
+		Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+		_ => {
+			emit_error!(ast.span(), "newtypes only supports tuple structs with a single field");
+			return Output::new();
+		}
+	};
+
+	if !matches!(field.vis, Visibility::Inherited) {
+		emit_error!(
+			field.vis.span(),
+			"the inner field of a newtype must be private, otherwise it can be built without validation"
+		);
+	}
+
+	let attributes = ValidationAttributes {
+		modificate: true,
+		..Default::default()
+	};
+
+	let mut options = NewtypeOptions::default();
+	for attr in &ast.attrs {
+		if attr.path().is_ident("newtype")
+			&& let Err(error) = attr.parse_nested_meta(|meta| {
+				match &meta.path {
+					path if path.is_ident("from_str") => options.from_str = true,
+					path if path.is_ident("display") => options.display = true,
+					_ => return Err(meta.error("unknown arg")),
+				}
+
+				Ok(())
+			}) {
+			emit_error!(error.span(), error.to_string());
+		}
+	}
+
+	let imports = RefCell::new(ImportsSet::new());
+	let struct_name = StructName::new(&ast.ident, &ast.generics);
+	let factory = NewtypeFactory::new(&struct_name, &ast.generics, &field.ty, options);
+
+	let name = Ident::new("value", Span::call_site());
+	let mut field_attributes = FieldAttributes::from_named(&field.ty, &name, &attributes);
+	apply_attributes(&ast.attrs, &factory, &mut field_attributes, &attributes, &imports);
+
+	factory.create(vec![field_attributes], &attributes, &imports)
+}
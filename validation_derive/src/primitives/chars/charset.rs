@@ -0,0 +1,136 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, LitStr, Result, parse::ParseStream};
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::commons::{ArgParser, parse_attrs, remove_parens},
+};
+
+pub struct CharsetArgs {
+	pub allowed: Option<LitStr>,
+	pub code: LitStr,
+	pub message: LitStr,
+}
+
+impl Default for CharsetArgs {
+	fn default() -> Self {
+		CharsetArgs {
+			allowed: None,
+			code: LitStr::new("charset", Span::call_site()),
+			message: LitStr::new("has characters outside the allowed set", Span::call_site()),
+		}
+	}
+}
+
+impl ArgParser for CharsetArgs {
+	const POSITIONAL_KEYS: &'static [&'static str] = &["allowed", "message", "code"];
+
+	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
+		match name {
+			"allowed" => self.allowed = Some(input.parse()?),
+			"code" => self.code = input.parse()?,
+			"message" => self.message = input.parse()?,
+			_ => return Err(Error::new(input.span(), "unknown arg")),
+		}
+
+		Ok(())
+	}
+}
+
+pub fn create_charset(input: ParseStream, field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
+	imports.borrow_mut().add(Import::ValidationFunction(
+		"chars::validate_charset as validate_charset_fn",
+	));
+
+	let field_name = field.get_name();
+	let reference = field.get_reference();
+	let content = remove_parens(input);
+
+	let CharsetArgs { allowed, code, message } = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => CharsetArgs::default(),
+	};
+
+	let Some(allowed) = allowed else {
+		let span = input.span();
+		emit_error!(span, "needs an allowed charset");
+		return quote! {};
+	};
+
+	if !is_valid_charset(&allowed.value()) {
+		emit_error!(
+			allowed.span(),
+			"invalid charset, expected characters and ascending ranges like \"a-z0-9_-\""
+		);
+		return quote! {};
+	}
+
+	if field.is_ref() {
+		field.set_is_ref(true);
+		#[rustfmt::skip]
+		let result = quote! {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_charset_fn(#reference, #allowed, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+		  let _ref = &#reference;
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_charset_fn(_ref, #allowed, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	}
+}
+
+fn is_valid_charset(spec: &str) -> bool {
+	let mut items = Vec::new();
+	let mut chars = spec.chars();
+
+	while let Some(char) = chars.next() {
+		match char {
+			'\\' => match chars.next() {
+				Some(escaped) => items.push((escaped, true)),
+				None => return false,
+			},
+			char => items.push((char, false)),
+		}
+	}
+
+	let mut index = 0;
+
+	while index < items.len() {
+		match (items.get(index + 1), items.get(index + 2)) {
+			(Some(('-', false)), Some(&(end, _))) => {
+				if items[index].0 > end {
+					return false;
+				}
+
+				index += 3;
+			}
+			_ => index += 1,
+		}
+	}
+
+	true
+}
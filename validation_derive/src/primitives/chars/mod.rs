@@ -0,0 +1,9 @@
+pub mod alpha;
+pub mod alphanumeric;
+pub mod ascii;
+pub mod charset;
+pub mod no_control_chars;
+pub mod no_zero_width;
+pub mod numeric;
+pub mod printable;
+pub mod single_line;
@@ -1,5 +1,8 @@
+use quote::ToTokens;
 use syn::{
-	Error, GenericArgument, Ident, PathArguments, Result, Token, Type, parenthesized,
+	Error, GenericArgument, Ident, PathArguments, Result, Token, Type,
+	ext::IdentExt,
+	parenthesized,
 	parse::{ParseBuffer, ParseStream},
 };
 
@@ -34,8 +37,8 @@ pub fn parse_attrs<T: ArgParser>(input: &ParseBuffer<'_>) -> Result<T> {
 			return Err(Error::new(input.span(), "too many args"));
 		}
 
-		if input.peek(Ident) && input.peek2(Token![=]) {
-			let key: Ident = input.parse()?;
+		if input.peek(Ident::peek_any) && input.peek2(Token![=]) {
+			let key = Ident::parse_any(input)?;
 			input.parse::<Token![=]>()?;
 			args.apply_value(&key.to_string(), input)?;
 		} else {
@@ -63,3 +66,110 @@ pub fn extract_inner_type(current_type: &Type) -> Option<Type> {
 
 	None
 }
+
+pub fn extract_pointer_type(current_type: &Type) -> Type {
+	if let Type::Path(type_path) = current_type
+		&& let Some(segment) = type_path.path.segments.last()
+		&& ["Box", "Rc", "Arc"].iter().any(|pointer| segment.ident == pointer)
+		&& let Some(inner_type) = extract_inner_type(current_type)
+	{
+		return extract_pointer_type(&inner_type);
+	}
+
+	current_type.clone()
+}
+
+pub fn extract_nested_type(current_type: &Type) -> Type {
+	if let Type::Path(type_path) = current_type
+		&& let Some(segment) = type_path.path.segments.last()
+		&& segment.ident == "Option"
+		&& let Some(inner_type) = extract_inner_type(current_type)
+	{
+		return extract_pointer_type(&inner_type);
+	}
+
+	extract_pointer_type(current_type)
+}
+
+pub fn is_vec_type(current_type: &Type) -> bool {
+	matches!(
+		current_type,
+		Type::Path(type_path) if type_path.path.segments.last().is_some_and(|segment| segment.ident == "Vec")
+	)
+}
+
+pub fn replace_type(current_type: &Type, from: &Type, to: &Type) -> Type {
+	if current_type.to_token_stream().to_string() == from.to_token_stream().to_string() {
+		return to.clone();
+	}
+
+	let mut new_type = current_type.clone();
+	if let Type::Path(type_path) = &mut new_type
+		&& let Some(segment) = type_path.path.segments.last_mut()
+		&& let PathArguments::AngleBracketed(args) = &mut segment.arguments
+	{
+		for arg in args.args.iter_mut() {
+			if let GenericArgument::Type(inner_type) = arg {
+				*inner_type = replace_type(inner_type, from, to);
+			}
+		}
+	}
+
+	new_type
+}
+
+pub fn is_optional_cow_str(current_type: &Type) -> bool {
+	if let Type::Path(type_path) = current_type
+		&& type_path
+			.path
+			.segments
+			.last()
+			.is_some_and(|segment| segment.ident == "Option")
+		&& let Some(Type::Path(inner_path)) = extract_inner_type(current_type)
+		&& let Some(segment) = inner_path.path.segments.last()
+		&& segment.ident == "Cow"
+		&& let PathArguments::AngleBracketed(args) = &segment.arguments
+	{
+		return args.args.iter().any(
+			|arg| matches!(arg, GenericArgument::Type(Type::Path(inner_type)) if inner_type.path.is_ident("str")),
+		);
+	}
+
+	false
+}
+
+pub fn has_lifetime(current_type: &Type) -> bool {
+	match current_type {
+		Type::Reference(reference) => reference.lifetime.is_some() || has_lifetime(&reference.elem),
+		Type::Path(type_path) => type_path.path.segments.iter().any(|segment| match &segment.arguments {
+			PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| match arg {
+				GenericArgument::Lifetime(_) => true,
+				GenericArgument::Type(inner_type) => has_lifetime(inner_type),
+				_ => false,
+			}),
+			_ => false,
+		}),
+		Type::Slice(slice) => has_lifetime(&slice.elem),
+		Type::Array(array) => has_lifetime(&array.elem),
+		Type::Tuple(tuple) => tuple.elems.iter().any(has_lifetime),
+		_ => false,
+	}
+}
+
+pub fn extract_map_types(current_type: &Type) -> Option<(Type, Type)> {
+	if let Type::Path(type_path) = current_type
+		&& let Some(segment) = type_path.path.segments.last()
+		&& let PathArguments::AngleBracketed(args) = &segment.arguments
+	{
+		let mut types = args.args.iter().filter_map(|arg| match arg {
+			GenericArgument::Type(inner_type) => Some(inner_type.clone()),
+			_ => None,
+		});
+
+		if let (Some(key_type), Some(value_type)) = (types.next(), types.next()) {
+			return Some((key_type, value_type));
+		}
+	}
+
+	None
+}
@@ -1,7 +1,7 @@
 use proc_macro_error::emit_error;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Error, ExprArray, Ident, Result, parse::ParseStream};
+use syn::{Error, ExprArray, Ident, Path, Result, parse::ParseStream};
 
 use crate::{
 	fields::FieldAttributes,
@@ -12,15 +12,17 @@ use crate::{
 struct CustomArgs {
 	function: Option<Ident>,
 	params: Option<ExprArray>,
+	unparse: Option<Path>,
 }
 
 impl ArgParser for CustomArgs {
-	const POSITIONAL_KEYS: &'static [&'static str] = &["function", "params"];
+	const POSITIONAL_KEYS: &'static [&'static str] = &["function", "params", "unparse"];
 
 	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
 		match name {
 			"function" => self.function = Some(input.parse()?),
 			"params" => self.params = Some(input.parse()?),
+			"unparse" => self.unparse = Some(input.parse()?),
 			_ => return Err(Error::new(input.span(), "unknown arg")),
 		}
 
@@ -35,7 +37,11 @@ pub fn create_custom_parse(input: ParseStream, field: &mut FieldAttributes) -> T
 	let new_reference = field.get_reference();
 	let content = remove_parens(input);
 
-	let CustomArgs { function, params } = match content {
+	let CustomArgs {
+		function,
+		params,
+		unparse,
+	} = match content {
 		Ok(content) => parse_attrs(&content)
 			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
 			.unwrap_or_default(),
@@ -50,6 +56,8 @@ pub fn create_custom_parse(input: ParseStream, field: &mut FieldAttributes) -> T
 
 	let extra_args = params.iter().flat_map(|p| &p.elems).map(|arg| quote! { #arg });
 
+	field.add_unparse(unparse.map(|unparse| quote! { #unparse(value) }));
+
 	if field.is_ref() {
 		field.set_is_ref(false);
 		#[rustfmt::skip]
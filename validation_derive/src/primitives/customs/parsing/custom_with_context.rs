@@ -1,7 +1,7 @@
 use proc_macro_error::emit_error;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Error, ExprArray, Ident, Result, parse::ParseStream};
+use syn::{Error, ExprArray, Ident, Path, Result, parse::ParseStream};
 
 use crate::{
 	attributes::ValidationAttributes,
@@ -13,15 +13,17 @@ use crate::{
 struct CustomWithContextArgs {
 	function: Option<Ident>,
 	params: Option<ExprArray>,
+	unparse: Option<Path>,
 }
 
 impl ArgParser for CustomWithContextArgs {
-	const POSITIONAL_KEYS: &'static [&'static str] = &["function", "params"];
+	const POSITIONAL_KEYS: &'static [&'static str] = &["function", "params", "unparse"];
 
 	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
 		match name {
 			"function" => self.function = Some(input.parse()?),
 			"params" => self.params = Some(input.parse()?),
+			"unparse" => self.unparse = Some(input.parse()?),
 			_ => return Err(Error::new(input.span(), "unknown arg")),
 		}
 
@@ -45,7 +47,11 @@ pub fn create_custom_with_context_parse(
 	let new_reference = field.get_reference();
 	let content = remove_parens(input);
 
-	let CustomWithContextArgs { function, params } = match content {
+	let CustomWithContextArgs {
+		function,
+		params,
+		unparse,
+	} = match content {
 		Ok(content) => parse_attrs(&content)
 			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
 			.unwrap_or_default(),
@@ -60,6 +66,8 @@ pub fn create_custom_with_context_parse(
 
 	let extra_args = params.iter().flat_map(|p| &p.elems).map(|arg| quote! { #arg });
 
+	field.add_unparse(unparse.map(|unparse| quote! { #unparse(value) }));
+
 	if field.is_ref() {
 		field.set_is_ref(false);
 		#[rustfmt::skip]
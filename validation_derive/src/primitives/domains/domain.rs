@@ -0,0 +1,119 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, LitBool, LitStr, Result, parse::ParseStream};
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::commons::{ArgParser, parse_attrs, remove_parens},
+};
+
+pub struct DomainArgs {
+	pub code: LitStr,
+	pub message: LitStr,
+	pub require_tld: bool,
+	pub allow_wildcard: bool,
+	pub suffix_of: Option<LitStr>,
+}
+
+impl Default for DomainArgs {
+	fn default() -> Self {
+		DomainArgs {
+			code: LitStr::new("domain", Span::call_site()),
+			message: LitStr::new("invalid domain", Span::call_site()),
+			require_tld: true,
+			allow_wildcard: false,
+			suffix_of: None,
+		}
+	}
+}
+
+impl ArgParser for DomainArgs {
+	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code", "require_tld", "allow_wildcard", "suffix_of"];
+
+	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
+		match name {
+			"code" => self.code = input.parse()?,
+			"message" => self.message = input.parse()?,
+			"require_tld" => self.require_tld = input.parse::<LitBool>()?.value(),
+			"allow_wildcard" => self.allow_wildcard = input.parse::<LitBool>()?.value(),
+			"suffix_of" => self.suffix_of = Some(input.parse()?),
+			_ => return Err(Error::new(input.span(), "unknown arg")),
+		}
+
+		Ok(())
+	}
+}
+
+pub fn create_domain(input: ParseStream, field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
+	imports.borrow_mut().add(Import::ValidationFunction(
+		"domain::validate_domain as validate_domain_fn",
+	));
+	imports
+		.borrow_mut()
+		.add(Import::ValidationFunction("domain::DomainPolicy"));
+
+	let field_name = field.get_name();
+	let reference = field.get_reference();
+	let content = remove_parens(input);
+
+	let args: DomainArgs = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => DomainArgs::default(),
+	};
+
+	let policy = get_domain_policy(&args, quote! { DomainPolicy });
+	let DomainArgs { code, message, .. } = args;
+
+	if field.is_ref() {
+		field.set_is_ref(true);
+		#[rustfmt::skip]
+		let result = quote! {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_domain_fn(#reference, &#policy, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+		  let _ref = &#reference;
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_domain_fn(_ref, &#policy, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	}
+}
+
+pub fn get_domain_policy(args: &DomainArgs, policy: TokenStream) -> TokenStream {
+	let require_tld = args.require_tld;
+	let allow_wildcard = args.allow_wildcard;
+	let suffix_of = args
+		.suffix_of
+		.as_ref()
+		.map_or(quote! { None }, |suffix| quote! { Some(#suffix) });
+
+	quote! {
+		#policy {
+			require_tld: #require_tld,
+			allow_wildcard: #allow_wildcard,
+			suffix_of: #suffix_of,
+		}
+	}
+}
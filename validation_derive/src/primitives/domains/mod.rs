@@ -0,0 +1,3 @@
+pub mod domain;
+pub mod hostname;
+pub mod parse_domain;
@@ -0,0 +1,91 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::ParseStream;
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::{
+		commons::{parse_attrs, remove_parens},
+		domains::domain::{DomainArgs, get_domain_policy},
+	},
+};
+
+pub fn create_domain_parse(
+	input: ParseStream,
+	field: &mut FieldAttributes,
+	imports: &RefCell<ImportsSet>,
+) -> TokenStream {
+	imports
+		.borrow_mut()
+		.add(Import::ParsingFunction("domain::default_domain as default_domain_fn"));
+	imports
+		.borrow_mut()
+		.add(Import::ParsingFunction("domain::parse_domain as parse_domain_fn"));
+	imports
+		.borrow_mut()
+		.add(Import::ParsingFunction("domain::DomainPolicy as ParseDomainPolicy"));
+
+	let field_name = field.get_name();
+	let reference = field.get_reference();
+	field.increment_modifications();
+	let new_reference = field.get_reference();
+	let content = remove_parens(input);
+
+	let args: DomainArgs = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => DomainArgs::default(),
+	};
+
+	let policy = get_domain_policy(&args, quote! { ParseDomainPolicy });
+	let DomainArgs { code, message, .. } = args;
+
+	field.add_unparse(Some(quote! { value.to_string() }));
+
+	if field.is_ref() {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+			let (mut #new_reference, error) = if can_continue(&errors, failure_mode, #field_name) {
+			  parse_domain_fn(#reference, &#policy, #field_name, #code, #message)
+			} else {
+			  (default_domain_fn(), None)
+			};
+
+			if let Some(e) = error {
+				append_error(&mut errors, e, failure_mode, #field_name);
+				if should_fail_fast(&errors, failure_mode, #field_name) {
+				  return Err(errors);
+			  };
+		  }
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+  		let (mut #new_reference, error) = if can_continue(&errors, failure_mode, #field_name) {
+        let _ref = &#reference;
+  		  parse_domain_fn(_ref, &#policy, #field_name, #code, #message)
+  		} else {
+  		  (default_domain_fn(), None)
+  		};
+
+			if let Some(e) = error {
+				append_error(&mut errors, e, failure_mode, #field_name);
+				if should_fail_fast(&errors, failure_mode, #field_name) {
+				  return Err(errors);
+			  };
+		  }
+		};
+
+		result
+	}
+}
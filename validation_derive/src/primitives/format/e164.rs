@@ -0,0 +1,76 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Expr, Result, parse::ParseStream};
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::{
+		commons::{ArgParser, parse_attrs, remove_parens},
+		phones::phone::get_phone_region,
+	},
+};
+
+#[derive(Default)]
+pub struct E164Args {
+	pub region: Option<Expr>,
+}
+
+impl ArgParser for E164Args {
+	const POSITIONAL_KEYS: &'static [&'static str] = &["region"];
+
+	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
+		match name {
+			"region" => self.region = Some(input.parse()?),
+			_ => return Err(Error::new(input.span(), "unknown arg")),
+		}
+
+		Ok(())
+	}
+}
+
+pub fn create_e164(input: ParseStream, field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
+	imports
+		.borrow_mut()
+		.add(Import::ModificationFunction("phone::e164 as e164_fn"));
+
+	let reference = field.get_reference();
+	let field_name = field.get_name();
+	let content = remove_parens(input);
+
+	let E164Args { region } = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => E164Args::default(),
+	};
+
+	let region = get_phone_region(&region);
+
+	if field.is_ref() {
+		field.set_is_ref(true);
+		#[rustfmt::skip]
+		let result = quote! {
+			if can_continue(&errors, failure_mode, #field_name) {
+			  e164_fn(#reference, #region);
+			};
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+		  if can_continue(&errors, failure_mode, #field_name) {
+				let _ref = &mut #reference;
+				e164_fn(_ref, #region);
+		  };
+		};
+
+		result
+	}
+}
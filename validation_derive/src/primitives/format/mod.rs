@@ -1,8 +1,12 @@
 pub mod camel_case;
 pub mod capitalize;
+pub mod digits_only;
+pub mod e164;
 pub mod kebab_case;
 pub mod lower_camel_case;
 pub mod lowercase;
+pub mod normalize_email;
+pub mod punycode;
 pub mod shouty_kebab_case;
 pub mod shouty_snake_case;
 pub mod snake_case;
@@ -10,4 +14,5 @@ pub mod train_case;
 pub mod trim;
 pub mod trim_end;
 pub mod trim_start;
+pub mod truncate;
 pub mod uppercase;
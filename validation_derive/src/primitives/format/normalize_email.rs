@@ -0,0 +1,75 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, LitBool, Result, parse::ParseStream};
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::commons::{ArgParser, parse_attrs, remove_parens},
+};
+
+#[derive(Default)]
+pub struct NormalizeEmailArgs {
+	pub strip_tag: bool,
+}
+
+impl ArgParser for NormalizeEmailArgs {
+	const POSITIONAL_KEYS: &'static [&'static str] = &["strip_tag"];
+
+	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
+		match name {
+			"strip_tag" => self.strip_tag = input.parse::<LitBool>()?.value(),
+			_ => return Err(Error::new(input.span(), "unknown arg")),
+		}
+
+		Ok(())
+	}
+}
+
+pub fn create_normalize_email(
+	input: ParseStream,
+	field: &mut FieldAttributes,
+	imports: &RefCell<ImportsSet>,
+) -> TokenStream {
+	imports.borrow_mut().add(Import::ModificationFunction(
+		"email::normalize_email as normalize_email_fn",
+	));
+
+	let reference = field.get_reference();
+	let field_name = field.get_name();
+	let content = remove_parens(input);
+
+	let NormalizeEmailArgs { strip_tag } = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => NormalizeEmailArgs::default(),
+	};
+
+	if field.is_ref() {
+		field.set_is_ref(true);
+		#[rustfmt::skip]
+		let result = quote! {
+			if can_continue(&errors, failure_mode, #field_name) {
+			  normalize_email_fn(#reference, #strip_tag);
+			};
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+		  if can_continue(&errors, failure_mode, #field_name) {
+				let _ref = &mut #reference;
+				normalize_email_fn(_ref, #strip_tag);
+		  };
+		};
+
+		result
+	}
+}
@@ -1,9 +1,15 @@
+use std::cell::RefCell;
+
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::fields::FieldAttributes;
+use crate::{ImportsSet, fields::FieldAttributes, imports::Import};
+
+pub fn create_trim(field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
+	imports
+		.borrow_mut()
+		.add(Import::ModificationFunction("text::trim as trim_fn"));
 
-pub fn create_trim(field: &mut FieldAttributes) -> TokenStream {
 	let reference = field.get_reference();
 	let field_name = field.get_name();
 
@@ -11,9 +17,9 @@ pub fn create_trim(field: &mut FieldAttributes) -> TokenStream {
 		field.set_is_ref(true);
 		#[rustfmt::skip]
 		let result = quote! {
-  		if can_continue(&errors, failure_mode, #field_name) {
-  		  *#reference = #reference.trim().to_string();
-  		};
+			if can_continue(&errors, failure_mode, #field_name) {
+			  trim_fn(#reference);
+			};
 		};
 
 		result
@@ -21,10 +27,10 @@ pub fn create_trim(field: &mut FieldAttributes) -> TokenStream {
 		field.set_is_ref(false);
 		#[rustfmt::skip]
 		let result = quote! {
-			if can_continue(&errors, failure_mode, #field_name) {
-			  let _ref = &mut #reference;
-			  *_ref = _ref.trim().to_string();
-  	  };
+		  if can_continue(&errors, failure_mode, #field_name) {
+				let _ref = &mut #reference;
+				trim_fn(_ref);
+		  };
 		};
 
 		result
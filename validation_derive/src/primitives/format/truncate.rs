@@ -0,0 +1,82 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Expr, LitStr, Result, parse::ParseStream};
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::{
+		commons::{ArgParser, parse_attrs, remove_parens},
+		ranges::length::get_length_unit,
+	},
+};
+
+#[derive(Default)]
+pub struct TruncateArgs {
+	pub max: Option<Expr>,
+	pub unit: Option<LitStr>,
+}
+
+impl ArgParser for TruncateArgs {
+	const POSITIONAL_KEYS: &'static [&'static str] = &["max", "unit"];
+
+	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
+		match name {
+			"max" => self.max = Some(input.parse()?),
+			"unit" => self.unit = Some(input.parse()?),
+			_ => return Err(Error::new(input.span(), "unknown arg")),
+		}
+
+		Ok(())
+	}
+}
+
+pub fn create_truncate(input: ParseStream, field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
+	imports
+		.borrow_mut()
+		.add(Import::ModificationFunction("text::truncate as truncate_fn"));
+
+	let reference = field.get_reference();
+	let field_name = field.get_name();
+	let content = remove_parens(input);
+
+	let TruncateArgs { max, unit } = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => TruncateArgs::default(),
+	};
+
+	if max.is_none() {
+		emit_error!(input.span(), "needs a max length");
+	}
+
+	let unit = get_length_unit(unit.as_ref());
+
+	if field.is_ref() {
+		field.set_is_ref(true);
+		#[rustfmt::skip]
+		let result = quote! {
+			if can_continue(&errors, failure_mode, #field_name) {
+			  truncate_fn(#reference, #max, #unit);
+			};
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+		  if can_continue(&errors, failure_mode, #field_name) {
+				let _ref = &mut #reference;
+				truncate_fn(_ref, #max, #unit);
+		  };
+		};
+
+		result
+	}
+}
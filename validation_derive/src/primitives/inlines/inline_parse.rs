@@ -12,15 +12,17 @@ use crate::{
 pub struct InlineParseArgs {
 	pub closure: Option<ExprClosure>,
 	pub params: Option<ExprArray>,
+	pub unparse: Option<ExprClosure>,
 }
 
 impl ArgParser for InlineParseArgs {
-	const POSITIONAL_KEYS: &'static [&'static str] = &["closure", "params"];
+	const POSITIONAL_KEYS: &'static [&'static str] = &["closure", "params", "unparse"];
 
 	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
 		match name {
 			"closure" => self.closure = Some(input.parse()?),
 			"params" => self.params = Some(input.parse()?),
+			"unparse" => self.unparse = Some(input.parse()?),
 			_ => return Err(Error::new(input.span(), "unknown arg")),
 		}
 
@@ -34,7 +36,11 @@ pub fn create_inline_parse(input: ParseStream, field: &mut FieldAttributes) -> T
 	let new_reference = field.get_reference();
 	let content = remove_parens(input);
 
-	let InlineParseArgs { closure, params } = match content {
+	let InlineParseArgs {
+		closure,
+		params,
+		unparse,
+	} = match content {
 		Ok(content) => parse_attrs(&content)
 			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
 			.unwrap_or_default(),
@@ -49,6 +55,8 @@ pub fn create_inline_parse(input: ParseStream, field: &mut FieldAttributes) -> T
 	let extra_args = params.iter().flat_map(|p| &p.elems).map(|arg| quote! { #arg });
 	let field_name = field.get_name();
 
+	field.add_unparse(unparse.map(|unparse| quote! { (#unparse)(value) }));
+
 	if field.is_ref() {
 		field.set_is_ref(false);
 		#[rustfmt::skip]
@@ -0,0 +1,112 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, Expr, LitBool, LitStr, Result, parse::ParseStream};
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::commons::{ArgParser, parse_attrs, remove_parens},
+};
+
+pub struct CidrArgs {
+	pub code: LitStr,
+	pub message: LitStr,
+	pub min_prefix: Option<Expr>,
+	pub max_prefix: Option<Expr>,
+	pub strict: bool,
+}
+
+impl Default for CidrArgs {
+	fn default() -> Self {
+		CidrArgs {
+			code: LitStr::new("cidr", Span::call_site()),
+			message: LitStr::new("invalid cidr format", Span::call_site()),
+			min_prefix: None,
+			max_prefix: None,
+			strict: false,
+		}
+	}
+}
+
+impl ArgParser for CidrArgs {
+	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code", "min_prefix", "max_prefix", "strict"];
+
+	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
+		match name {
+			"code" => self.code = input.parse()?,
+			"message" => self.message = input.parse()?,
+			"min_prefix" => self.min_prefix = Some(input.parse()?),
+			"max_prefix" => self.max_prefix = Some(input.parse()?),
+			"strict" => self.strict = input.parse::<LitBool>()?.value(),
+			_ => return Err(Error::new(input.span(), "unknown arg")),
+		}
+
+		Ok(())
+	}
+}
+
+pub fn create_cidr(input: ParseStream, field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
+	imports
+		.borrow_mut()
+		.add(Import::ValidationFunction("ip::validate_cidr as validate_cidr_fn"));
+
+	let field_name = field.get_name();
+	let reference = field.get_reference();
+	let content = remove_parens(input);
+
+	let args: CidrArgs = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => CidrArgs::default(),
+	};
+
+	let (min_prefix, max_prefix, strict) = get_cidr_limits(&args);
+	let CidrArgs { code, message, .. } = args;
+
+	if field.is_ref() {
+		field.set_is_ref(true);
+		#[rustfmt::skip]
+		let result = quote! {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_cidr_fn(#reference, #min_prefix, #max_prefix, #strict, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+		  let _ref = &#reference;
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_cidr_fn(_ref, #min_prefix, #max_prefix, #strict, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	}
+}
+
+pub fn get_cidr_limits(args: &CidrArgs) -> (TokenStream, TokenStream, bool) {
+	let min_prefix = match &args.min_prefix {
+		Some(min_prefix) => quote! { Some(#min_prefix) },
+		None => quote! { None },
+	};
+	let max_prefix = match &args.max_prefix {
+		Some(max_prefix) => quote! { Some(#max_prefix) },
+		None => quote! { None },
+	};
+
+	(min_prefix, max_prefix, args.strict)
+}
@@ -1,9 +1,9 @@
-use std::cell::RefCell;
+use std::{cell::RefCell, net::IpAddr};
 
 use proc_macro_error::emit_error;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Error, LitStr, Result, parse::ParseStream};
+use syn::{Error, Expr, ExprArray, ExprLit, Ident, Lit, LitStr, Result, parse::ParseStream};
 
 use crate::{
 	ImportsSet,
@@ -12,9 +12,114 @@ use crate::{
 	primitives::commons::{ArgParser, parse_attrs, remove_parens},
 };
 
+#[derive(Default)]
+pub struct IpPolicyArgs {
+	pub in_networks: Option<ExprArray>,
+	pub not_in_networks: Option<ExprArray>,
+	pub class: Option<LitStr>,
+}
+
+impl IpPolicyArgs {
+	pub fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
+		match name {
+			"in" => self.in_networks = Some(input.parse()?),
+			"not_in" => self.not_in_networks = Some(input.parse()?),
+			"class" => self.class = Some(input.parse()?),
+			_ => return Err(Error::new(input.span(), "unknown arg")),
+		}
+
+		Ok(())
+	}
+
+	pub fn to_policy(&self, policy: TokenStream, ip_class: TokenStream, ip_network: TokenStream) -> TokenStream {
+		let in_networks = networks_static(&self.in_networks, "IN_NETWORKS", &ip_network);
+		let not_in_networks = networks_static(&self.not_in_networks, "NOT_IN_NETWORKS", &ip_network);
+		let class = match &self.class {
+			Some(class) => match class.value().as_str() {
+				"public" => quote! { Some(#ip_class::Public) },
+				"private" => quote! { Some(#ip_class::Private) },
+				"loopback" => quote! { Some(#ip_class::Loopback) },
+				"multicast" => quote! { Some(#ip_class::Multicast) },
+				"global_unicast" => quote! { Some(#ip_class::GlobalUnicast) },
+				_ => {
+					emit_error!(
+						class.span(),
+						"class must be \"public\", \"private\", \"loopback\", \"multicast\" or \"global_unicast\""
+					);
+					quote! { None }
+				}
+			},
+			None => quote! { None },
+		};
+
+		let (in_networks_static, in_networks) = in_networks;
+		let (not_in_networks_static, not_in_networks) = not_in_networks;
+
+		quote! {
+			{
+				#in_networks_static
+				#not_in_networks_static
+				#policy {
+					in_networks: #in_networks,
+					not_in_networks: #not_in_networks,
+					class: #class,
+				}
+			}
+		}
+	}
+}
+
+fn networks_static(networks: &Option<ExprArray>, name: &str, ip_network: &TokenStream) -> (TokenStream, TokenStream) {
+	let Some(networks) = networks else {
+		return (quote! {}, quote! { &[] });
+	};
+
+	for network in &networks.elems {
+		match network {
+			Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) if !is_valid_network(&lit.value()) => {
+				emit_error!(
+					lit.span(),
+					"network must be an IP address or a CIDR like \"10.0.0.0/8\""
+				);
+			}
+			Expr::Lit(ExprLit { lit, .. }) if !matches!(lit, Lit::Str(_)) => {
+				emit_error!(lit.span(), "network must be a string");
+			}
+			_ => {}
+		}
+	}
+
+	let name = Ident::new(name, Span::call_site());
+	let elems = &networks.elems;
+
+	#[rustfmt::skip]
+	let result = quote! {
+		static #name: ::std::sync::LazyLock<Vec<#ip_network>> = ::std::sync::LazyLock::new(|| {
+		  [#elems].into_iter().map(#ip_network::parse).collect()
+		});
+	};
+
+	(result, quote! { &#name })
+}
+
+fn is_valid_network(network: &str) -> bool {
+	let (address, prefix) = match network.split_once('/') {
+		Some((address, prefix)) => (address, Some(prefix)),
+		None => (network, None),
+	};
+
+	match (address.parse::<IpAddr>(), prefix) {
+		(Ok(_), None) => true,
+		(Ok(IpAddr::V4(_)), Some(prefix)) => prefix.parse::<u8>().is_ok_and(|prefix| prefix <= 32),
+		(Ok(IpAddr::V6(_)), Some(prefix)) => prefix.parse::<u8>().is_ok_and(|prefix| prefix <= 128),
+		(Err(_), _) => false,
+	}
+}
+
 pub struct IpArgs {
 	pub code: LitStr,
 	pub message: LitStr,
+	pub policy: IpPolicyArgs,
 }
 
 impl Default for IpArgs {
@@ -22,18 +127,19 @@ impl Default for IpArgs {
 		IpArgs {
 			code: LitStr::new("ip", Span::call_site()),
 			message: LitStr::new("invalid ip format", Span::call_site()),
+			policy: IpPolicyArgs::default(),
 		}
 	}
 }
 
 impl ArgParser for IpArgs {
-	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code"];
+	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code", "in", "not_in", "class"];
 
 	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
 		match name {
 			"code" => self.code = input.parse()?,
 			"message" => self.message = input.parse()?,
-			_ => return Err(Error::new(input.span(), "unknown arg")),
+			_ => return self.policy.apply_value(name, input),
 		}
 
 		Ok(())
@@ -41,26 +147,31 @@ impl ArgParser for IpArgs {
 }
 
 pub fn create_ip(input: ParseStream, field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
-	imports
-		.borrow_mut()
-		.add(Import::ValidationFunction("ip::validate_ip as validate_ip_fn"));
+	imports.borrow_mut().add(Import::ValidationFunction(
+		"ip::validate_ip_with_policy as validate_ip_fn",
+	));
+	imports.borrow_mut().add(Import::ValidationFunction("ip::IpPolicy"));
+	imports.borrow_mut().add(Import::ValidationFunction("ip::IpClass"));
+	imports.borrow_mut().add(Import::ValidationFunction("ip::IpNetwork"));
 
 	let field_name = field.get_name();
 	let reference = field.get_reference();
 	let content = remove_parens(input);
 
-	let IpArgs { code, message } = match content {
+	let IpArgs { code, message, policy } = match content {
 		Ok(content) => parse_attrs(&content)
 			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
 			.unwrap_or_default(),
 		Err(_) => IpArgs::default(),
 	};
 
+	let policy = policy.to_policy(quote! { IpPolicy }, quote! { IpClass }, quote! { IpNetwork });
+
 	if field.is_ref() {
 		field.set_is_ref(true);
 		#[rustfmt::skip]
 		let result = quote! {
-			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_ip_fn(#reference, #field_name, #code, #message) {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_ip_fn(#reference, &#policy, #field_name, #code, #message) {
         append_error(&mut errors, e, failure_mode, #field_name);
         if should_fail_fast(&errors, failure_mode, #field_name) {
      			return Err(errors);
@@ -74,7 +185,7 @@ pub fn create_ip(input: ParseStream, field: &mut FieldAttributes, imports: &RefC
 		#[rustfmt::skip]
 		let result = quote! {
 		  let _ref = &#reference;
-			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_ip_fn(_ref, #field_name, #code, #message) {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_ip_fn(_ref, &#policy, #field_name, #code, #message) {
         append_error(&mut errors, e, failure_mode, #field_name);
         if should_fail_fast(&errors, failure_mode, #field_name) {
      			return Err(errors);
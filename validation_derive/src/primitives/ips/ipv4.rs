@@ -3,18 +3,22 @@ use std::cell::RefCell;
 use proc_macro_error::emit_error;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Error, LitStr, Result, parse::ParseStream};
+use syn::{LitStr, Result, parse::ParseStream};
 
 use crate::{
 	ImportsSet,
 	fields::FieldAttributes,
 	imports::Import,
-	primitives::commons::{ArgParser, parse_attrs, remove_parens},
+	primitives::{
+		commons::{ArgParser, parse_attrs, remove_parens},
+		ips::ip::IpPolicyArgs,
+	},
 };
 
 pub struct Ipv4Args {
 	pub code: LitStr,
 	pub message: LitStr,
+	pub policy: IpPolicyArgs,
 }
 
 impl Default for Ipv4Args {
@@ -22,18 +26,19 @@ impl Default for Ipv4Args {
 		Ipv4Args {
 			code: LitStr::new("ipv4", Span::call_site()),
 			message: LitStr::new("invalid ipv4 format", Span::call_site()),
+			policy: IpPolicyArgs::default(),
 		}
 	}
 }
 
 impl ArgParser for Ipv4Args {
-	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code"];
+	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code", "in", "not_in", "class"];
 
 	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
 		match name {
 			"code" => self.code = input.parse()?,
 			"message" => self.message = input.parse()?,
-			_ => return Err(Error::new(input.span(), "unknown arg")),
+			_ => return self.policy.apply_value(name, input),
 		}
 
 		Ok(())
@@ -41,26 +46,31 @@ impl ArgParser for Ipv4Args {
 }
 
 pub fn create_ipv4(input: ParseStream, field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
-	imports
-		.borrow_mut()
-		.add(Import::ValidationFunction("ip::validate_ipv4 as validate_ipv4_fn"));
+	imports.borrow_mut().add(Import::ValidationFunction(
+		"ip::validate_ipv4_with_policy as validate_ipv4_fn",
+	));
+	imports.borrow_mut().add(Import::ValidationFunction("ip::IpPolicy"));
+	imports.borrow_mut().add(Import::ValidationFunction("ip::IpClass"));
+	imports.borrow_mut().add(Import::ValidationFunction("ip::IpNetwork"));
 
 	let field_name = field.get_name();
 	let reference = field.get_reference();
 	let content = remove_parens(input);
 
-	let Ipv4Args { code, message } = match content {
+	let Ipv4Args { code, message, policy } = match content {
 		Ok(content) => parse_attrs(&content)
 			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
 			.unwrap_or_default(),
 		Err(_) => Ipv4Args::default(),
 	};
 
+	let policy = policy.to_policy(quote! { IpPolicy }, quote! { IpClass }, quote! { IpNetwork });
+
 	if field.is_ref() {
 		field.set_is_ref(true);
 		#[rustfmt::skip]
 		let result = quote! {
-			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_ipv4_fn(#reference, #field_name, #code, #message) {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_ipv4_fn(#reference, &#policy, #field_name, #code, #message) {
         append_error(&mut errors, e, failure_mode, #field_name);
         if should_fail_fast(&errors, failure_mode, #field_name) {
      			return Err(errors);
@@ -74,7 +84,7 @@ pub fn create_ipv4(input: ParseStream, field: &mut FieldAttributes, imports: &Re
 		#[rustfmt::skip]
 		let result = quote! {
 		  let _ref = &#reference;
-			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_ipv4_fn(_ref, #field_name, #code, #message) {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_ipv4_fn(_ref, &#policy, #field_name, #code, #message) {
         append_error(&mut errors, e, failure_mode, #field_name);
         if should_fail_fast(&errors, failure_mode, #field_name) {
      			return Err(errors);
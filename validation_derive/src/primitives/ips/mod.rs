@@ -1,6 +1,11 @@
+pub mod cidr;
 pub mod ip;
 pub mod ipv4;
 pub mod ipv6;
+pub mod parse_cidr;
 pub mod parse_ip;
 pub mod parse_ipv4;
 pub mod parse_ipv6;
+pub mod parse_socket_addr;
+pub mod port;
+pub mod socket_addr;
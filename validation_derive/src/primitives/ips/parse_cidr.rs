@@ -0,0 +1,88 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::ParseStream;
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::{
+		commons::{parse_attrs, remove_parens},
+		ips::cidr::{CidrArgs, get_cidr_limits},
+	},
+};
+
+pub fn create_cidr_parse(
+	input: ParseStream,
+	field: &mut FieldAttributes,
+	imports: &RefCell<ImportsSet>,
+) -> TokenStream {
+	imports
+		.borrow_mut()
+		.add(Import::ParsingFunction("ip::default_cidr as default_cidr_fn"));
+	imports
+		.borrow_mut()
+		.add(Import::ParsingFunction("ip::parse_cidr as parse_cidr_fn"));
+
+	let field_name = field.get_name();
+	let reference = field.get_reference();
+	field.increment_modifications();
+	let new_reference = field.get_reference();
+	let content = remove_parens(input);
+
+	let args: CidrArgs = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => CidrArgs::default(),
+	};
+
+	let (min_prefix, max_prefix, strict) = get_cidr_limits(&args);
+	let CidrArgs { code, message, .. } = args;
+
+	field.add_unparse(Some(quote! { value.to_string() }));
+
+	if field.is_ref() {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+			let (mut #new_reference, error) = if can_continue(&errors, failure_mode, #field_name) {
+			  parse_cidr_fn(#reference, #min_prefix, #max_prefix, #strict, #field_name, #code, #message)
+			} else {
+			  (default_cidr_fn(), None)
+			};
+
+			if let Some(e) = error {
+				append_error(&mut errors, e, failure_mode, #field_name);
+				if should_fail_fast(&errors, failure_mode, #field_name) {
+				  return Err(errors);
+			  };
+		  }
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+  		let (mut #new_reference, error) = if can_continue(&errors, failure_mode, #field_name) {
+        let _ref = &#reference;
+  		  parse_cidr_fn(_ref, #min_prefix, #max_prefix, #strict, #field_name, #code, #message)
+  		} else {
+  		  (default_cidr_fn(), None)
+  		};
+
+			if let Some(e) = error {
+				append_error(&mut errors, e, failure_mode, #field_name);
+				if should_fail_fast(&errors, failure_mode, #field_name) {
+				  return Err(errors);
+			  };
+		  }
+		};
+
+		result
+	}
+}
@@ -3,18 +3,22 @@ use std::cell::RefCell;
 use proc_macro_error::emit_error;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Error, LitStr, Result, parse::ParseStream};
+use syn::{LitStr, Result, parse::ParseStream};
 
 use crate::{
 	ImportsSet,
 	fields::FieldAttributes,
 	imports::Import,
-	primitives::commons::{ArgParser, parse_attrs, remove_parens},
+	primitives::{
+		commons::{ArgParser, parse_attrs, remove_parens},
+		ips::ip::IpPolicyArgs,
+	},
 };
 
 pub struct ParseIpArgs {
 	pub code: LitStr,
 	pub message: LitStr,
+	pub policy: IpPolicyArgs,
 }
 
 impl Default for ParseIpArgs {
@@ -22,18 +26,19 @@ impl Default for ParseIpArgs {
 		ParseIpArgs {
 			code: LitStr::new("ipv6", Span::call_site()),
 			message: LitStr::new("invalid ipv6 format", Span::call_site()),
+			policy: IpPolicyArgs::default(),
 		}
 	}
 }
 
 impl ArgParser for ParseIpArgs {
-	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code"];
+	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code", "in", "not_in", "class"];
 
 	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
 		match name {
 			"code" => self.code = input.parse()?,
 			"message" => self.message = input.parse()?,
-			_ => return Err(Error::new(input.span(), "unknown arg")),
+			_ => return self.policy.apply_value(name, input),
 		}
 
 		Ok(())
@@ -50,7 +55,16 @@ pub fn create_ipv6_parse(
 		.add(Import::ParsingFunction("ip::default_ipv6 as default_ipv6_fn"));
 	imports
 		.borrow_mut()
-		.add(Import::ParsingFunction("ip::parse_ipv6 as parse_ipv6_fn"));
+		.add(Import::ParsingFunction("ip::parse_ipv6_with_policy as parse_ipv6_fn"));
+	imports
+		.borrow_mut()
+		.add(Import::ParsingFunction("ip::IpPolicy as ParseIpPolicy"));
+	imports
+		.borrow_mut()
+		.add(Import::ParsingFunction("ip::IpClass as ParseIpClass"));
+	imports
+		.borrow_mut()
+		.add(Import::ParsingFunction("ip::IpNetwork as ParseIpNetwork"));
 
 	let field_name = field.get_name();
 	let reference = field.get_reference();
@@ -58,19 +72,27 @@ pub fn create_ipv6_parse(
 	let new_reference = field.get_reference();
 	let content = remove_parens(input);
 
-	let ParseIpArgs { code, message } = match content {
+	let ParseIpArgs { code, message, policy } = match content {
 		Ok(content) => parse_attrs(&content)
 			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
 			.unwrap_or_default(),
 		Err(_) => ParseIpArgs::default(),
 	};
 
+	let policy = policy.to_policy(
+		quote! { ParseIpPolicy },
+		quote! { ParseIpClass },
+		quote! { ParseIpNetwork },
+	);
+
+	field.add_unparse(Some(quote! { value.to_string() }));
+
 	if field.is_ref() {
 		field.set_is_ref(false);
 		#[rustfmt::skip]
 		let result = quote! {
 			let (mut #new_reference, error) = if can_continue(&errors, failure_mode, #field_name) {
-			  parse_ipv6_fn(#reference, #field_name, #code, #message)
+			  parse_ipv6_fn(#reference, &#policy, #field_name, #code, #message)
 			} else {
 			  (default_ipv6_fn(), None)
 			};
@@ -90,7 +112,7 @@ pub fn create_ipv6_parse(
 		let result = quote! {
   		let (mut #new_reference, error) = if can_continue(&errors, failure_mode, #field_name) {
         let _ref = &#reference;
-  		  parse_ipv6_fn(_ref, #field_name, #code, #message)
+  		  parse_ipv6_fn(_ref, &#policy, #field_name, #code, #message)
   		} else {
   		  (default_ipv6_fn(), None)
   		};
@@ -0,0 +1,107 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, LitStr, Result, parse::ParseStream};
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::commons::{ArgParser, parse_attrs, remove_parens},
+};
+
+pub struct CountryArgs {
+	pub code: LitStr,
+	pub message: LitStr,
+	pub format: Option<LitStr>,
+}
+
+impl Default for CountryArgs {
+	fn default() -> Self {
+		CountryArgs {
+			code: LitStr::new("country", Span::call_site()),
+			message: LitStr::new("invalid country code", Span::call_site()),
+			format: None,
+		}
+	}
+}
+
+impl ArgParser for CountryArgs {
+	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code", "format"];
+
+	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
+		match name {
+			"code" => self.code = input.parse()?,
+			"message" => self.message = input.parse()?,
+			"format" => self.format = Some(input.parse()?),
+			_ => return Err(Error::new(input.span(), "unknown arg")),
+		}
+
+		Ok(())
+	}
+}
+
+pub fn create_country(input: ParseStream, field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
+	imports.borrow_mut().add(Import::ValidationFunction(
+		"iso::validate_country as validate_country_fn",
+	));
+
+	let field_name = field.get_name();
+	let reference = field.get_reference();
+	let content = remove_parens(input);
+
+	let args: CountryArgs = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => CountryArgs::default(),
+	};
+
+	let format = get_country_format(&args.format);
+	let CountryArgs { code, message, .. } = args;
+
+	if field.is_ref() {
+		field.set_is_ref(true);
+		#[rustfmt::skip]
+		let result = quote! {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_country_fn(#reference, #format, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+		  let _ref = &#reference;
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_country_fn(_ref, #format, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	}
+}
+
+pub fn get_country_format(format: &Option<LitStr>) -> TokenStream {
+	match format {
+		Some(format) if matches!(format.value().as_str(), "alpha2" | "alpha3") => quote! { Some(#format) },
+		Some(format) => {
+			emit_error!(
+				format.span(),
+				"unknown country format, expected \"alpha2\" or \"alpha3\""
+			);
+			quote! { None }
+		}
+		None => quote! { None },
+	}
+}
@@ -0,0 +1,8 @@
+pub mod country;
+pub mod currency;
+pub mod language_tag;
+pub mod parse_country;
+pub mod parse_currency;
+pub mod parse_language_tag;
+pub mod parse_timezone;
+pub mod timezone;
@@ -0,0 +1,93 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::ParseStream;
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::{
+		commons::{parse_attrs, remove_parens},
+		isos::country::{CountryArgs, get_country_format},
+	},
+};
+
+pub fn create_country_parse(
+	input: ParseStream,
+	field: &mut FieldAttributes,
+	imports: &RefCell<ImportsSet>,
+) -> TokenStream {
+	imports
+		.borrow_mut()
+		.add(Import::ParsingFunction("iso::default_country as default_country_fn"));
+	imports
+		.borrow_mut()
+		.add(Import::ParsingFunction("iso::parse_country as parse_country_fn"));
+
+	let field_name = field.get_name();
+	let reference = field.get_reference();
+	field.increment_modifications();
+	let new_reference = field.get_reference();
+	let content = remove_parens(input);
+
+	let args: CountryArgs = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => CountryArgs::default(),
+	};
+
+	let is_alpha3 = args.format.as_ref().is_some_and(|format| format.value() == "alpha3");
+	let format = get_country_format(&args.format);
+	let CountryArgs { code, message, .. } = args;
+
+	field.add_unparse(Some(if is_alpha3 {
+		quote! { value.alpha3().to_string() }
+	} else {
+		quote! { value.alpha2().to_string() }
+	}));
+
+	if field.is_ref() {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+			let (mut #new_reference, error) = if can_continue(&errors, failure_mode, #field_name) {
+			  parse_country_fn(#reference, #format, #field_name, #code, #message)
+			} else {
+			  (default_country_fn(), None)
+			};
+
+			if let Some(e) = error {
+				append_error(&mut errors, e, failure_mode, #field_name);
+				if should_fail_fast(&errors, failure_mode, #field_name) {
+				  return Err(errors);
+			  };
+		  }
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+  		let (mut #new_reference, error) = if can_continue(&errors, failure_mode, #field_name) {
+        let _ref = &#reference;
+  		  parse_country_fn(_ref, #format, #field_name, #code, #message)
+  		} else {
+  		  (default_country_fn(), None)
+  		};
+
+			if let Some(e) = error {
+				append_error(&mut errors, e, failure_mode, #field_name);
+				if should_fail_fast(&errors, failure_mode, #field_name) {
+				  return Err(errors);
+			  };
+		  }
+		};
+
+		result
+	}
+}
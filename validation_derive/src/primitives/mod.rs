@@ -1,13 +1,20 @@
+pub mod chars;
 pub mod collections;
 pub mod commons;
 pub mod customs;
+pub mod domains;
 pub mod field_datas;
 pub mod format;
 pub mod inlines;
 pub mod ips;
+pub mod isos;
 pub mod option;
+pub mod passwords;
 pub mod patterns;
+pub mod payments;
+pub mod phones;
 pub mod ranges;
 pub mod specials;
 pub mod time;
+pub mod unicodes;
 pub mod uuids;
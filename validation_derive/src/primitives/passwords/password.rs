@@ -0,0 +1,190 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, Expr, ExprArray, ExprLit, Ident, Lit, LitStr, Result, parse::ParseStream, parse_str};
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::commons::{ArgParser, parse_attrs, remove_parens},
+};
+
+pub struct PasswordArgs {
+	pub code: LitStr,
+	pub message: LitStr,
+	pub min_entropy: Option<Expr>,
+	pub min_score: Option<Expr>,
+	pub require: Option<ExprArray>,
+	pub forbid_fields: Option<ExprArray>,
+	pub max_repeats: Option<Expr>,
+	pub reference_year: Option<Expr>,
+}
+
+impl Default for PasswordArgs {
+	fn default() -> Self {
+		PasswordArgs {
+			code: LitStr::new("password", Span::call_site()),
+			message: LitStr::new("weak password", Span::call_site()),
+			min_entropy: None,
+			min_score: None,
+			require: None,
+			forbid_fields: None,
+			max_repeats: None,
+			reference_year: None,
+		}
+	}
+}
+
+impl ArgParser for PasswordArgs {
+	const POSITIONAL_KEYS: &'static [&'static str] = &[
+		"message",
+		"code",
+		"min_entropy",
+		"min_score",
+		"require",
+		"forbid_fields",
+		"max_repeats",
+		"reference_year",
+	];
+
+	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
+		match name {
+			"code" => self.code = input.parse()?,
+			"message" => self.message = input.parse()?,
+			"min_entropy" => self.min_entropy = Some(input.parse()?),
+			"min_score" => self.min_score = Some(input.parse()?),
+			"require" => self.require = Some(input.parse()?),
+			"forbid_fields" => self.forbid_fields = Some(input.parse()?),
+			"max_repeats" => self.max_repeats = Some(input.parse()?),
+			"reference_year" => self.reference_year = Some(input.parse()?),
+			_ => return Err(Error::new(input.span(), "unknown arg")),
+		}
+
+		Ok(())
+	}
+}
+
+pub fn create_password(input: ParseStream, field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
+	imports.borrow_mut().add(Import::ValidationFunction(
+		"password::validate_password as validate_password_fn",
+	));
+	imports
+		.borrow_mut()
+		.add(Import::ValidationFunction("password::PasswordPolicy"));
+
+	let field_name = field.get_name();
+	let reference = field.get_reference();
+	let content = remove_parens(input);
+
+	let args: PasswordArgs = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => PasswordArgs::default(),
+	};
+
+	let policy = get_password_policy(&args, field);
+	let PasswordArgs { code, message, .. } = args;
+
+	if field.is_ref() {
+		field.set_is_ref(true);
+		#[rustfmt::skip]
+		let result = quote! {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_password_fn(#reference, &#policy, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+		  let _ref = &#reference;
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_password_fn(_ref, &#policy, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	}
+}
+
+fn get_password_policy(args: &PasswordArgs, field: &mut FieldAttributes) -> TokenStream {
+	let min_entropy = args
+		.min_entropy
+		.as_ref()
+		.map_or(quote! { None }, |min_entropy| quote! { Some(#min_entropy) });
+	let min_score = args
+		.min_score
+		.as_ref()
+		.map_or(quote! { None }, |min_score| quote! { Some(#min_score) });
+	let max_repeats = args
+		.max_repeats
+		.as_ref()
+		.map_or(quote! { None }, |max_repeats| quote! { Some(#max_repeats) });
+	let reference_year = args
+		.reference_year
+		.as_ref()
+		.map_or(quote! { None }, |reference_year| quote! { Some(#reference_year) });
+	let require = args
+		.require
+		.as_ref()
+		.map_or(quote! { [] }, |require| quote! { #require });
+
+	for class in args.require.iter().flat_map(|require| require.elems.iter()) {
+		if let Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) = class
+			&& !matches!(lit.value().as_str(), "upper" | "lower" | "digit" | "symbol")
+		{
+			emit_error!(
+				lit.span(),
+				"require must be \"upper\", \"lower\", \"digit\" or \"symbol\""
+			);
+		}
+	}
+
+	let forbidden = args
+		.forbid_fields
+		.iter()
+		.flat_map(|fields| fields.elems.iter())
+		.filter_map(|sibling| {
+			if let Expr::Lit(ExprLit {
+				lit: Lit::Str(name), ..
+			}) = sibling
+			{
+				let Ok(mut sibling) = parse_str::<Ident>(&name.value()) else {
+					emit_error!(name.span(), "expected a sibling field name");
+					return None;
+				};
+
+				sibling.set_span(name.span());
+				let reference = field.add_sibling(&sibling);
+				Some(quote! { #reference.as_sibling() })
+			} else {
+				emit_error!(sibling, "expected a sibling field name as a string literal");
+				None
+			}
+		});
+
+	let forbidden: Vec<TokenStream> = forbidden.collect();
+
+	quote! {
+		PasswordPolicy {
+			min_entropy: #min_entropy,
+			min_score: #min_score,
+			require: &#require,
+			forbidden: &[#(#forbidden),*],
+			max_repeats: #max_repeats,
+			reference_year: #reference_year,
+		}
+	}
+}
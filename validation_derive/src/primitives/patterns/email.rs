@@ -3,7 +3,7 @@ use std::cell::RefCell;
 use proc_macro_error::emit_error;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Error, LitStr, Result, parse::ParseStream};
+use syn::{Error, ExprArray, LitBool, LitStr, Result, parse::ParseStream};
 
 use crate::{
 	ImportsSet,
@@ -15,6 +15,12 @@ use crate::{
 pub struct EmailArgs {
 	pub code: LitStr,
 	pub message: LitStr,
+	pub allow_display_name: bool,
+	pub allowed_domains: Option<ExprArray>,
+	pub blocked_domains: Option<ExprArray>,
+	pub require_tld: bool,
+	pub allow_ip_domain: bool,
+	pub allow_disposable: bool,
 }
 
 impl Default for EmailArgs {
@@ -22,17 +28,38 @@ impl Default for EmailArgs {
 		EmailArgs {
 			code: LitStr::new("email", Span::call_site()),
 			message: LitStr::new("invalid email format", Span::call_site()),
+			allow_display_name: false,
+			allowed_domains: None,
+			blocked_domains: None,
+			require_tld: true,
+			allow_ip_domain: false,
+			allow_disposable: false,
 		}
 	}
 }
 
 impl ArgParser for EmailArgs {
-	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code"];
+	const POSITIONAL_KEYS: &'static [&'static str] = &[
+		"message",
+		"code",
+		"allow_display_name",
+		"allowed_domains",
+		"blocked_domains",
+		"require_tld",
+		"allow_ip_domain",
+		"allow_disposable",
+	];
 
 	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
 		match name {
 			"code" => self.code = input.parse()?,
 			"message" => self.message = input.parse()?,
+			"allow_display_name" => self.allow_display_name = input.parse::<LitBool>()?.value(),
+			"allowed_domains" => self.allowed_domains = Some(input.parse()?),
+			"blocked_domains" => self.blocked_domains = Some(input.parse()?),
+			"require_tld" => self.require_tld = input.parse::<LitBool>()?.value(),
+			"allow_ip_domain" => self.allow_ip_domain = input.parse::<LitBool>()?.value(),
+			"allow_disposable" => self.allow_disposable = input.parse::<LitBool>()?.value(),
 			_ => return Err(Error::new(input.span(), "unknown arg")),
 		}
 
@@ -41,26 +68,32 @@ impl ArgParser for EmailArgs {
 }
 
 pub fn create_email(input: ParseStream, field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
+	imports.borrow_mut().add(Import::ValidationFunction(
+		"email::validate_email_with_policy as validate_email_fn",
+	));
 	imports
 		.borrow_mut()
-		.add(Import::ValidationFunction("email::validate_email as validate_email_fn"));
+		.add(Import::ValidationFunction("email::EmailPolicy"));
 
 	let field_name = field.get_name();
 	let reference = field.get_reference();
 	let content = remove_parens(input);
 
-	let EmailArgs { code, message } = match content {
+	let args: EmailArgs = match content {
 		Ok(content) => parse_attrs(&content)
 			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
 			.unwrap_or_default(),
 		Err(_) => EmailArgs::default(),
 	};
 
+	let policy = get_email_policy(&args, quote! { EmailPolicy });
+	let EmailArgs { code, message, .. } = args;
+
 	if field.is_ref() {
 		field.set_is_ref(true);
 		#[rustfmt::skip]
 		let result = quote! {
-			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_email_fn(#reference, #field_name, #code, #message) {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_email_fn(#reference, &#policy, #field_name, #code, #message) {
         append_error(&mut errors, e, failure_mode, #field_name);
         if should_fail_fast(&errors, failure_mode, #field_name) {
      			return Err(errors);
@@ -74,7 +107,7 @@ pub fn create_email(input: ParseStream, field: &mut FieldAttributes, imports: &R
 		#[rustfmt::skip]
 		let result = quote! {
 		  let _ref = &#reference;
-			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_email_fn(_ref, #field_name, #code, #message) {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_email_fn(_ref, &#policy, #field_name, #code, #message) {
         append_error(&mut errors, e, failure_mode, #field_name);
         if should_fail_fast(&errors, failure_mode, #field_name) {
      			return Err(errors);
@@ -85,3 +118,29 @@ pub fn create_email(input: ParseStream, field: &mut FieldAttributes, imports: &R
 		result
 	}
 }
+
+pub fn get_email_policy(args: &EmailArgs, policy: TokenStream) -> TokenStream {
+	let allowed_domains = args
+		.allowed_domains
+		.as_ref()
+		.map_or(quote! { [] }, |domains| quote! { #domains });
+	let blocked_domains = args
+		.blocked_domains
+		.as_ref()
+		.map_or(quote! { [] }, |domains| quote! { #domains });
+	let allow_display_name = args.allow_display_name;
+	let require_tld = args.require_tld;
+	let allow_ip_domain = args.allow_ip_domain;
+	let allow_disposable = args.allow_disposable;
+
+	quote! {
+		#policy {
+			allow_display_name: #allow_display_name,
+			allowed_domains: &#allowed_domains,
+			blocked_domains: &#blocked_domains,
+			require_tld: #require_tld,
+			allow_ip_domain: #allow_ip_domain,
+			allow_disposable: #allow_disposable,
+		}
+	}
+}
@@ -1,5 +1,7 @@
 pub mod contains;
 pub mod email;
+pub mod parse_email;
+pub mod parse_url;
 pub mod pattern;
 pub mod prefix;
 pub mod suffix;
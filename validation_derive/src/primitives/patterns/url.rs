@@ -3,7 +3,7 @@ use std::cell::RefCell;
 use proc_macro_error::emit_error;
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Error, LitStr, Result, parse::ParseStream};
+use syn::{Error, Expr, ExprArray, LitBool, LitStr, Result, parse::ParseStream};
 
 use crate::{
 	ImportsSet,
@@ -15,6 +15,12 @@ use crate::{
 pub struct UrlArgs {
 	pub code: LitStr,
 	pub message: LitStr,
+	pub schemes: Option<ExprArray>,
+	pub require_host: bool,
+	pub allowed_hosts: Option<ExprArray>,
+	pub blocked_hosts: Option<ExprArray>,
+	pub deny_private_ips: bool,
+	pub max_length: Option<Expr>,
 }
 
 impl Default for UrlArgs {
@@ -22,17 +28,38 @@ impl Default for UrlArgs {
 		UrlArgs {
 			code: LitStr::new("url", Span::call_site()),
 			message: LitStr::new("invalid url format", Span::call_site()),
+			schemes: None,
+			require_host: false,
+			allowed_hosts: None,
+			blocked_hosts: None,
+			deny_private_ips: false,
+			max_length: None,
 		}
 	}
 }
 
 impl ArgParser for UrlArgs {
-	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code"];
+	const POSITIONAL_KEYS: &'static [&'static str] = &[
+		"message",
+		"code",
+		"schemes",
+		"require_host",
+		"allowed_hosts",
+		"blocked_hosts",
+		"deny_private_ips",
+		"max_length",
+	];
 
 	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
 		match name {
 			"code" => self.code = input.parse()?,
 			"message" => self.message = input.parse()?,
+			"schemes" => self.schemes = Some(input.parse()?),
+			"require_host" => self.require_host = input.parse::<LitBool>()?.value(),
+			"allowed_hosts" => self.allowed_hosts = Some(input.parse()?),
+			"blocked_hosts" => self.blocked_hosts = Some(input.parse()?),
+			"deny_private_ips" => self.deny_private_ips = input.parse::<LitBool>()?.value(),
+			"max_length" => self.max_length = Some(input.parse()?),
 			_ => return Err(Error::new(input.span(), "unknown arg")),
 		}
 
@@ -41,26 +68,30 @@ impl ArgParser for UrlArgs {
 }
 
 pub fn create_url(input: ParseStream, field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
-	imports
-		.borrow_mut()
-		.add(Import::ValidationFunction("url::validate_url as validate_url_fn"));
+	imports.borrow_mut().add(Import::ValidationFunction(
+		"url::validate_url_with_policy as validate_url_fn",
+	));
+	imports.borrow_mut().add(Import::ValidationFunction("url::UrlPolicy"));
 
 	let field_name = field.get_name();
 	let reference = field.get_reference();
 	let content = remove_parens(input);
 
-	let UrlArgs { code, message } = match content {
+	let args: UrlArgs = match content {
 		Ok(content) => parse_attrs(&content)
 			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
 			.unwrap_or_default(),
 		Err(_) => UrlArgs::default(),
 	};
 
+	let policy = get_url_policy(&args, quote! { UrlPolicy });
+	let UrlArgs { code, message, .. } = args;
+
 	if field.is_ref() {
 		field.set_is_ref(true);
 		#[rustfmt::skip]
 		let result = quote! {
-			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_url_fn(#reference, #field_name, #code, #message) {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_url_fn(#reference, &#policy, #field_name, #code, #message) {
         append_error(&mut errors, e, failure_mode, #field_name);
         if should_fail_fast(&errors, failure_mode, #field_name) {
      			return Err(errors);
@@ -74,7 +105,7 @@ pub fn create_url(input: ParseStream, field: &mut FieldAttributes, imports: &Ref
 		#[rustfmt::skip]
 		let result = quote! {
 		  let _ref = &#reference;
-			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_url_fn(_ref, #field_name, #code, #message) {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_url_fn(_ref, &#policy, #field_name, #code, #message) {
         append_error(&mut errors, e, failure_mode, #field_name);
         if should_fail_fast(&errors, failure_mode, #field_name) {
      			return Err(errors);
@@ -85,3 +116,35 @@ pub fn create_url(input: ParseStream, field: &mut FieldAttributes, imports: &Ref
 		result
 	}
 }
+
+pub fn get_url_policy(args: &UrlArgs, policy: TokenStream) -> TokenStream {
+	let schemes = args
+		.schemes
+		.as_ref()
+		.map_or(quote! { [] }, |schemes| quote! { #schemes });
+	let allowed_hosts = args
+		.allowed_hosts
+		.as_ref()
+		.map_or(quote! { [] }, |hosts| quote! { #hosts });
+	let blocked_hosts = args
+		.blocked_hosts
+		.as_ref()
+		.map_or(quote! { [] }, |hosts| quote! { #hosts });
+	let require_host = args.require_host;
+	let deny_private_ips = args.deny_private_ips;
+	let max_length = match &args.max_length {
+		Some(max_length) => quote! { Some(#max_length) },
+		None => quote! { None },
+	};
+
+	quote! {
+		#policy {
+			schemes: &#schemes,
+			require_host: #require_host,
+			allowed_hosts: &#allowed_hosts,
+			blocked_hosts: &#blocked_hosts,
+			deny_private_ips: #deny_private_ips,
+			max_length: #max_length,
+		}
+	}
+}
@@ -0,0 +1,4 @@
+pub mod bic;
+pub mod credit_card;
+pub mod iban;
+pub mod luhn;
@@ -0,0 +1,2 @@
+pub mod parse_phone;
+pub mod phone;
@@ -0,0 +1,182 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, Expr, ExprArray, ExprLit, Lit, LitStr, Result, parse::ParseStream};
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::commons::{ArgParser, parse_attrs, remove_parens},
+};
+
+const PHONE_TYPES: &[&str] = &[
+	"mobile",
+	"fixed_line",
+	"toll_free",
+	"premium_rate",
+	"shared_cost",
+	"personal_number",
+	"voip",
+	"pager",
+	"uan",
+	"voicemail",
+];
+
+const PHONE_REGIONS: &[&str] = &[
+	"AC", "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AR", "AS", "AT", "AU", "AW", "AX", "AZ", "BA", "BB", "BD",
+	"BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS", "BT", "BW", "BY", "BZ", "CA", "CC",
+	"CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN", "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ",
+	"DK", "DM", "DO", "DZ", "EC", "EE", "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB",
+	"GD", "GE", "GF", "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GT", "GU", "GW", "GY", "HK", "HN", "HR",
+	"HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM", "JO", "JP", "KE", "KG", "KH",
+	"KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC", "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY",
+	"MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK", "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV",
+	"MW", "MX", "MY", "MZ", "NA", "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE",
+	"PF", "PG", "PH", "PK", "PL", "PM", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW", "SA", "SB",
+	"SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS", "ST", "SV", "SX", "SY", "SZ",
+	"TA", "TC", "TD", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO", "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "US",
+	"UY", "UZ", "VA", "VC", "VE", "VG", "VI", "VN", "VU", "WF", "WS", "XK", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+pub struct PhoneArgs {
+	pub code: LitStr,
+	pub message: LitStr,
+	pub region: Option<Expr>,
+	pub regions: Option<ExprArray>,
+	pub types: Option<ExprArray>,
+}
+
+impl Default for PhoneArgs {
+	fn default() -> Self {
+		PhoneArgs {
+			code: LitStr::new("phone", Span::call_site()),
+			message: LitStr::new("invalid phone number", Span::call_site()),
+			region: None,
+			regions: None,
+			types: None,
+		}
+	}
+}
+
+impl ArgParser for PhoneArgs {
+	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code", "region", "regions", "types"];
+
+	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
+		match name {
+			"code" => self.code = input.parse()?,
+			"message" => self.message = input.parse()?,
+			"region" => self.region = Some(input.parse()?),
+			"regions" => self.regions = Some(input.parse()?),
+			"types" => self.types = Some(input.parse()?),
+			_ => return Err(Error::new(input.span(), "unknown arg")),
+		}
+
+		Ok(())
+	}
+}
+
+pub fn create_phone(input: ParseStream, field: &mut FieldAttributes, imports: &RefCell<ImportsSet>) -> TokenStream {
+	imports
+		.borrow_mut()
+		.add(Import::ValidationFunction("phone::validate_phone as validate_phone_fn"));
+	imports
+		.borrow_mut()
+		.add(Import::ValidationFunction("phone::PhonePolicy"));
+
+	let field_name = field.get_name();
+	let reference = field.get_reference();
+	let content = remove_parens(input);
+
+	let args: PhoneArgs = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => PhoneArgs::default(),
+	};
+
+	let policy = get_phone_policy(&args, quote! { PhonePolicy });
+	let PhoneArgs { code, message, .. } = args;
+
+	if field.is_ref() {
+		field.set_is_ref(true);
+		#[rustfmt::skip]
+		let result = quote! {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_phone_fn(#reference, &#policy, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+		  let _ref = &#reference;
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_phone_fn(_ref, &#policy, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	}
+}
+
+pub fn get_phone_region(region: &Option<Expr>) -> TokenStream {
+	if let Some(Expr::Lit(ExprLit { lit: Lit::Str(lit), .. })) = region {
+		check_phone_region(lit);
+	}
+
+	region.as_ref().map_or(quote! { None }, |region| {
+		quote! { Some(::std::convert::AsRef::<str>::as_ref(&#region)) }
+	})
+}
+
+pub fn get_phone_policy(args: &PhoneArgs, policy: TokenStream) -> TokenStream {
+	let region = get_phone_region(&args.region);
+
+	for region in args.regions.iter().flat_map(|regions| regions.elems.iter()) {
+		if let Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) = region {
+			check_phone_region(lit);
+		}
+	}
+
+	for kind in args.types.iter().flat_map(|types| types.elems.iter()) {
+		if let Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) = kind
+			&& !PHONE_TYPES.contains(&lit.value().as_str())
+		{
+			emit_error!(
+				lit.span(),
+				"type must be \"mobile\", \"fixed_line\", \"toll_free\", \"premium_rate\", \"shared_cost\", \"personal_number\", \"voip\", \"pager\", \"uan\" or \"voicemail\""
+			);
+		}
+	}
+
+	let regions = args
+		.regions
+		.as_ref()
+		.map_or(quote! { [] }, |regions| quote! { #regions });
+	let types = args.types.as_ref().map_or(quote! { [] }, |types| quote! { #types });
+
+	quote! {
+		#policy {
+			region: #region,
+			regions: &#regions,
+			types: &#types,
+		}
+	}
+}
+
+fn check_phone_region(region: &LitStr) {
+	if !PHONE_REGIONS.contains(&region.value().to_ascii_uppercase().as_str()) {
+		emit_error!(region.span(), "region must be an ISO 3166-1 alpha-2 code, like \"BR\"");
+	}
+}
@@ -16,6 +16,7 @@ pub struct LengthArgs {
 	pub range: Option<ExprRange>,
 	pub code: LitStr,
 	pub message: LitStr,
+	pub unit: Option<LitStr>,
 }
 
 impl Default for LengthArgs {
@@ -24,18 +25,20 @@ impl Default for LengthArgs {
 			range: None,
 			code: LitStr::new("length", Span::call_site()),
 			message: LitStr::new("length out of range", Span::call_site()),
+			unit: None,
 		}
 	}
 }
 
 impl ArgParser for LengthArgs {
-	const POSITIONAL_KEYS: &'static [&'static str] = &["range", "message", "code"];
+	const POSITIONAL_KEYS: &'static [&'static str] = &["range", "message", "code", "unit"];
 
 	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
 		match name {
 			"range" => self.range = Some(input.parse()?),
 			"code" => self.code = input.parse()?,
 			"message" => self.message = input.parse()?,
+			"unit" => self.unit = Some(input.parse()?),
 			_ => return Err(Error::new(input.span(), "unknown arg")),
 		}
 
@@ -52,7 +55,12 @@ pub fn create_length(input: ParseStream, field: &mut FieldAttributes, imports: &
 	let reference = field.get_reference();
 	let content = remove_parens(input);
 
-	let LengthArgs { range, code, message } = match content {
+	let LengthArgs {
+		range,
+		code,
+		message,
+		unit,
+	} = match content {
 		Ok(content) => parse_attrs(&content)
 			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
 			.unwrap_or_default(),
@@ -63,6 +71,12 @@ pub fn create_length(input: ParseStream, field: &mut FieldAttributes, imports: &
 		emit_error!(input.span(), "needs a range");
 	}
 
+	imports
+		.borrow_mut()
+		.add(Import::ValidationFunction("length::Length as _"));
+	let unit = get_length_unit(unit.as_ref());
+	let length = quote! { (#reference).measure(#unit) };
+
 	if field.is_ref() {
 		field.set_is_ref(true);
 	} else {
@@ -71,7 +85,7 @@ pub fn create_length(input: ParseStream, field: &mut FieldAttributes, imports: &
 
 	#[rustfmt::skip]
 	let result = quote! {
-		if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_length_fn(&#reference.len(), #range, #field_name, #code, #message) {
+		if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_length_fn(&#length, #range, #field_name, #code, #message) {
       append_error(&mut errors, e, failure_mode, #field_name);
       if should_fail_fast(&errors, failure_mode, #field_name) {
    			return Err(errors);
@@ -81,3 +95,23 @@ pub fn create_length(input: ParseStream, field: &mut FieldAttributes, imports: &
 
 	result
 }
+
+pub fn get_length_unit(unit: Option<&LitStr>) -> TokenStream {
+	let Some(unit) = unit else {
+		return quote! { ValidationSettings::get_length_unit() };
+	};
+
+	match unit.value().as_str() {
+		"bytes" => quote! { LengthUnit::Bytes },
+		"chars" => quote! { LengthUnit::Chars },
+		"graphemes" => quote! { LengthUnit::Graphemes },
+		"utf16" => quote! { LengthUnit::Utf16 },
+		_ => {
+			emit_error!(
+				unit.span(),
+				"unit must be \"bytes\", \"chars\", \"graphemes\" or \"utf16\""
+			);
+			quote! { LengthUnit::Bytes }
+		}
+	}
+}
@@ -0,0 +1,35 @@
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Expr, Token, parse::ParseStream};
+
+use crate::{attributes::ValidationAttributes, fields::FieldAttributes};
+
+pub fn create_default(
+	input: ParseStream,
+	field: &mut FieldAttributes,
+	attributes: &ValidationAttributes,
+) -> TokenStream {
+	if !attributes.payload {
+		emit_error!(input.span(), "requires payload attribute");
+		return quote! {};
+	}
+
+	if field.get_default().is_some() {
+		emit_error!(input.span(), "default already defined");
+		return quote! {};
+	}
+
+	if input.peek(Token![=]) {
+		let value = input.parse::<Token![=]>().and_then(|_| input.parse::<Expr>());
+
+		match value {
+			Ok(value) => field.set_default(quote! { #value }),
+			Err(error) => emit_error!(error.span(), "{}", error),
+		}
+	} else {
+		field.set_default(quote! { Default::default() });
+	}
+
+	quote! {}
+}
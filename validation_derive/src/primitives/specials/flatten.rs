@@ -0,0 +1,36 @@
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::ParseStream;
+
+use crate::{attributes::ValidationAttributes, factories::core::AbstractValidationFactory, fields::FieldAttributes};
+
+pub fn create_flatten(
+	factory: &dyn AbstractValidationFactory,
+	input: ParseStream,
+	field: &mut FieldAttributes,
+	attributes: &ValidationAttributes,
+) -> TokenStream {
+	if field.get_scopes() > 0 {
+		emit_error!(input.span(), "unsupported inside collection rules");
+		return quote! {};
+	}
+
+	if attributes.partial {
+		emit_error!(input.span(), "unsupported with partial attribute");
+		return quote! {};
+	}
+
+	if attributes.multipart {
+		emit_error!(input.span(), "unsupported with multipart attribute");
+		return quote! {};
+	}
+
+	if field.is_flatten() {
+		emit_error!(input.span(), "duplicate flatten rule");
+		return quote! {};
+	}
+
+	field.set_flatten(true);
+	factory.create_nested(input, field)
+}
@@ -1,8 +1,8 @@
 use std::cell::RefCell;
 
 use proc_macro_error::emit_error;
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::{format_ident, quote};
 use syn::{Error, Result, Type, meta::ParseNestedMeta, parse::ParseStream};
 
 use crate::{
@@ -68,35 +68,32 @@ impl ArgParser for ForEachArgs {
 	}
 }
 
-pub fn create_for_each(
+pub fn get_item_operations(
 	factory: &dyn AbstractValidationFactory,
 	meta: ParseNestedMeta<'_>,
 	field: &mut FieldAttributes,
 	attributes: &ValidationAttributes,
 	imports: &RefCell<ImportsSet>,
-) -> TokenStream {
+	mut on_config: impl FnMut(ForEachArgs, &mut FieldAttributes),
+) -> (Vec<TokenStream>, bool) {
 	let mut operations = Vec::<TokenStream>::new();
-	let reference = field.get_reference();
-	field.enter_scope();
-	let is_ref = field.is_ref();
-	field.set_is_ref(!field.is_payload());
-
-	let item_reference = field.get_reference();
-	let mut args = ForEachArgs::default();
-	let current_type = field.get_current_type().clone();
-	args.update_from_type(&current_type, field);
+	let mut nested = false;
 
 	let _ = meta.parse_nested_meta(|meta| {
 		if meta.path.is_ident("config") {
 			let content = remove_parens(meta.input);
-			args = match content {
+			let args = match content {
 				Ok(content) => parse_attrs(&content)
 					.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
 					.unwrap_or_default(),
 				Err(_) => ForEachArgs::default(),
 			};
 
-			args.update_from_item_type(field);
+			on_config(args, field);
+		} else if meta.path.is_ident("nested") {
+			nested = true;
+			let operation = factory.create_nested(meta.input, field);
+			operations.push(operation);
 		} else if meta.path.is_ident("validate")
 			&& let Err(error) = meta.parse_nested_meta(|meta| {
 				let validation = get_validate_by_attr_macro(factory, meta, field, attributes, imports);
@@ -120,6 +117,7 @@ pub fn create_for_each(
 			emit_error!(error.span(), error.to_string());
 		} else if meta.path.is_ident("special")
 			&& let Err(error) = meta.parse_nested_meta(|meta| {
+				nested = nested || meta.path.is_ident("nested");
 				let operation = get_special_by_attr_macro(factory, meta, field, attributes, imports);
 				operations.push(operation.clone());
 				Ok(())
@@ -129,10 +127,114 @@ pub fn create_for_each(
 		Ok(())
 	});
 
+	(operations, nested)
+}
+
+pub fn create_for_each(
+	factory: &dyn AbstractValidationFactory,
+	meta: ParseNestedMeta<'_>,
+	field: &mut FieldAttributes,
+	attributes: &ValidationAttributes,
+	imports: &RefCell<ImportsSet>,
+) -> TokenStream {
+	let mut from_collection = None;
+	let unparses_count = field.get_unparses_count();
+	let reference = field.get_reference();
+	field.enter_scope();
+	let scopes = field.get_scopes();
+	let is_ref = field.is_ref();
+	field.set_is_ref(!field.is_payload());
+
+	let item_reference = field.get_reference();
+	let mut args = ForEachArgs::default();
+	let current_type = field.get_current_type().clone();
+	args.update_from_type(&current_type, field);
+	args.update_from_item_type(field);
+
+	let (operations, indexed) = get_item_operations(factory, meta, field, attributes, imports, |config, field| {
+		args = config;
+		from_collection = args.from_collection.clone();
+		args.update_from_item_type(field);
+	});
+
 	args.update_from_type(&current_type, field);
 	let final_item_reference = field.get_reference();
 	field.exit_scope();
 
+	if !field.is_payload() {
+		field.set_is_ref(is_ref);
+	}
+
+	let mut item_unparses = field.take_unparses(unparses_count);
+	item_unparses.reverse();
+
+	if attributes.payload {
+		let unparses: Option<Vec<TokenStream>> = item_unparses.into_iter().collect();
+		let collect = match from_collection {
+			Some(from_collection) => quote! { collect::<#from_collection>() },
+			None => quote! { collect() },
+		};
+
+		field.add_unparse(unparses.map(|unparses| {
+			quote! {
+				value.into_iter().map(|value| {
+					#(let value = #unparses;)*
+					value
+				}).#collect
+			}
+		}));
+	}
+
+	let field_name = field.get_name();
+	let index = format_ident!("index_{}", scopes);
+	let previous = format_ident!("previous_{}", scopes);
+	let items_errors = format_ident!("items_errors_{}", scopes);
+
+	let (enumerate, before, body, after) = if indexed {
+		(
+			quote! { .enumerate() },
+			quote! { let mut #items_errors = ValidationErrors::new(); },
+			{
+				let take_errors = quote! {
+				  take_item_errors(&mut errors, &mut #items_errors, #field_name, #previous, #index);
+				};
+				let operations = with_item_errors_on_return(
+					quote! { #(#operations)* },
+					&quote! {
+					  #take_errors
+					  let error = NestedValidationError::from(#items_errors, #field_name);
+					  append_error(&mut errors, error.into(), failure_mode, #field_name);
+					},
+				);
+
+				quote! {
+				  let #previous = previous_errors(&mut errors, failure_mode, #field_name);
+				  #operations
+				  #take_errors
+				}
+			},
+			quote! {
+			  if !#items_errors.is_empty() {
+					let error = NestedValidationError::from(#items_errors, #field_name);
+
+					append_error(&mut errors, error.into(), failure_mode, #field_name);
+					if should_fail_fast(&errors, failure_mode, #field_name) {
+					  return Err(errors);
+					}
+			  }
+			},
+		)
+	} else {
+		(quote! {}, quote! {}, quote! { #(#operations)* }, quote! {})
+	};
+
+	let item_binding = match (indexed, attributes.payload) {
+		(true, true) => quote! { (#index, mut #item_reference) },
+		(true, false) => quote! { (#index, #item_reference) },
+		(false, true) => quote! { mut #item_reference },
+		(false, false) => quote! { #item_reference },
+	};
+
 	match (attributes.payload, attributes.modificate, is_ref) {
 		(true, _, true) => {
 			field.increment_modifications();
@@ -142,14 +244,16 @@ pub fn create_for_each(
 			#[rustfmt::skip]
   		let result = quote! {
   		  let mut #new_reference: #to_collection = Default::default();
-  		  for mut #item_reference in *#reference.into_iter() {
-  				#(#operations)*
+  		  #before
+  		  for #item_binding in *#reference.into_iter()#enumerate {
+  				#body
 
   				Extend::extend(
   					&mut #new_reference,
   					::std::iter::once(#final_item_reference)
   				);
   		  }
+  		  #after
   		};
 
 			result
@@ -162,14 +266,16 @@ pub fn create_for_each(
 			#[rustfmt::skip]
   		let result = quote! {
   		  let mut #new_reference: #to_collection = Default::default();
-  		  for mut #item_reference in #reference.into_iter() {
-  				#(#operations)*
+  		  #before
+  		  for #item_binding in #reference.into_iter()#enumerate {
+  				#body
 
   				Extend::extend(
   					&mut #new_reference,
   					::std::iter::once(#final_item_reference)
   				);
   		  }
+  		  #after
   		};
 
 			result
@@ -178,9 +284,11 @@ pub fn create_for_each(
 			#[rustfmt::skip]
   		let result = quote! {
         let _ref_source = #reference;
-  		  for #item_reference in _ref_source.into_iter() {
-  				#(#operations)*
+        #before
+  		  for #item_binding in _ref_source.into_iter()#enumerate {
+  				#body
   		  }
+  		  #after
   		};
 
 			result
@@ -189,9 +297,11 @@ pub fn create_for_each(
 			#[rustfmt::skip]
   		let result = quote! {
         let _ref_source = &mut #reference;
-  		  for #item_reference in _ref_source.into_iter() {
-  				#(#operations)*
+        #before
+  		  for #item_binding in _ref_source.into_iter()#enumerate {
+  				#body
   		  }
+  		  #after
   		};
 
 			result
@@ -200,9 +310,11 @@ pub fn create_for_each(
 			#[rustfmt::skip]
   		let result = quote! {
   		  let _ref_source = #reference;
-  			for #item_reference in _ref_source.into_iter() {
-  				#(#operations)*
+  		  #before
+  			for #item_binding in _ref_source.into_iter()#enumerate {
+  				#body
   		  }
+  		  #after
   		};
 
 			result
@@ -210,12 +322,59 @@ pub fn create_for_each(
 		(_, _, false) => {
 			let result = quote! {
 			  let _ref_source = &#reference;
-				for #item_reference in _ref_source.into_iter() {
-					#(#operations)*
+			  #before
+				for #item_binding in _ref_source.into_iter()#enumerate {
+					#body
 			  }
+			  #after
 			};
 
 			result
 		}
 	}
 }
+
+pub fn with_item_errors_on_return(operations: TokenStream, on_return: &TokenStream) -> TokenStream {
+	let mut tokens: Vec<TokenTree> = operations.into_iter().collect();
+	let mut result = TokenStream::new();
+	let mut index = 0;
+
+	while index < tokens.len() {
+		if is_error_return(&tokens[index..]) {
+			let ret = &tokens[index];
+			let err = &tokens[index + 1];
+			let errors = &tokens[index + 2];
+			result.extend(quote! { { #on_return #ret #err #errors } });
+			index += 3;
+			continue;
+		}
+
+		if let TokenTree::Group(group) = &mut tokens[index] {
+			let mut replaced = Group::new(group.delimiter(), with_item_errors_on_return(group.stream(), on_return));
+			replaced.set_span(group.span());
+			*group = replaced;
+		}
+
+		result.extend([tokens[index].clone()]);
+		index += 1;
+	}
+
+	result
+}
+
+fn is_error_return(tokens: &[TokenTree]) -> bool {
+	match tokens {
+		[
+			TokenTree::Ident(ret),
+			TokenTree::Ident(err),
+			TokenTree::Group(group),
+			..,
+		] => {
+			ret == "return"
+				&& err == "Err"
+				&& group.delimiter() == Delimiter::Parenthesis
+				&& group.stream().to_string() == "errors"
+		}
+		_ => false,
+	}
+}
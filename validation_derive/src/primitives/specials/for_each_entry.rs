@@ -0,0 +1,299 @@
+use std::cell::RefCell;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Type, meta::ParseNestedMeta};
+
+use crate::{
+	ImportsSet,
+	attributes::ValidationAttributes,
+	factories::core::AbstractValidationFactory,
+	fields::FieldAttributes,
+	primitives::{
+		commons::extract_map_types,
+		specials::for_each::{ForEachArgs, get_item_operations, with_item_errors_on_return},
+	},
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum MapEntry {
+	Key,
+	Value,
+}
+
+impl ForEachArgs {
+	pub fn update_from_map_type(&mut self, current_type: &Type, field: &mut FieldAttributes, entry: MapEntry) {
+		if self.from_collection.is_none() {
+			self.from_collection = Some(current_type.clone());
+		}
+
+		if self.from_item.is_none()
+			&& let Some(current_type) = self.from_collection.as_ref()
+			&& let Some((key_type, value_type)) = extract_map_types(current_type)
+		{
+			self.from_item = match entry {
+				MapEntry::Key => Some(key_type),
+				MapEntry::Value => Some(value_type),
+			};
+		}
+
+		if self.to_collection.is_none()
+			&& let Some(current_type) = self.from_collection.as_ref()
+		{
+			self.to_collection = Some(current_type.clone());
+		}
+
+		if let Some(current_type) = self.to_collection.as_ref() {
+			field.set_current_type(current_type);
+		}
+	}
+}
+
+pub fn create_for_each_key(
+	factory: &dyn AbstractValidationFactory,
+	meta: ParseNestedMeta<'_>,
+	field: &mut FieldAttributes,
+	attributes: &ValidationAttributes,
+	imports: &RefCell<ImportsSet>,
+) -> TokenStream {
+	create_for_each_entry(factory, meta, field, attributes, imports, MapEntry::Key)
+}
+
+pub fn create_for_each_value(
+	factory: &dyn AbstractValidationFactory,
+	meta: ParseNestedMeta<'_>,
+	field: &mut FieldAttributes,
+	attributes: &ValidationAttributes,
+	imports: &RefCell<ImportsSet>,
+) -> TokenStream {
+	create_for_each_entry(factory, meta, field, attributes, imports, MapEntry::Value)
+}
+
+fn create_for_each_entry(
+	factory: &dyn AbstractValidationFactory,
+	meta: ParseNestedMeta<'_>,
+	field: &mut FieldAttributes,
+	attributes: &ValidationAttributes,
+	imports: &RefCell<ImportsSet>,
+	entry: MapEntry,
+) -> TokenStream {
+	let mut from_collection = None;
+	let unparses_count = field.get_unparses_count();
+	let reference = field.get_reference();
+	field.enter_scope();
+	let scopes = field.get_scopes();
+	let is_ref = field.is_ref();
+	field.set_is_ref(!field.is_payload());
+
+	let item_reference = field.get_reference();
+	let mut args = ForEachArgs::default();
+	let current_type = field.get_current_type().clone();
+	args.update_from_map_type(&current_type, field, entry);
+	args.update_from_item_type(field);
+
+	let (operations, _) = get_item_operations(factory, meta, field, attributes, imports, |config, field| {
+		args = config;
+		from_collection = args.from_collection.clone();
+		args.update_from_map_type(&current_type, field, entry);
+		args.update_from_item_type(field);
+	});
+
+	args.update_from_map_type(&current_type, field, entry);
+	let final_item_reference = field.get_reference();
+	field.exit_scope();
+
+	if !field.is_payload() {
+		field.set_is_ref(is_ref);
+	}
+
+	let mut item_unparses = field.take_unparses(unparses_count);
+	item_unparses.reverse();
+
+	if attributes.payload {
+		let unparses: Option<Vec<TokenStream>> = item_unparses.into_iter().collect();
+		let collect = match from_collection {
+			Some(from_collection) => quote! { collect::<#from_collection>() },
+			None => quote! { collect() },
+		};
+
+		field.add_unparse(unparses.map(|unparses| match entry {
+			MapEntry::Key => quote! {
+				value.into_iter().map(|(value, other)| {
+					#(let value = #unparses;)*
+					(value, other)
+				}).#collect
+			},
+			MapEntry::Value => quote! {
+				value.into_iter().map(|(other, value)| {
+					#(let value = #unparses;)*
+					(other, value)
+				}).#collect
+			},
+		}));
+	}
+
+	let field_name = field.get_name();
+	let other = format_ident!("other_{}", scopes);
+	let entry_name = format_ident!("entry_name_{}", scopes);
+	let previous = format_ident!("previous_{}", scopes);
+	let items_errors = format_ident!("items_errors_{}", scopes);
+	let old_key = format_ident!("old_key_{}", scopes);
+	let new_key = format_ident!("new_key_{}", scopes);
+	let renames = format_ident!("renames_{}", scopes);
+
+	let name_source = match entry {
+		MapEntry::Key => quote! { #item_reference },
+		MapEntry::Value => quote! { #other },
+	};
+
+	let take_errors = quote! {
+	  take_item_errors(&mut errors, &mut #items_errors, #field_name, #previous, &#entry_name);
+	};
+	let operations = with_item_errors_on_return(
+		quote! { #(#operations)* },
+		&quote! {
+		  #take_errors
+		  let error = NestedValidationError::from(#items_errors, #field_name);
+		  append_error(&mut errors, error.into(), failure_mode, #field_name);
+		},
+	);
+
+	#[rustfmt::skip]
+	let body = quote! {
+	  let #entry_name = #name_source.to_string();
+	  let #previous = previous_errors(&mut errors, failure_mode, #field_name);
+	  #operations
+	  #take_errors
+	};
+
+	#[rustfmt::skip]
+	let after = quote! {
+	  if !#items_errors.is_empty() {
+			let error = NestedValidationError::from(#items_errors, #field_name);
+
+			append_error(&mut errors, error.into(), failure_mode, #field_name);
+			if should_fail_fast(&errors, failure_mode, #field_name) {
+			  return Err(errors);
+			}
+	  }
+	};
+
+	let source = match (attributes.payload, attributes.modificate, is_ref) {
+		(true, _, true) => quote! { *#reference },
+		(true, _, false) => quote! { #reference },
+		(_, _, true) => quote! { #reference },
+		(_, true, false) => quote! { &mut #reference },
+		(_, false, false) => quote! { &#reference },
+	};
+
+	match (attributes.payload, attributes.modificate, entry) {
+		(true, _, _) => {
+			field.increment_modifications();
+			let new_reference = field.get_reference();
+			let to_collection = args.to_collection;
+
+			let (binding, pair) = match entry {
+				MapEntry::Key => (
+					quote! { (mut #item_reference, #other) },
+					quote! { (#final_item_reference, #other) },
+				),
+				MapEntry::Value => (
+					quote! { (#other, mut #item_reference) },
+					quote! { (#other, #final_item_reference) },
+				),
+			};
+
+			let extend = quote! {
+			  Extend::extend(
+					&mut #new_reference,
+					::std::iter::once(#pair)
+			  );
+			};
+
+			let extend = match entry {
+				MapEntry::Key => quote! {
+				  if #new_reference.contains_key(&#final_item_reference) {
+						append_duplicate_key(&mut #items_errors, #entry_name);
+				  } else {
+						#extend
+				  }
+				},
+				MapEntry::Value => extend,
+			};
+
+			#[rustfmt::skip]
+			let result = quote! {
+			  let mut #new_reference: #to_collection = Default::default();
+			  let mut #items_errors = ValidationErrors::new();
+			  for #binding in #source.into_iter() {
+					#body
+					#extend
+			  }
+			  #after
+			};
+
+			result
+		}
+		(_, true, MapEntry::Key) => {
+			#[rustfmt::skip]
+			let result = quote! {
+			  let _ref_source = #source;
+			  let mut #items_errors = ValidationErrors::new();
+			  let _keys: Vec<_> = _ref_source.keys().cloned().collect();
+			  let mut #renames = Vec::new();
+			  for #old_key in _keys {
+					let mut #new_key = #old_key.clone();
+
+					{
+					  let #item_reference = &mut #new_key;
+					  #body
+					}
+
+					if #new_key != #old_key {
+					  #renames.push((#old_key, #new_key));
+					}
+			  }
+
+			  let (#renames, _rejected) = resolve_renamed_keys(#renames, |key| _ref_source.contains_key(key));
+			  for #old_key in _rejected {
+					append_duplicate_key(&mut #items_errors, &#old_key);
+			  }
+
+			  let _moved: Vec<_> = #renames
+					.into_iter()
+					.filter_map(|(#old_key, #new_key)| _ref_source.remove(&#old_key).map(|#other| (#new_key, #other)))
+					.collect();
+			  Extend::extend(_ref_source, _moved);
+			  #after
+			};
+
+			result
+		}
+		(_, _, MapEntry::Key) => {
+			#[rustfmt::skip]
+			let result = quote! {
+			  let _ref_source = #source;
+			  let mut #items_errors = ValidationErrors::new();
+			  for (#item_reference, _) in _ref_source.into_iter() {
+					#body
+			  }
+			  #after
+			};
+
+			result
+		}
+		(_, _, MapEntry::Value) => {
+			#[rustfmt::skip]
+			let result = quote! {
+			  let _ref_source = #source;
+			  let mut #items_errors = ValidationErrors::new();
+			  for (#other, #item_reference) in _ref_source.into_iter() {
+					#body
+			  }
+			  #after
+			};
+
+			result
+		}
+	}
+}
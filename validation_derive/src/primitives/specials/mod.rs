@@ -1,4 +1,8 @@
+pub mod default;
+pub mod flatten;
 pub mod for_each;
+pub mod for_each_entry;
 pub mod from_type;
 pub mod ignore;
 pub mod nested;
+pub mod newtype;
@@ -1,7 +1,19 @@
-use crate::primitives::commons::{ArgParser, parse_attrs, remove_parens};
+use std::cell::RefCell;
+
+use crate::{
+	ImportsSet,
+	attributes::ValidationAttributes,
+	factories::core::AbstractValidationFactory,
+	fields::FieldAttributes,
+	primitives::{
+		commons::{ArgParser, extract_nested_type, extract_pointer_type, is_vec_type, parse_attrs, remove_parens},
+		specials::for_each::create_for_each,
+	},
+};
 use proc_macro_error::emit_error;
-use proc_macro2::Span;
-use syn::{Error, LitStr, Result, Type, parse::ParseStream};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Attribute, Error, LitStr, Result, Type, meta::ParseNestedMeta, parse::ParseStream, parse_quote, token};
 
 pub struct NestedArgs {
 	pub value: Option<Type>,
@@ -34,7 +46,32 @@ impl ArgParser for NestedArgs {
 	}
 }
 
-pub fn get_nested(input: ParseStream) -> (Option<Type>, Option<Type>, LitStr) {
+pub fn create_nested(
+	factory: &dyn AbstractValidationFactory,
+	meta: ParseNestedMeta<'_>,
+	field: &mut FieldAttributes,
+	attributes: &ValidationAttributes,
+	imports: &RefCell<ImportsSet>,
+) -> TokenStream {
+	if meta.input.peek(token::Paren) || field.get_scopes() > 0 || !is_vec_type(field.get_current_type()) {
+		return factory.create_nested(meta.input, field);
+	}
+
+	let mut operation = quote! {};
+	let attribute: Attribute = parse_quote! { #[special(for_each(nested))] };
+	let result = attribute.parse_nested_meta(|meta| {
+		operation = create_for_each(factory, meta, field, attributes, imports);
+		Ok(())
+	});
+
+	if let Err(error) = result {
+		emit_error!(error.span(), "{}", error);
+	}
+
+	operation
+}
+
+pub fn get_nested(input: ParseStream, field: &FieldAttributes) -> (Type, Option<Type>, LitStr) {
 	let content = remove_parens(input);
 	let NestedArgs { value, wrapper, code } = match content {
 		Ok(content) => parse_attrs(&content)
@@ -43,11 +80,10 @@ pub fn get_nested(input: ParseStream) -> (Option<Type>, Option<Type>, LitStr) {
 		Err(_) => NestedArgs::default(),
 	};
 
-	if let Some(nested_type) = &value {
-		(Some(nested_type.clone()), wrapper, code)
-	} else {
-		emit_error!(input.span(), "needs the value type");
+	let value = match value {
+		Some(value) => extract_pointer_type(&value),
+		None => extract_nested_type(field.get_current_type()),
+	};
 
-		(None, None, code)
-	}
+	(value, wrapper.as_ref().map(extract_pointer_type), code)
 }
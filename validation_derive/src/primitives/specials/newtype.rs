@@ -0,0 +1,54 @@
+use proc_macro_error::emit_error;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Result, parse::ParseStream, parse_quote};
+
+use crate::{
+	attributes::ValidationAttributes,
+	fields::FieldAttributes,
+	imports::import_validy_core,
+	primitives::commons::{ArgParser, extract_inner_type, parse_attrs, remove_parens},
+};
+
+#[derive(Default)]
+pub struct NewtypeArgs {}
+
+impl ArgParser for NewtypeArgs {
+	const POSITIONAL_KEYS: &'static [&'static str] = &[];
+
+	fn apply_value(&mut self, _: &str, input: ParseStream) -> Result<()> {
+		Err(Error::new(input.span(), "unknown arg"))
+	}
+}
+
+pub fn create_newtype(
+	input: ParseStream,
+	field: &mut FieldAttributes,
+	attributes: &ValidationAttributes,
+) -> TokenStream {
+	let content = remove_parens(input);
+
+	let NewtypeArgs {} = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => NewtypeArgs::default(),
+	};
+
+	if !attributes.payload {
+		emit_error!(input.span(), "requires payload attribute");
+		return quote! {};
+	}
+
+	let field_type = field.get_current_type().clone();
+	let newtype = match field.is_option() {
+		true => extract_inner_type(&field_type).unwrap_or(field_type),
+		false => field_type,
+	};
+
+	let valid_newtype = import_validy_core("ValidNewtype");
+	field.set_initial_type(&parse_quote! { <#newtype as #valid_newtype>::Inner });
+	field.set_newtype(&newtype);
+
+	quote! {}
+}
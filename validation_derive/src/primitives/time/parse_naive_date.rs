@@ -73,6 +73,8 @@ pub fn create_naive_date_parse(
 		return quote! {};
 	}
 
+	field.add_unparse(Some(quote! { value.format(#format).to_string() }));
+
 	if field.is_ref() {
 		field.set_is_ref(false);
 		#[rustfmt::skip]
@@ -0,0 +1,117 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, LitStr, Result, parse::ParseStream};
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::commons::{ArgParser, parse_attrs, remove_parens},
+};
+
+pub struct ConfusableSafeArgs {
+	pub code: LitStr,
+	pub message: LitStr,
+	pub level: Option<LitStr>,
+}
+
+impl Default for ConfusableSafeArgs {
+	fn default() -> Self {
+		ConfusableSafeArgs {
+			code: LitStr::new("confusable_safe", Span::call_site()),
+			message: LitStr::new("has confusable characters", Span::call_site()),
+			level: None,
+		}
+	}
+}
+
+impl ArgParser for ConfusableSafeArgs {
+	const POSITIONAL_KEYS: &'static [&'static str] = &["message", "code", "level"];
+
+	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
+		match name {
+			"code" => self.code = input.parse()?,
+			"message" => self.message = input.parse()?,
+			"level" => self.level = Some(input.parse()?),
+			_ => return Err(Error::new(input.span(), "unknown arg")),
+		}
+
+		Ok(())
+	}
+}
+
+pub fn create_confusable_safe(
+	input: ParseStream,
+	field: &mut FieldAttributes,
+	imports: &RefCell<ImportsSet>,
+) -> TokenStream {
+	imports.borrow_mut().add(Import::ValidationFunction(
+		"unicode::validate_confusable_safe as validate_confusable_safe_fn",
+	));
+
+	let field_name = field.get_name();
+	let reference = field.get_reference();
+	let content = remove_parens(input);
+
+	let ConfusableSafeArgs { code, message, level } = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => ConfusableSafeArgs::default(),
+	};
+
+	let level = get_restriction_level(&level);
+
+	if field.is_ref() {
+		field.set_is_ref(true);
+		#[rustfmt::skip]
+		let result = quote! {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_confusable_safe_fn(#reference, #level, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+		  let _ref = &#reference;
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_confusable_safe_fn(_ref, #level, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	}
+}
+
+fn get_restriction_level(level: &Option<LitStr>) -> TokenStream {
+	match level {
+		Some(level)
+			if matches!(
+				level.value().as_str(),
+				"ascii" | "single_script" | "highly_restrictive" | "moderately_restrictive" | "minimally_restrictive"
+			) =>
+		{
+			quote! { Some(#level) }
+		}
+		Some(level) => {
+			emit_error!(
+				level.span(),
+				"unknown restriction level, expected \"ascii\", \"single_script\", \"highly_restrictive\", \"moderately_restrictive\" or \"minimally_restrictive\""
+			);
+			quote! { None }
+		}
+		None => quote! { None },
+	}
+}
@@ -0,0 +1,3 @@
+pub mod confusable_safe;
+pub mod no_mixed_script;
+pub mod unicode_scripts;
@@ -0,0 +1,128 @@
+use std::cell::RefCell;
+
+use proc_macro_error::emit_error;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Error, Expr, ExprArray, ExprLit, Lit, LitStr, Result, parse::ParseStream};
+use unicode_script::Script;
+
+use crate::{
+	ImportsSet,
+	fields::FieldAttributes,
+	imports::Import,
+	primitives::commons::{ArgParser, parse_attrs, remove_parens},
+};
+
+pub struct UnicodeScriptsArgs {
+	pub allowed: Option<ExprArray>,
+	pub code: LitStr,
+	pub message: LitStr,
+}
+
+impl Default for UnicodeScriptsArgs {
+	fn default() -> Self {
+		UnicodeScriptsArgs {
+			allowed: None,
+			code: LitStr::new("unicode_scripts", Span::call_site()),
+			message: LitStr::new("has characters from disallowed scripts", Span::call_site()),
+		}
+	}
+}
+
+impl ArgParser for UnicodeScriptsArgs {
+	const POSITIONAL_KEYS: &'static [&'static str] = &["allowed", "message", "code"];
+
+	fn apply_value(&mut self, name: &str, input: ParseStream) -> Result<()> {
+		match name {
+			"allowed" => self.allowed = Some(input.parse()?),
+			"code" => self.code = input.parse()?,
+			"message" => self.message = input.parse()?,
+			_ => return Err(Error::new(input.span(), "unknown arg")),
+		}
+
+		Ok(())
+	}
+}
+
+pub fn create_unicode_scripts(
+	input: ParseStream,
+	field: &mut FieldAttributes,
+	imports: &RefCell<ImportsSet>,
+) -> TokenStream {
+	imports.borrow_mut().add(Import::ValidationFunction(
+		"unicode::validate_unicode_scripts as validate_unicode_scripts_fn",
+	));
+	imports
+		.borrow_mut()
+		.add(Import::ValidationFunction("unicode::parse_scripts as parse_scripts_fn"));
+	imports
+		.borrow_mut()
+		.add(Import::ValidationFunction("unicode::AllowedScripts"));
+
+	let field_name = field.get_name();
+	let reference = field.get_reference();
+	let content = remove_parens(input);
+
+	let UnicodeScriptsArgs { allowed, code, message } = match content {
+		Ok(content) => parse_attrs(&content)
+			.inspect_err(|erro| emit_error!(erro.span(), "{}", erro))
+			.unwrap_or_default(),
+		Err(_) => UnicodeScriptsArgs::default(),
+	};
+
+	let Some(allowed) = allowed else {
+		let span = input.span();
+		emit_error!(span, "needs the allowed scripts");
+		return quote! {};
+	};
+
+	for name in &allowed.elems {
+		if let Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) = name
+			&& Script::from_full_name(&lit.value())
+				.or_else(|| Script::from_short_name(&lit.value()))
+				.is_none()
+		{
+			emit_error!(
+				lit.span(),
+				"script must be a Unicode script name, like \"Latin\" or \"Cyrl\""
+			);
+		}
+	}
+
+	#[rustfmt::skip]
+	let allowed = quote! {
+		{
+		  static ALLOWED_SCRIPTS: ::std::sync::LazyLock<AllowedScripts> = ::std::sync::LazyLock::new(|| parse_scripts_fn(&#allowed));
+		  &*ALLOWED_SCRIPTS
+		}
+	};
+
+	if field.is_ref() {
+		field.set_is_ref(true);
+		#[rustfmt::skip]
+		let result = quote! {
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_unicode_scripts_fn(#reference, #allowed, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	} else {
+		field.set_is_ref(false);
+		#[rustfmt::skip]
+		let result = quote! {
+		  let _ref = &#reference;
+			if can_continue(&errors, failure_mode, #field_name) && let Err(e) = validate_unicode_scripts_fn(_ref, #allowed, #field_name, #code, #message) {
+        append_error(&mut errors, e, failure_mode, #field_name);
+        if should_fail_fast(&errors, failure_mode, #field_name) {
+     			return Err(errors);
+     	  }
+		  }
+		};
+
+		result
+	}
+}
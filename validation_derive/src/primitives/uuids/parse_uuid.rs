@@ -65,6 +65,8 @@ pub fn create_uuid_parse(
 		Err(_) => ParseUuidArgs::default(),
 	};
 
+	field.add_unparse(Some(quote! { value.to_string() }));
+
 	if field.is_ref() {
 		field.set_is_ref(false);
 		#[rustfmt::skip]
@@ -1,2 +1,59 @@
+use std::fmt;
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::{Generics, Ident};
+
 pub type Input = proc_macro::TokenStream;
 pub type Output = proc_macro::TokenStream;
+
+#[derive(Clone)]
+pub struct StructName {
+	ident: Ident,
+	generics: Generics,
+}
+
+impl StructName {
+	pub fn new(ident: &Ident, generics: &Generics) -> Self {
+		StructName {
+			ident: ident.clone(),
+			generics: generics.clone(),
+		}
+	}
+
+	pub fn with_suffix(&self, suffix: &str) -> Self {
+		StructName {
+			ident: format_ident!("{}{}", self.ident, suffix),
+			generics: self.generics.clone(),
+		}
+	}
+
+	pub fn impl_generics(&self) -> TokenStream {
+		let (impl_generics, _, _) = self.generics.split_for_impl();
+		quote! { #impl_generics }
+	}
+
+	pub fn where_clause(&self) -> TokenStream {
+		let (_, _, where_clause) = self.generics.split_for_impl();
+		quote! { #where_clause }
+	}
+
+	pub fn generic_params(&self) -> TokenStream {
+		let params = self.generics.params.iter();
+		quote! { #(#params,)* }
+	}
+}
+
+impl ToTokens for StructName {
+	fn to_tokens(&self, tokens: &mut TokenStream) {
+		let ident = &self.ident;
+		let (_, ty_generics, _) = self.generics.split_for_impl();
+		tokens.extend(quote! { #ident #ty_generics });
+	}
+}
+
+impl fmt::Display for StructName {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.ident.fmt(f)
+	}
+}